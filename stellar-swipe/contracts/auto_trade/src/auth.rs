@@ -1,4 +1,5 @@
 use soroban_sdk::{contracttype, Address, Env, Symbol};
+use stellar_swipe_common::{bump_persistent, TtlClass};
 
 use crate::errors::AutoTradeError;
 
@@ -41,15 +42,13 @@ pub fn grant_authorization(
         granted_at: current_time,
     };
 
-    env.storage()
-        .persistent()
-        .set(&AuthKey::Authorization(user.clone()), &config);
+    let key = AuthKey::Authorization(user.clone());
+    env.storage().persistent().set(&key, &config);
+    bump_persistent(env, &key, TtlClass::Hot);
 
     #[allow(deprecated)]
-    env.events().publish(
-        (Symbol::new(env, "auth_granted"), user.clone()),
-        config,
-    );
+    env.events()
+        .publish((Symbol::new(env, "auth_granted"), user.clone()), config);
 
     Ok(())
 }
@@ -63,22 +62,15 @@ pub fn revoke_authorization(env: &Env, user: &Address) -> Result<(), AutoTradeEr
         .remove(&AuthKey::Authorization(user.clone()));
 
     #[allow(deprecated)]
-    env.events().publish(
-        (Symbol::new(env, "auth_revoked"), user.clone()),
-        (),
-    );
+    env.events()
+        .publish((Symbol::new(env, "auth_revoked"), user.clone()), ());
 
     Ok(())
 }
 
 /// Check if user is authorized for a specific trade amount
 pub fn is_authorized(env: &Env, user: &Address, amount: i128) -> bool {
-    let config: Option<AuthConfig> = env
-        .storage()
-        .persistent()
-        .get(&AuthKey::Authorization(user.clone()));
-
-    match config {
+    match get_auth_config(env, user) {
         Some(cfg) => {
             let current_time = env.ledger().timestamp();
             cfg.authorized && current_time < cfg.expires_at && amount <= cfg.max_trade_amount
@@ -87,9 +79,15 @@ pub fn is_authorized(env: &Env, user: &Address, amount: i128) -> bool {
     }
 }
 
-/// Get authorization config for a user
+/// Get authorization config for a user.
+/// Unexpired configs are bumped; expired ones are left to lapse.
 pub fn get_auth_config(env: &Env, user: &Address) -> Option<AuthConfig> {
-    env.storage()
-        .persistent()
-        .get(&AuthKey::Authorization(user.clone()))
+    let key = AuthKey::Authorization(user.clone());
+    let config: Option<AuthConfig> = env.storage().persistent().get(&key);
+    if let Some(ref cfg) = config {
+        if env.ledger().timestamp() < cfg.expires_at {
+            bump_persistent(env, &key, TtlClass::Hot);
+        }
+    }
+    config
 }
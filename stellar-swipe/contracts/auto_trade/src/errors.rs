@@ -13,4 +13,13 @@ pub enum AutoTradeError {
     DailyTradeLimitExceeded = 7,
     PositionLimitExceeded = 8,
     StopLossTriggered = 9,
+    TooManyKeys = 10,
+    AlreadyInitialized = 11,
+    NotInitialized = 12,
+    NeutralSignal = 13,
+    StrategyNotFound = 14,
+    StrategyLegFailed = 15,
+    FilteredByPreferences = 16,
+    StrategyNotActive = 17,
+    UnknownAsset = 18,
 }
@@ -5,6 +5,8 @@
 //! Gas: ~O(limit) per get_trade_history query.
 
 use soroban_sdk::{contracttype, Address, Env, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
 /// Default page size for trade history
 pub const DEFAULT_HISTORY_LIMIT: u32 = 20;
 
@@ -24,6 +26,7 @@ pub enum HistoryTradeStatus {
 #[derive(Clone, Debug)]
 pub struct HistoryTrade {
     pub id: u64,
+    /// Signal id, or `strategy::trade_record_id` for strategy legs
     pub signal_id: u64,
     pub base_asset: u32,
     pub amount: i128,
@@ -41,10 +44,14 @@ pub enum HistoryDataKey {
 
 /// Get number of trades for a user
 pub fn get_user_trade_count(env: &Env, user: &Address) -> u64 {
-    env.storage()
-        .persistent()
-        .get(&HistoryDataKey::UserTradeCount(user.clone()))
-        .unwrap_or(0)
+    let key = HistoryDataKey::UserTradeCount(user.clone());
+    match env.storage().persistent().get(&key) {
+        Some(count) => {
+            bump_persistent(env, &key, TtlClass::Hot);
+            count
+        }
+        None => 0,
+    }
 }
 
 /// Record a trade to history. Called after successful execution.
@@ -73,14 +80,14 @@ pub fn record_trade(
         status,
     };
 
-    env.storage()
-        .persistent()
-        .set(&HistoryDataKey::Trade(user.clone(), id), &trade);
+    let trade_key = HistoryDataKey::Trade(user.clone(), id);
+    env.storage().persistent().set(&trade_key, &trade);
+    bump_persistent(env, &trade_key, TtlClass::History);
 
     let new_count = count + 1;
-    env.storage()
-        .persistent()
-        .set(&HistoryDataKey::UserTradeCount(user.clone()), &new_count);
+    let count_key = HistoryDataKey::UserTradeCount(user.clone());
+    env.storage().persistent().set(&count_key, &new_count);
+    bump_persistent(env, &count_key, TtlClass::Hot);
 
     id
 }
@@ -1,18 +1,26 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Symbol};
 
 mod auth;
 mod errors;
 mod history;
 mod multi_asset;
 mod portfolio;
+mod registry;
 mod risk;
 mod sdex;
 mod storage;
+mod strategy;
+mod upgrade;
 
 use crate::storage::DataKey;
+pub use crate::storage::TtlKey;
+pub use crate::strategy::{Strategy, StrategyLeg, StrategyTrade};
 use errors::AutoTradeError;
+use stellar_swipe_common::{
+    bump_instance, bump_persistent, MigrationStatus, TtlClass, MAX_BUMP_KEYS,
+};
 
 /// ==========================
 /// Types
@@ -65,7 +73,52 @@ pub struct AutoTradeContract;
 
 #[contractimpl]
 impl AutoTradeContract {
-    /// Execute a trade on behalf of a user based on a signal
+    /// Set the contract admin at deployment
+    pub fn __constructor(env: Env, admin: Address) {
+        upgrade::init_admin(&env, &admin);
+    }
+
+    /// Replace the contract Wasm. Admin only.
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), AutoTradeError> {
+        upgrade::upgrade(&env, wasm_hash)
+    }
+
+    /// Schema version of the data in storage
+    pub fn get_schema_version(env: Env) -> u32 {
+        upgrade::get_schema_version(&env)
+    }
+
+    /// Rewrite up to `batch` old-layout records into the current layout.
+    /// Permissionless and resumable; call until `remaining` is 0.
+    pub fn migrate(env: Env, batch: u32) -> MigrationStatus {
+        upgrade::migrate(&env, batch)
+    }
+
+    /// Link the signal registry whose user preferences gate copies. Admin only.
+    pub fn set_signal_registry(env: Env, registry: Address) -> Result<(), AutoTradeError> {
+        registry::set_signal_registry(&env, &registry)
+    }
+
+    pub fn get_signal_registry(env: Env) -> Option<Address> {
+        registry::get_signal_registry(&env)
+    }
+
+    /// Map a registry asset pair to the asset id it trades under here, so
+    /// registry strategies on that pair can be copied. Admin only.
+    pub fn set_registry_asset(
+        env: Env,
+        asset_pair: String,
+        asset_id: u32,
+    ) -> Result<(), AutoTradeError> {
+        registry::set_asset_id(&env, &asset_pair, asset_id)
+    }
+
+    pub fn get_registry_asset(env: Env, asset_pair: String) -> Option<u32> {
+        registry::get_asset_id(&env, &asset_pair)
+    }
+
+    /// Execute a trade on behalf of a user based on a signal. Fails with
+    /// `FilteredByPreferences` when the user's registry preferences reject it.
     pub fn execute_trade(
         env: Env,
         user: Address,
@@ -85,6 +138,12 @@ impl AutoTradeContract {
             return Err(AutoTradeError::SignalExpired);
         }
 
+        if registry::is_neutral_signal(&env, signal_id) {
+            return Err(AutoTradeError::NeutralSignal);
+        }
+
+        registry::require_signal_allowed(&env, &user, signal_id)?;
+
         if !auth::is_authorized(&env, &user, amount) {
             return Err(AutoTradeError::Unauthorized);
         }
@@ -171,9 +230,10 @@ impl AutoTradeContract {
             risk::add_trade_record(&env, &user, signal_id, execution.executed_amount);
         }
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Trades(user.clone(), signal_id), &trade);
+        let trade_key = DataKey::Trades(user.clone(), signal_id);
+        env.storage().persistent().set(&trade_key, &trade);
+        bump_persistent(&env, &trade_key, TtlClass::History);
+        bump_instance(&env);
 
         if execution.executed_amount > 0 {
             let hist_status = match status {
@@ -224,6 +284,24 @@ impl AutoTradeContract {
             .get(&DataKey::Trades(user, signal_id))
     }
 
+    /// Execute all legs of a strategy signal, or none of them. `amount` is
+    /// split across legs by weight. Strategies not cached yet are read from
+    /// the linked registry.
+    pub fn execute_strategy(
+        env: Env,
+        user: Address,
+        strategy_id: u64,
+        order_type: OrderType,
+        amount: i128,
+    ) -> Result<StrategyTrade, AutoTradeError> {
+        strategy::execute_strategy(&env, &user, strategy_id, order_type, amount)
+    }
+
+    /// Fetch executed strategy trade by user + strategy
+    pub fn get_strategy_trade(env: Env, user: Address, strategy_id: u64) -> Option<StrategyTrade> {
+        strategy::get_strategy_trade(&env, &user, strategy_id)
+    }
+
     /// Get user's risk configuration
     pub fn get_risk_config(env: Env, user: Address) -> risk::RiskConfig {
         risk::get_risk_config(&env, &user)
@@ -288,6 +366,15 @@ impl AutoTradeContract {
     pub fn get_auth_config(env: Env, user: Address) -> Option<auth::AuthConfig> {
         auth::get_auth_config(&env, &user)
     }
+
+    /// Extend the TTL of contract entries. Permissionless so anyone can keep
+    /// live data from expiring. Returns the number of ledger entries bumped.
+    pub fn bump_ttl(env: Env, keys: soroban_sdk::Vec<TtlKey>) -> Result<u32, AutoTradeError> {
+        if keys.len() > MAX_BUMP_KEYS {
+            return Err(AutoTradeError::TooManyKeys);
+        }
+        Ok(storage::bump_ttl(&env, &keys))
+    }
 }
 
 mod test;
@@ -0,0 +1,164 @@
+//! Link to the signal registry.
+//!
+//! Users keep their copy preferences (blocked providers, risk ceiling,
+//! watched pairs, ...) in the registry. Once the admin links the registry,
+//! every copy asks it whether the user's preferences allow the signal or
+//! strategy. Deployments without a linked registry copy unfiltered.
+//!
+//! Whether a signal is a Hold/neutral call also comes from the registry, and
+//! is cached once known.
+//!
+//! Strategies are also read from the registry the first time they are
+//! copied. Registry legs name an asset pair; the admin maps each pair to the
+//! asset id this contract trades it under.
+
+use soroban_sdk::{contracttype, vec, Address, Env, IntoVal, String, Symbol, Vec};
+
+use crate::errors::AutoTradeError;
+use crate::storage::{self, DataKey};
+use crate::upgrade;
+
+/// Registry `SignalAction`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RegistrySignalAction {
+    Buy,
+    Sell,
+    Hold,
+}
+
+/// Registry `SignalStatus`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RegistrySignalStatus {
+    Pending,
+    Active,
+    Executed,
+    Expired,
+    Successful,
+    Failed,
+    Cancelled,
+}
+
+/// Registry `StrategyLeg`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryStrategyLeg {
+    pub asset_pair: String,
+    pub action: RegistrySignalAction,
+    pub price: i128,
+    pub weight_bps: u32,
+}
+
+/// Registry `StrategySignal`, as returned by `get_strategy_signal`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryStrategy {
+    pub id: u64,
+    pub provider: Address,
+    pub rationale: String,
+    pub legs: Vec<RegistryStrategyLeg>,
+    pub timestamp: u64,
+    pub expiry: u64,
+    pub status: RegistrySignalStatus,
+    pub roi: Option<i128>,
+}
+
+/// Link the registry whose preferences gate copies. Requires the admin's auth.
+pub fn set_signal_registry(env: &Env, registry: &Address) -> Result<(), AutoTradeError> {
+    let admin = upgrade::get_admin(env)?;
+    admin.require_auth();
+
+    env.storage()
+        .instance()
+        .set(&DataKey::SignalRegistry, registry);
+    Ok(())
+}
+
+pub fn get_signal_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::SignalRegistry)
+}
+
+/// Map a registry asset pair to the asset id it trades under here. Requires
+/// the admin's auth.
+pub fn set_asset_id(env: &Env, asset_pair: &String, asset_id: u32) -> Result<(), AutoTradeError> {
+    let admin = upgrade::get_admin(env)?;
+    admin.require_auth();
+
+    env.storage()
+        .instance()
+        .set(&DataKey::RegistryAsset(asset_pair.clone()), &asset_id);
+    Ok(())
+}
+
+pub fn get_asset_id(env: &Env, asset_pair: &String) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RegistryAsset(asset_pair.clone()))
+}
+
+/// Read a strategy from the linked registry. None without a registry or
+/// when the registry has no such strategy.
+pub fn fetch_strategy(env: &Env, strategy_id: u64) -> Option<RegistryStrategy> {
+    let registry = get_signal_registry(env)?;
+    env.invoke_contract(
+        &registry,
+        &Symbol::new(env, "get_strategy_signal"),
+        vec![env, strategy_id.into_val(env)],
+    )
+}
+
+/// Whether `signal_id` is a Hold/neutral call. Asks the registry unless the
+/// signal is already cached as neutral; without a registry only the cache
+/// is consulted.
+pub fn is_neutral_signal(env: &Env, signal_id: u64) -> bool {
+    if storage::is_neutral_signal(env, signal_id) {
+        return true;
+    }
+    let Some(registry) = get_signal_registry(env) else {
+        return false;
+    };
+    let neutral: bool = env.invoke_contract(
+        &registry,
+        &Symbol::new(env, "is_neutral_signal"),
+        vec![env, signal_id.into_val(env)],
+    );
+    if neutral {
+        storage::set_signal_neutral(env, signal_id);
+    }
+    neutral
+}
+
+/// Fail unless `user`'s registry preferences allow copying `signal_id`
+pub fn require_signal_allowed(
+    env: &Env,
+    user: &Address,
+    signal_id: u64,
+) -> Result<(), AutoTradeError> {
+    require_allowed(env, "signal_matches_preferences", user, signal_id)
+}
+
+/// Fail unless `user`'s registry preferences allow copying `strategy_id`
+pub fn require_strategy_allowed(
+    env: &Env,
+    user: &Address,
+    strategy_id: u64,
+) -> Result<(), AutoTradeError> {
+    require_allowed(env, "strategy_matches_preferences", user, strategy_id)
+}
+
+fn require_allowed(env: &Env, check: &str, user: &Address, id: u64) -> Result<(), AutoTradeError> {
+    let Some(registry) = get_signal_registry(env) else {
+        return Ok(());
+    };
+    let allowed: bool = env.invoke_contract(
+        &registry,
+        &Symbol::new(env, check),
+        vec![env, user.into_val(env), id.into_val(env)],
+    );
+    if allowed {
+        Ok(())
+    } else {
+        Err(AutoTradeError::FilteredByPreferences)
+    }
+}
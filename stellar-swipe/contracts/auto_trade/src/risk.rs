@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 use soroban_sdk::{contracttype, Address, Env, Map, Vec};
 
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
 use crate::errors::AutoTradeError;
 
 /// ==========================
@@ -38,6 +40,7 @@ pub struct Position {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TradeRecord {
     pub timestamp: u64,
+    /// Signal id, or `strategy::trade_record_id` for strategy legs
     pub signal_id: u64,
     pub amount: i128,
 }
@@ -54,26 +57,34 @@ pub enum RiskDataKey {
 /// Risk Configuration Management
 /// ==========================
 pub fn get_risk_config(env: &Env, user: &Address) -> RiskConfig {
-    env.storage()
-        .persistent()
-        .get(&RiskDataKey::UserRiskConfig(user.clone()))
-        .unwrap_or_default()
+    let key = RiskDataKey::UserRiskConfig(user.clone());
+    match env.storage().persistent().get(&key) {
+        Some(config) => {
+            bump_persistent(env, &key, TtlClass::Hot);
+            config
+        }
+        None => RiskConfig::default(),
+    }
 }
 
 pub fn set_risk_config(env: &Env, user: &Address, config: &RiskConfig) {
-    env.storage()
-        .persistent()
-        .set(&RiskDataKey::UserRiskConfig(user.clone()), config);
+    let key = RiskDataKey::UserRiskConfig(user.clone());
+    env.storage().persistent().set(&key, config);
+    bump_persistent(env, &key, TtlClass::Hot);
 }
 
 /// ==========================
 /// Position Management
 /// ==========================
 pub fn get_user_positions(env: &Env, user: &Address) -> Map<u32, Position> {
-    env.storage()
-        .persistent()
-        .get(&RiskDataKey::UserPositions(user.clone()))
-        .unwrap_or_else(|| Map::new(env))
+    let key = RiskDataKey::UserPositions(user.clone());
+    match env.storage().persistent().get(&key) {
+        Some(positions) => {
+            bump_persistent(env, &key, TtlClass::Hot);
+            positions
+        }
+        None => Map::new(env),
+    }
 }
 
 pub fn update_position(env: &Env, user: &Address, asset_id: u32, amount: i128, price: i128) {
@@ -91,19 +102,23 @@ pub fn update_position(env: &Env, user: &Address, asset_id: u32, amount: i128, p
         positions.set(asset_id, position);
     }
 
-    env.storage()
-        .persistent()
-        .set(&RiskDataKey::UserPositions(user.clone()), &positions);
+    let key = RiskDataKey::UserPositions(user.clone());
+    env.storage().persistent().set(&key, &positions);
+    bump_persistent(env, &key, TtlClass::Hot);
 }
 
 /// ==========================
 /// Trade History Management
 /// ==========================
 pub fn get_trade_history(env: &Env, user: &Address) -> Vec<TradeRecord> {
-    env.storage()
-        .persistent()
-        .get(&RiskDataKey::UserTradeHistory(user.clone()))
-        .unwrap_or_else(|| Vec::new(env))
+    let key = RiskDataKey::UserTradeHistory(user.clone());
+    match env.storage().persistent().get(&key) {
+        Some(history) => {
+            bump_persistent(env, &key, TtlClass::Hot);
+            history
+        }
+        None => Vec::new(env),
+    }
 }
 
 pub fn add_trade_record(env: &Env, user: &Address, signal_id: u64, amount: i128) {
@@ -117,9 +132,9 @@ pub fn add_trade_record(env: &Env, user: &Address, signal_id: u64, amount: i128)
 
     history.push_back(record);
 
-    env.storage()
-        .persistent()
-        .set(&RiskDataKey::UserTradeHistory(user.clone()), &history);
+    let key = RiskDataKey::UserTradeHistory(user.clone());
+    env.storage().persistent().set(&key, &history);
+    bump_persistent(env, &key, TtlClass::Hot);
 }
 
 /// ==========================
@@ -31,6 +31,28 @@ pub fn execute_market_order(
     _user: &Address,
     signal: &Signal,
     amount: i128,
+) -> Result<ExecutionResult, AutoTradeError> {
+    let key = (symbol_short!("liquidity"), signal.signal_id);
+    fill_market_order(env, signal, amount, env.storage().temporary().get(&key))
+}
+
+/// Market order for one strategy leg. Legs fill against the liquidity of
+/// their asset rather than a per-signal book.
+pub fn execute_leg_market_order(
+    env: &Env,
+    _user: &Address,
+    leg: &Signal,
+    amount: i128,
+) -> Result<ExecutionResult, AutoTradeError> {
+    let key = (symbol_short!("liquidity"), leg.base_asset);
+    fill_market_order(env, leg, amount, env.storage().temporary().get(&key))
+}
+
+fn fill_market_order(
+    env: &Env,
+    signal: &Signal,
+    amount: i128,
+    liquidity: Option<i128>,
 ) -> Result<ExecutionResult, AutoTradeError> {
     let now = env.ledger().timestamp();
 
@@ -38,8 +60,7 @@ pub fn execute_market_order(
         return Err(AutoTradeError::SignalExpired);
     }
 
-    let key = (symbol_short!("liquidity"), signal.signal_id);
-    let available_liquidity: i128 = env.storage().temporary().get(&key).unwrap_or(amount);
+    let available_liquidity = liquidity.unwrap_or(amount);
 
     if available_liquidity <= 0 {
         return Err(AutoTradeError::InsufficientLiquidity);
@@ -61,6 +82,27 @@ pub fn execute_limit_order(
     _user: &Address,
     signal: &Signal,
     amount: i128,
+) -> Result<ExecutionResult, AutoTradeError> {
+    let key = (symbol_short!("price"), signal.signal_id);
+    fill_limit_order(env, signal, amount, env.storage().temporary().get(&key))
+}
+
+/// Limit order for one strategy leg, priced from the leg asset's market
+pub fn execute_leg_limit_order(
+    env: &Env,
+    _user: &Address,
+    leg: &Signal,
+    amount: i128,
+) -> Result<ExecutionResult, AutoTradeError> {
+    let key = (symbol_short!("price"), leg.base_asset);
+    fill_limit_order(env, leg, amount, env.storage().temporary().get(&key))
+}
+
+fn fill_limit_order(
+    env: &Env,
+    signal: &Signal,
+    amount: i128,
+    market_price: Option<i128>,
 ) -> Result<ExecutionResult, AutoTradeError> {
     let now = env.ledger().timestamp();
 
@@ -68,8 +110,7 @@ pub fn execute_limit_order(
         return Err(AutoTradeError::SignalExpired);
     }
 
-    let key = (symbol_short!("price"), signal.signal_id);
-    let market_price: i128 = env.storage().temporary().get(&key).unwrap_or(signal.price);
+    let market_price = market_price.unwrap_or(signal.price);
 
     if market_price > signal.price {
         return Ok(ExecutionResult {
@@ -1,5 +1,10 @@
 #![allow(dead_code)]
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use stellar_swipe_common::{bump_instance, bump_persistent, TtlClass};
+
+use crate::auth::AuthKey;
+use crate::history::HistoryDataKey;
+use crate::risk::RiskDataKey;
 
 #[contracttype]
 #[derive(Clone)]
@@ -14,14 +19,117 @@ pub struct Signal {
 pub enum DataKey {
     Trades(Address, u64),
     Signal(u64),
+    /// Contract admin (instance)
+    Admin,
+    /// signal_id -> true for Hold/neutral calls, which have no trade to execute,
+    /// cached from the registry. Kept apart from `Signal` so stored signals
+    /// keep their layout.
+    NeutralSignal(u64),
+    /// Registry consulted for user preferences (instance)
+    SignalRegistry,
+    /// Registry asset pair -> asset id it trades under here (instance)
+    RegistryAsset(String),
+}
+
+/// Entry that can be passed to the `bump_ttl` maintenance entrypoint
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TtlKey {
+    /// Contract instance storage
+    Instance,
+    /// A cached signal
+    Signal(u64),
+    /// Trade record for (user, signal_id)
+    Trade(Address, u64),
+    /// Trade history entry for (user, index)
+    HistoryTrade(Address, u64),
+    /// All of a user's hot entries: positions, risk config, risk trade
+    /// history, trade count and (unexpired) authorization
+    User(Address),
 }
 
 /// Get a signal by ID
 pub fn get_signal(env: &Env, id: u64) -> Option<Signal> {
-    env.storage().persistent().get(&DataKey::Signal(id))
+    let key = DataKey::Signal(id);
+    let signal = env.storage().persistent().get(&key);
+    if signal.is_some() {
+        bump_persistent(env, &key, TtlClass::Hot);
+    }
+    signal
 }
 
 /// Set a signal
 pub fn set_signal(env: &Env, id: u64, signal: &Signal) {
-    env.storage().persistent().set(&DataKey::Signal(id), signal);
+    let key = DataKey::Signal(id);
+    env.storage().persistent().set(&key, signal);
+    bump_persistent(env, &key, TtlClass::Hot);
+}
+
+/// Mark a signal as a Hold/neutral call
+pub fn set_signal_neutral(env: &Env, id: u64) {
+    let key = DataKey::NeutralSignal(id);
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key, TtlClass::Hot);
+}
+
+/// Whether a signal is a Hold/neutral call
+pub fn is_neutral_signal(env: &Env, id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::NeutralSignal(id))
+        .unwrap_or(false)
+}
+
+/// Extend the TTL of the given entries. Missing entries and expired
+/// authorizations are skipped. Returns the number of ledger entries bumped.
+pub fn bump_ttl(env: &Env, keys: &Vec<TtlKey>) -> u32 {
+    let mut bumped = 0u32;
+    for key in keys.iter() {
+        match key {
+            TtlKey::Instance => {
+                bump_instance(env);
+                bumped += 1;
+            }
+            TtlKey::Signal(id) => {
+                bumped += bump_persistent(env, &DataKey::Signal(id), TtlClass::Hot) as u32;
+                bumped += bump_persistent(env, &DataKey::NeutralSignal(id), TtlClass::Hot) as u32;
+            }
+            TtlKey::Trade(user, signal_id) => {
+                bumped += bump_persistent(env, &DataKey::Trades(user, signal_id), TtlClass::History)
+                    as u32;
+            }
+            TtlKey::HistoryTrade(user, index) => {
+                bumped +=
+                    bump_persistent(env, &HistoryDataKey::Trade(user, index), TtlClass::History)
+                        as u32;
+            }
+            TtlKey::User(user) => {
+                bumped += bump_persistent(
+                    env,
+                    &RiskDataKey::UserPositions(user.clone()),
+                    TtlClass::Hot,
+                ) as u32;
+                bumped += bump_persistent(
+                    env,
+                    &RiskDataKey::UserRiskConfig(user.clone()),
+                    TtlClass::Hot,
+                ) as u32;
+                bumped += bump_persistent(
+                    env,
+                    &RiskDataKey::UserTradeHistory(user.clone()),
+                    TtlClass::Hot,
+                ) as u32;
+                bumped += bump_persistent(
+                    env,
+                    &HistoryDataKey::UserTradeCount(user.clone()),
+                    TtlClass::Hot,
+                ) as u32;
+                if crate::auth::get_auth_config(env, &user).is_some() {
+                    bumped +=
+                        bump_persistent(env, &AuthKey::Authorization(user), TtlClass::Hot) as u32;
+                }
+            }
+        }
+    }
+    bumped
 }
@@ -0,0 +1,253 @@
+#![allow(dead_code)]
+//! Multi-leg strategy execution.
+//!
+//! A strategy (pair trade or basket) is cached like a signal and executed as
+//! one unit: every leg is risk checked and priced first, and nothing is
+//! written unless all legs fill completely. Strategies missing from the
+//! cache are read from the linked signal registry.
+//!
+//! Strategy ids are issued separately from signal ids, so the risk and
+//! history records of a strategy trade carry the strategy id tagged with
+//! `STRATEGY_TRADE_TAG` in their `signal_id` field.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+use stellar_swipe_common::{bump_instance, bump_persistent, TtlClass};
+
+use crate::errors::AutoTradeError;
+use crate::registry::{RegistrySignalAction, RegistrySignalStatus};
+use crate::sdex::{self, ExecutionResult};
+use crate::storage::Signal;
+use crate::{auth, history, registry, risk, OrderType};
+
+/// Leg weights must add up to this (100%)
+pub const TOTAL_WEIGHT_BPS: u32 = 10_000;
+
+/// High bit set on strategy ids in trade records
+pub const STRATEGY_TRADE_TAG: u64 = 1 << 63;
+
+/// Id under which a strategy's trades are recorded in risk and history
+pub fn trade_record_id(strategy_id: u64) -> u64 {
+    strategy_id | STRATEGY_TRADE_TAG
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StrategyLeg {
+    pub base_asset: u32,
+    pub price: i128,
+    /// Share of the strategy amount in basis points
+    pub weight_bps: u32,
+    /// Short leg
+    pub is_sell: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Strategy {
+    pub strategy_id: u64,
+    pub expiry: u64,
+    pub legs: Vec<StrategyLeg>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegExecution {
+    pub base_asset: u32,
+    pub is_sell: bool,
+    pub executed_amount: i128,
+    pub executed_price: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StrategyTrade {
+    pub strategy_id: u64,
+    pub user: Address,
+    pub requested_amount: i128,
+    pub legs: Vec<LegExecution>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+pub enum StrategyDataKey {
+    Strategy(u64),
+    Trade(Address, u64),
+}
+
+pub fn get_strategy(env: &Env, strategy_id: u64) -> Option<Strategy> {
+    let key = StrategyDataKey::Strategy(strategy_id);
+    let strategy = env.storage().persistent().get(&key);
+    if strategy.is_some() {
+        bump_persistent(env, &key, TtlClass::Hot);
+    }
+    strategy
+}
+
+pub fn set_strategy(env: &Env, strategy: &Strategy) {
+    let key = StrategyDataKey::Strategy(strategy.strategy_id);
+    env.storage().persistent().set(&key, strategy);
+    bump_persistent(env, &key, TtlClass::Hot);
+}
+
+/// Cached strategy, or the registry's copy of it once cached. Only active
+/// registry strategies whose pairs all have an asset id can be copied.
+pub fn load_strategy(env: &Env, strategy_id: u64) -> Result<Strategy, AutoTradeError> {
+    if let Some(strategy) = get_strategy(env, strategy_id) {
+        return Ok(strategy);
+    }
+
+    let source =
+        registry::fetch_strategy(env, strategy_id).ok_or(AutoTradeError::StrategyNotFound)?;
+    if source.status != RegistrySignalStatus::Active {
+        return Err(AutoTradeError::StrategyNotActive);
+    }
+
+    let mut legs = Vec::new(env);
+    for leg in source.legs.iter() {
+        let base_asset =
+            registry::get_asset_id(env, &leg.asset_pair).ok_or(AutoTradeError::UnknownAsset)?;
+        legs.push_back(StrategyLeg {
+            base_asset,
+            price: leg.price,
+            weight_bps: leg.weight_bps,
+            is_sell: leg.action == RegistrySignalAction::Sell,
+        });
+    }
+
+    let strategy = Strategy {
+        strategy_id,
+        expiry: source.expiry,
+        legs,
+    };
+    set_strategy(env, &strategy);
+    Ok(strategy)
+}
+
+pub fn get_strategy_trade(env: &Env, user: &Address, strategy_id: u64) -> Option<StrategyTrade> {
+    env.storage()
+        .persistent()
+        .get(&StrategyDataKey::Trade(user.clone(), strategy_id))
+}
+
+/// Execute every leg of a strategy for `amount`, split by leg weight.
+/// Fails without writing anything unless all legs fill completely.
+pub fn execute_strategy(
+    env: &Env,
+    user: &Address,
+    strategy_id: u64,
+    order_type: OrderType,
+    amount: i128,
+) -> Result<StrategyTrade, AutoTradeError> {
+    if amount <= 0 {
+        return Err(AutoTradeError::InvalidAmount);
+    }
+
+    user.require_auth();
+
+    let strategy = load_strategy(env, strategy_id)?;
+
+    if env.ledger().timestamp() > strategy.expiry {
+        return Err(AutoTradeError::SignalExpired);
+    }
+
+    registry::require_strategy_allowed(env, user, strategy_id)?;
+
+    if !auth::is_authorized(env, user, amount) {
+        return Err(AutoTradeError::Unauthorized);
+    }
+
+    // Validate and fill every leg before writing anything
+    let mut fills: Vec<LegExecution> = Vec::new(env);
+    for leg in strategy.legs.iter() {
+        let leg_amount = amount * leg.weight_bps as i128 / TOTAL_WEIGHT_BPS as i128;
+        if leg_amount <= 0 {
+            return Err(AutoTradeError::InvalidAmount);
+        }
+
+        if !sdex::has_sufficient_balance(env, user, &leg.base_asset, leg_amount) {
+            return Err(AutoTradeError::InsufficientBalance);
+        }
+
+        risk::validate_trade(
+            env,
+            user,
+            leg.base_asset,
+            leg_amount,
+            leg.price,
+            leg.is_sell,
+        )?;
+
+        let order = Signal {
+            signal_id: strategy_id,
+            price: leg.price,
+            expiry: strategy.expiry,
+            base_asset: leg.base_asset,
+        };
+        let execution: ExecutionResult = match order_type {
+            OrderType::Market => sdex::execute_leg_market_order(env, user, &order, leg_amount)?,
+            OrderType::Limit => sdex::execute_leg_limit_order(env, user, &order, leg_amount)?,
+        };
+        if execution.executed_amount < leg_amount {
+            return Err(AutoTradeError::StrategyLegFailed);
+        }
+
+        fills.push_back(LegExecution {
+            base_asset: leg.base_asset,
+            is_sell: leg.is_sell,
+            executed_amount: execution.executed_amount,
+            executed_price: execution.executed_price,
+        });
+    }
+
+    let record_id = trade_record_id(strategy_id);
+    for fill in fills.iter() {
+        let positions = risk::get_user_positions(env, user);
+        let current_amount = positions
+            .get(fill.base_asset)
+            .map(|p| p.amount)
+            .unwrap_or(0);
+        let new_amount = if fill.is_sell {
+            current_amount - fill.executed_amount
+        } else {
+            current_amount + fill.executed_amount
+        };
+        risk::set_asset_price(env, fill.base_asset, fill.executed_price);
+        risk::update_position(env, user, fill.base_asset, new_amount, fill.executed_price);
+        risk::add_trade_record(env, user, record_id, fill.executed_amount);
+        history::record_trade(
+            env,
+            user,
+            record_id,
+            fill.base_asset,
+            fill.executed_amount,
+            fill.executed_price,
+            0,
+            history::HistoryTradeStatus::Executed,
+        );
+    }
+
+    let trade = StrategyTrade {
+        strategy_id,
+        user: user.clone(),
+        requested_amount: amount,
+        legs: fills,
+        timestamp: env.ledger().timestamp(),
+    };
+
+    let key = StrategyDataKey::Trade(user.clone(), strategy_id);
+    env.storage().persistent().set(&key, &trade);
+    bump_persistent(env, &key, TtlClass::History);
+    bump_instance(env);
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (
+            Symbol::new(env, "strategy_executed"),
+            user.clone(),
+            strategy_id,
+        ),
+        trade.clone(),
+    );
+
+    Ok(trade)
+}
@@ -3,6 +3,7 @@
 use super::*;
 use crate::risk;
 use crate::storage;
+use crate::strategy;
 use soroban_sdk::{
     symbol_short,
     testutils::{Address as _, Ledger as _},
@@ -16,6 +17,24 @@ fn setup_env() -> Env {
     env
 }
 
+fn register_contract(env: &Env) -> Address {
+    env.register(AutoTradeContract, (Address::generate(env),))
+}
+
+/// Give `user` an open-ended authorization so trades pass the auth check
+fn authorize_user(env: &Env, user: &Address) {
+    let now = env.ledger().timestamp();
+    env.storage().persistent().set(
+        &crate::auth::AuthKey::Authorization(user.clone()),
+        &crate::auth::AuthConfig {
+            authorized: true,
+            max_trade_amount: i128::MAX,
+            expires_at: now + 365 * 86_400,
+            granted_at: now,
+        },
+    );
+}
+
 fn setup_signal(_env: &Env, signal_id: u64, expiry: u64) -> storage::Signal {
     storage::Signal {
         signal_id,
@@ -28,7 +47,7 @@ fn setup_signal(_env: &Env, signal_id: u64, expiry: u64) -> storage::Signal {
 #[test]
 fn test_execute_trade_invalid_amount() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
@@ -42,7 +61,7 @@ fn test_execute_trade_invalid_amount() {
 #[test]
 fn test_execute_trade_signal_not_found() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
@@ -61,7 +80,7 @@ fn test_execute_trade_signal_not_found() {
 #[test]
 fn test_execute_trade_signal_expired() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() - 1);
@@ -80,10 +99,33 @@ fn test_execute_trade_signal_expired() {
     });
 }
 
+#[test]
+fn test_execute_trade_neutral_signal() {
+    let env = setup_env();
+    let contract_id = register_contract(&env);
+    let user = Address::generate(&env);
+    let signal_id = 1;
+    let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
+
+    env.as_contract(&contract_id, || {
+        storage::set_signal(&env, signal_id, &signal);
+        storage::set_signal_neutral(&env, signal_id);
+        let res = AutoTradeContract::execute_trade(
+            env.clone(),
+            user.clone(),
+            signal_id,
+            OrderType::Market,
+            100,
+        );
+
+        assert_eq!(res, Err(AutoTradeError::NeutralSignal));
+    });
+}
+
 #[test]
 fn test_execute_trade_unauthorized() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
@@ -105,14 +147,14 @@ fn test_execute_trade_unauthorized() {
 #[test]
 fn test_execute_trade_insufficient_balance() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        storage::authorize_user(&env, &user);
+        authorize_user(&env, &user);
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &50i128);
@@ -132,14 +174,14 @@ fn test_execute_trade_insufficient_balance() {
 #[test]
 fn test_execute_trade_market_full_fill() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        storage::authorize_user(&env, &user);
+        authorize_user(&env, &user);
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
@@ -165,14 +207,14 @@ fn test_execute_trade_market_full_fill() {
 #[test]
 fn test_execute_trade_market_partial_fill() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 2;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        storage::authorize_user(&env, &user);
+        authorize_user(&env, &user);
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
@@ -198,14 +240,14 @@ fn test_execute_trade_market_partial_fill() {
 #[test]
 fn test_execute_trade_limit_filled() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 3;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        storage::authorize_user(&env, &user);
+        authorize_user(&env, &user);
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
@@ -231,14 +273,14 @@ fn test_execute_trade_limit_filled() {
 #[test]
 fn test_execute_trade_limit_not_filled() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 4;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        storage::authorize_user(&env, &user);
+        authorize_user(&env, &user);
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
@@ -264,14 +306,14 @@ fn test_execute_trade_limit_not_filled() {
 #[test]
 fn test_get_trade_existing() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        storage::authorize_user(&env, &user);
+        authorize_user(&env, &user);
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
@@ -301,7 +343,7 @@ fn test_get_trade_existing() {
 #[test]
 fn test_get_trade_non_existing() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 999;
 
@@ -319,7 +361,7 @@ fn test_get_trade_non_existing() {
 #[test]
 fn test_get_default_risk_config() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
@@ -334,7 +376,7 @@ fn test_get_default_risk_config() {
 #[test]
 fn test_set_custom_risk_config() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
@@ -354,14 +396,14 @@ fn test_set_custom_risk_config() {
 #[test]
 fn test_position_limit_allows_first_trade() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        storage::authorize_user(&env, &user);
+        authorize_user(&env, &user);
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &1000i128);
@@ -385,14 +427,14 @@ fn test_position_limit_allows_first_trade() {
 #[test]
 fn test_get_user_positions() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        storage::authorize_user(&env, &user);
+        authorize_user(&env, &user);
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &1000i128);
@@ -423,7 +465,7 @@ fn test_get_user_positions() {
 #[test]
 fn test_stop_loss_check() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
@@ -445,7 +487,7 @@ fn test_stop_loss_check() {
 #[test]
 fn test_get_trade_history_paginated() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
@@ -453,7 +495,7 @@ fn test_get_trade_history_paginated() {
     // Setup (max_position_pct: 100 so multiple buys in same asset pass risk checks)
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        storage::authorize_user(&env, &user);
+        authorize_user(&env, &user);
         risk::set_risk_config(
             &env,
             &user,
@@ -498,7 +540,7 @@ fn test_get_trade_history_paginated() {
 #[test]
 fn test_get_trade_history_empty() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
@@ -510,14 +552,14 @@ fn test_get_trade_history_empty() {
 #[test]
 fn test_get_portfolio() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        storage::authorize_user(&env, &user);
+        authorize_user(&env, &user);
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &1000i128);
@@ -544,7 +586,7 @@ fn test_get_portfolio() {
 #[test]
 fn test_portfolio_value_calculation() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
@@ -568,15 +610,16 @@ fn test_portfolio_value_calculation() {
 #[test]
 fn test_grant_authorization_success() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
-        let res = AutoTradeContract::grant_authorization(env.clone(), user.clone(), 500_0000000, 30);
+        let res =
+            AutoTradeContract::grant_authorization(env.clone(), user.clone(), 500_0000000, 30);
         assert!(res.is_ok());
 
         let config = AutoTradeContract::get_auth_config(env.clone(), user.clone()).unwrap();
-        assert_eq!(config.authorized, true);
+        assert!(config.authorized);
         assert_eq!(config.max_trade_amount, 500_0000000);
         assert_eq!(config.expires_at, 1000 + (30 * 86400));
     });
@@ -585,7 +628,7 @@ fn test_grant_authorization_success() {
 #[test]
 fn test_grant_authorization_zero_amount() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
@@ -597,14 +640,17 @@ fn test_grant_authorization_zero_amount() {
 #[test]
 fn test_revoke_authorization() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
         AutoTradeContract::grant_authorization(env.clone(), user.clone(), 1000_0000000, 30)
             .unwrap();
+    });
+    env.as_contract(&contract_id, || {
         AutoTradeContract::revoke_authorization(env.clone(), user.clone()).unwrap();
-
+    });
+    env.as_contract(&contract_id, || {
         let config = AutoTradeContract::get_auth_config(env.clone(), user.clone());
         assert!(config.is_none());
     });
@@ -613,15 +659,16 @@ fn test_revoke_authorization() {
 #[test]
 fn test_trade_under_limit_succeeds() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 500_0000000, 30)
-            .unwrap();
+        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 500_0000000, 30).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &1000_0000000i128);
@@ -643,15 +690,16 @@ fn test_trade_under_limit_succeeds() {
 #[test]
 fn test_trade_over_limit_fails() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 500_0000000, 30)
-            .unwrap();
+        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 500_0000000, 30).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &1000_0000000i128);
@@ -670,7 +718,7 @@ fn test_trade_over_limit_fails() {
 #[test]
 fn test_revoked_authorization_blocks_trade() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
@@ -679,8 +727,11 @@ fn test_revoked_authorization_blocks_trade() {
         storage::set_signal(&env, signal_id, &signal);
         AutoTradeContract::grant_authorization(env.clone(), user.clone(), 1000_0000000, 30)
             .unwrap();
+    });
+    env.as_contract(&contract_id, || {
         AutoTradeContract::revoke_authorization(env.clone(), user.clone()).unwrap();
-
+    });
+    env.as_contract(&contract_id, || {
         let res = AutoTradeContract::execute_trade(
             env.clone(),
             user.clone(),
@@ -695,7 +746,7 @@ fn test_revoked_authorization_blocks_trade() {
 #[test]
 fn test_expired_authorization_blocks_trade() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 100000);
@@ -703,9 +754,9 @@ fn test_expired_authorization_blocks_trade() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         // Grant with 1 day duration
-        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 1000_0000000, 1)
-            .unwrap();
-
+        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 1000_0000000, 1).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         // Fast forward time beyond expiry
         env.ledger().set_timestamp(1000 + 86400 + 1);
 
@@ -723,15 +774,17 @@ fn test_expired_authorization_blocks_trade() {
 #[test]
 fn test_multiple_authorization_grants_latest_applies() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
 
     env.as_contract(&contract_id, || {
-        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 500_0000000, 30)
-            .unwrap();
+        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 500_0000000, 30).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         AutoTradeContract::grant_authorization(env.clone(), user.clone(), 1000_0000000, 60)
             .unwrap();
-
+    });
+    env.as_contract(&contract_id, || {
         let config = AutoTradeContract::get_auth_config(env.clone(), user.clone()).unwrap();
         assert_eq!(config.max_trade_amount, 1000_0000000);
         assert_eq!(config.expires_at, 1000 + (60 * 86400));
@@ -741,15 +794,16 @@ fn test_multiple_authorization_grants_latest_applies() {
 #[test]
 fn test_authorization_at_exact_limit() {
     let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
+    let contract_id = register_contract(&env);
     let user = Address::generate(&env);
     let signal_id = 1;
     let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
 
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
-        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 500_0000000, 30)
-            .unwrap();
+        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 500_0000000, 30).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         env.storage()
             .temporary()
             .set(&(user.clone(), symbol_short!("balance")), &1000_0000000i128);
@@ -767,3 +821,495 @@ fn test_authorization_at_exact_limit() {
         assert!(res.is_ok());
     });
 }
+
+// ========================================
+// TTL Tests
+// ========================================
+
+#[test]
+fn test_bump_ttl_user_entries() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+    use stellar_swipe_common::{TtlClass, DAY_IN_LEDGERS};
+
+    let env = setup_env();
+    let contract_id = register_contract(&env);
+    let user = Address::generate(&env);
+    let auth_key = auth::AuthKey::Authorization(user.clone());
+
+    env.as_contract(&contract_id, || {
+        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 1000_0000000, 60)
+            .unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        AutoTradeContract::set_risk_config(env.clone(), user.clone(), risk::RiskConfig::default());
+
+        let ttl = env.storage().persistent().get_ttl(&auth_key);
+        assert_eq!(ttl, TtlClass::Hot.extend_to());
+    });
+
+    // Let most of the TTL run out
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 25 * DAY_IN_LEDGERS);
+
+    env.as_contract(&contract_id, || {
+        let mut keys = soroban_sdk::Vec::new(&env);
+        keys.push_back(TtlKey::User(user.clone()));
+        keys.push_back(TtlKey::Trade(user.clone(), 1)); // missing, skipped
+        let bumped = AutoTradeContract::bump_ttl(env.clone(), keys).unwrap();
+        assert_eq!(bumped, 2); // risk config + authorization
+
+        let ttl = env.storage().persistent().get_ttl(&auth_key);
+        assert_eq!(ttl, TtlClass::Hot.extend_to());
+    });
+}
+
+#[test]
+fn test_bump_ttl_too_many_keys() {
+    let env = setup_env();
+    let contract_id = register_contract(&env);
+
+    env.as_contract(&contract_id, || {
+        let mut keys = soroban_sdk::Vec::new(&env);
+        for _ in 0..(stellar_swipe_common::MAX_BUMP_KEYS + 1) {
+            keys.push_back(TtlKey::Instance);
+        }
+        let res = AutoTradeContract::bump_ttl(env.clone(), keys);
+        assert_eq!(res, Err(AutoTradeError::TooManyKeys));
+    });
+}
+
+#[test]
+fn test_constructor_sets_admin_and_schema_version() {
+    let env = setup_env();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(AutoTradeContract, (admin.clone(),));
+    let client = AutoTradeContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_schema_version(), upgrade::SCHEMA_VERSION);
+    env.as_contract(&contract_id, || {
+        assert_eq!(upgrade::get_admin(&env), Ok(admin.clone()));
+    });
+
+    let status = client.migrate(&10);
+    assert_eq!(status.schema_version, upgrade::SCHEMA_VERSION);
+    assert_eq!(status.remaining, 0);
+}
+
+fn setup_strategy(env: &Env, strategy_id: u64) -> strategy::Strategy {
+    let mut legs = soroban_sdk::Vec::new(env);
+    legs.push_back(strategy::StrategyLeg {
+        base_asset: 1,
+        price: 100,
+        weight_bps: 5000,
+        is_sell: false,
+    });
+    legs.push_back(strategy::StrategyLeg {
+        base_asset: 2,
+        price: 200,
+        weight_bps: 5000,
+        is_sell: true,
+    });
+    strategy::Strategy {
+        strategy_id,
+        expiry: env.ledger().timestamp() + 1000,
+        legs,
+    }
+}
+
+#[test]
+fn test_execute_strategy_fills_all_legs() {
+    let env = setup_env();
+    let contract_id = register_contract(&env);
+    let user = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        strategy::set_strategy(&env, &setup_strategy(&env, 7));
+        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 1000, 30).unwrap();
+        env.storage()
+            .temporary()
+            .set(&(user.clone(), symbol_short!("balance")), &1000i128);
+    });
+
+    env.as_contract(&contract_id, || {
+        let trade = AutoTradeContract::execute_strategy(
+            env.clone(),
+            user.clone(),
+            7,
+            OrderType::Market,
+            800,
+        )
+        .unwrap();
+        assert_eq!(trade.legs.len(), 2);
+        assert_eq!(trade.legs.get(0).unwrap().executed_amount, 400);
+        assert_eq!(trade.legs.get(1).unwrap().executed_amount, 400);
+
+        let positions = AutoTradeContract::get_user_positions(env.clone(), user.clone());
+        assert_eq!(positions.get(1).unwrap().amount, 400);
+        assert_eq!(positions.get(2).unwrap().amount, -400);
+        assert_eq!(
+            AutoTradeContract::get_strategy_trade(env.clone(), user.clone(), 7),
+            Some(trade)
+        );
+    });
+}
+
+#[test]
+fn test_execute_strategy_is_all_or_nothing() {
+    let env = setup_env();
+    let contract_id = register_contract(&env);
+    let user = Address::generate(&env);
+    let balance_key = (user.clone(), symbol_short!("balance"));
+
+    env.as_contract(&contract_id, || {
+        strategy::set_strategy(&env, &setup_strategy(&env, 7));
+        AutoTradeContract::grant_authorization(env.clone(), user.clone(), 1000, 30).unwrap();
+        env.storage().temporary().set(&balance_key, &1000i128);
+        // The first leg has the depth to fill, the second does not
+        env.storage()
+            .temporary()
+            .set(&(symbol_short!("liquidity"), 1u32), &1000i128);
+        env.storage()
+            .temporary()
+            .set(&(symbol_short!("liquidity"), 2u32), &300i128);
+    });
+
+    env.as_contract(&contract_id, || {
+        let res = AutoTradeContract::execute_strategy(
+            env.clone(),
+            user.clone(),
+            7,
+            OrderType::Market,
+            800,
+        );
+        assert_eq!(res, Err(AutoTradeError::StrategyLegFailed));
+
+        assert_eq!(
+            env.storage().temporary().get::<_, i128>(&balance_key),
+            Some(1000)
+        );
+        assert!(AutoTradeContract::get_user_positions(env.clone(), user.clone()).is_empty());
+        assert_eq!(risk::get_asset_price(&env, 1), None);
+        assert_eq!(risk::get_asset_price(&env, 2), None);
+        assert_eq!(
+            AutoTradeContract::get_trade_history_legacy(env.clone(), user.clone()).len(),
+            0
+        );
+        assert_eq!(
+            AutoTradeContract::get_trade_history(env.clone(), user.clone(), 0, 10).len(),
+            0
+        );
+        assert_eq!(
+            AutoTradeContract::get_strategy_trade(env.clone(), user.clone(), 7),
+            None
+        );
+    });
+
+    // With depth on both legs the same order goes through
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .temporary()
+            .set(&(symbol_short!("liquidity"), 2u32), &1000i128);
+    });
+    env.as_contract(&contract_id, || {
+        let trade = AutoTradeContract::execute_strategy(
+            env.clone(),
+            user.clone(),
+            7,
+            OrderType::Market,
+            800,
+        )
+        .unwrap();
+        assert_eq!(trade.legs.len(), 2);
+        assert_eq!(risk::get_asset_price(&env, 2), Some(200));
+    });
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            AutoTradeContract::execute_strategy(
+                env.clone(),
+                user.clone(),
+                8,
+                OrderType::Market,
+                800
+            ),
+            Err(AutoTradeError::StrategyNotFound)
+        );
+    });
+}
+
+/// Stand-in for the signal registry: preferences allow odd ids only
+#[contract]
+struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn signal_matches_preferences(_env: Env, _user: Address, signal_id: u64) -> bool {
+        signal_id % 2 == 1
+    }
+
+    /// Signal 3 is a Hold call
+    pub fn is_neutral_signal(_env: Env, signal_id: u64) -> bool {
+        signal_id == 3
+    }
+
+    pub fn strategy_matches_preferences(_env: Env, _user: Address, strategy_id: u64) -> bool {
+        strategy_id % 2 == 1
+    }
+
+    /// Strategy 9 is live and 11 was cancelled; no others exist
+    pub fn get_strategy_signal(env: Env, strategy_id: u64) -> Option<registry::RegistryStrategy> {
+        let status = match strategy_id {
+            9 => registry::RegistrySignalStatus::Active,
+            11 => registry::RegistrySignalStatus::Cancelled,
+            _ => return None,
+        };
+        let leg = |pair: &str, action, price| registry::RegistryStrategyLeg {
+            asset_pair: String::from_str(&env, pair),
+            action,
+            price,
+            weight_bps: 5000,
+        };
+        Some(registry::RegistryStrategy {
+            id: strategy_id,
+            provider: Address::generate(&env),
+            rationale: String::from_str(&env, "Pair trade"),
+            legs: soroban_sdk::Vec::from_array(
+                &env,
+                [
+                    leg("XLM/USDC", registry::RegistrySignalAction::Buy, 100),
+                    leg("BTC/USDC", registry::RegistrySignalAction::Sell, 200),
+                ],
+            ),
+            timestamp: env.ledger().timestamp(),
+            expiry: env.ledger().timestamp() + 1000,
+            status,
+            roi: None,
+        })
+    }
+}
+
+#[test]
+fn test_copies_respect_registry_preferences() {
+    let env = setup_env();
+    let contract_id = register_contract(&env);
+    let client = AutoTradeContractClient::new(&env, &contract_id);
+    let registry_id = env.register(MockRegistry, ());
+    client.set_signal_registry(&registry_id);
+    assert_eq!(client.get_signal_registry(), Some(registry_id));
+
+    let user = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 1000;
+
+    env.as_contract(&contract_id, || {
+        for signal_id in [1, 2] {
+            storage::set_signal(&env, signal_id, &setup_signal(&env, signal_id, expiry));
+            env.storage()
+                .temporary()
+                .set(&(symbol_short!("liquidity"), signal_id), &500i128);
+        }
+        strategy::set_strategy(&env, &setup_strategy(&env, 8));
+        authorize_user(&env, &user);
+        env.storage()
+            .temporary()
+            .set(&(user.clone(), symbol_short!("balance")), &1000i128);
+    });
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            AutoTradeContract::execute_trade(env.clone(), user.clone(), 2, OrderType::Market, 100),
+            Err(AutoTradeError::FilteredByPreferences)
+        );
+    });
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            AutoTradeContract::execute_strategy(
+                env.clone(),
+                user.clone(),
+                8,
+                OrderType::Market,
+                100
+            ),
+            Err(AutoTradeError::FilteredByPreferences)
+        );
+    });
+    env.as_contract(&contract_id, || {
+        let res =
+            AutoTradeContract::execute_trade(env.clone(), user.clone(), 1, OrderType::Market, 100)
+                .unwrap();
+        assert_eq!(res.trade.status, TradeStatus::Filled);
+    });
+}
+
+#[test]
+fn test_strategy_is_read_from_registry() {
+    let env = setup_env();
+    let contract_id = register_contract(&env);
+    let client = AutoTradeContractClient::new(&env, &contract_id);
+    client.set_signal_registry(&env.register(MockRegistry, ()));
+    let user = Address::generate(&env);
+    let xlm = String::from_str(&env, "XLM/USDC");
+    let btc = String::from_str(&env, "BTC/USDC");
+
+    env.as_contract(&contract_id, || {
+        authorize_user(&env, &user);
+        env.storage()
+            .temporary()
+            .set(&(user.clone(), symbol_short!("balance")), &1000i128);
+    });
+
+    // Legs on pairs without an asset id cannot be copied
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            AutoTradeContract::execute_strategy(
+                env.clone(),
+                user.clone(),
+                9,
+                OrderType::Market,
+                800
+            ),
+            Err(AutoTradeError::UnknownAsset)
+        );
+        assert_eq!(strategy::get_strategy(&env, 9), None);
+    });
+    client.set_registry_asset(&xlm, &1);
+    client.set_registry_asset(&btc, &2);
+    assert_eq!(client.get_registry_asset(&btc), Some(2));
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            AutoTradeContract::execute_strategy(
+                env.clone(),
+                user.clone(),
+                11,
+                OrderType::Market,
+                800
+            ),
+            Err(AutoTradeError::StrategyNotActive)
+        );
+    });
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            AutoTradeContract::execute_strategy(
+                env.clone(),
+                user.clone(),
+                13,
+                OrderType::Market,
+                800
+            ),
+            Err(AutoTradeError::StrategyNotFound)
+        );
+    });
+
+    env.as_contract(&contract_id, || {
+        let trade = AutoTradeContract::execute_strategy(
+            env.clone(),
+            user.clone(),
+            9,
+            OrderType::Market,
+            800,
+        )
+        .unwrap();
+        assert_eq!(trade.legs.len(), 2);
+
+        let cached = strategy::get_strategy(&env, 9).unwrap();
+        assert_eq!(cached.legs.get(0).unwrap().base_asset, 1);
+        assert!(!cached.legs.get(0).unwrap().is_sell);
+        assert_eq!(cached.legs.get(1).unwrap().base_asset, 2);
+        assert!(cached.legs.get(1).unwrap().is_sell);
+
+        let positions = AutoTradeContract::get_user_positions(env.clone(), user.clone());
+        assert_eq!(positions.get(1).unwrap().amount, 400);
+        assert_eq!(positions.get(2).unwrap().amount, -400);
+    });
+}
+
+#[test]
+fn test_strategy_trades_do_not_collide_with_signal_trades() {
+    let env = setup_env();
+    let contract_id = register_contract(&env);
+    let user = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        storage::set_signal(
+            &env,
+            7,
+            &setup_signal(&env, 7, env.ledger().timestamp() + 1000),
+        );
+        strategy::set_strategy(&env, &setup_strategy(&env, 7));
+        authorize_user(&env, &user);
+        risk::set_risk_config(
+            &env,
+            &user,
+            &risk::RiskConfig {
+                max_position_pct: 100,
+                daily_trade_limit: 10,
+                stop_loss_pct: 15,
+            },
+        );
+        env.storage()
+            .temporary()
+            .set(&(user.clone(), symbol_short!("balance")), &1000i128);
+    });
+
+    env.as_contract(&contract_id, || {
+        AutoTradeContract::execute_trade(env.clone(), user.clone(), 7, OrderType::Market, 100)
+            .unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        AutoTradeContract::execute_strategy(env.clone(), user.clone(), 7, OrderType::Market, 800)
+            .unwrap();
+
+        let tagged = strategy::trade_record_id(7);
+        assert_ne!(tagged, 7);
+        let history = AutoTradeContract::get_trade_history(env.clone(), user.clone(), 0, 10);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.get(0).unwrap().signal_id, tagged);
+        assert_eq!(history.get(1).unwrap().signal_id, tagged);
+        assert_eq!(history.get(2).unwrap().signal_id, 7);
+
+        let records = AutoTradeContract::get_trade_history_legacy(env.clone(), user.clone());
+        let signal_records = records.iter().filter(|r| r.signal_id == 7).count();
+        assert_eq!(signal_records, 1);
+        assert_eq!(
+            AutoTradeContract::get_trade(env.clone(), user.clone(), 7)
+                .unwrap()
+                .executed_amount,
+            100
+        );
+    });
+}
+
+#[test]
+fn test_neutral_flag_comes_from_registry() {
+    let env = setup_env();
+    let contract_id = register_contract(&env);
+    let user = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 1000;
+
+    env.as_contract(&contract_id, || {
+        storage::set_signal(&env, 3, &setup_signal(&env, 3, expiry));
+        authorize_user(&env, &user);
+        env.storage()
+            .temporary()
+            .set(&(user.clone(), symbol_short!("balance")), &1000i128);
+    });
+
+    // Without a registry nothing marks the signal neutral
+    env.as_contract(&contract_id, || {
+        assert!(!registry::is_neutral_signal(&env, 3));
+    });
+
+    AutoTradeContractClient::new(&env, &contract_id)
+        .set_signal_registry(&env.register(MockRegistry, ()));
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            AutoTradeContract::execute_trade(env.clone(), user.clone(), 3, OrderType::Market, 100),
+            Err(AutoTradeError::NeutralSignal)
+        );
+        assert!(storage::is_neutral_signal(&env, 3));
+        assert_eq!(
+            AutoTradeContract::get_trade(env.clone(), user.clone(), 3),
+            None
+        );
+    });
+}
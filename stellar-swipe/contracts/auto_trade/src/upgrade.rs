@@ -0,0 +1,64 @@
+//! Contract upgrades and storage schema migration.
+//!
+//! Schema versions:
+//! - 1: current layout (per-user trade, history, risk and auth entries).
+//!
+//! There are no records to rewrite yet; `migrate` stamps the schema version on
+//! deployments that predate versioning and is where future layout changes go.
+
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+use stellar_swipe_common::{
+    get_stored_schema_version, set_schema_version, upgrade_contract, MigrationStatus,
+};
+
+use crate::errors::AutoTradeError;
+use crate::storage::DataKey;
+
+/// Schema version written by this code
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Set the admin allowed to upgrade the contract. Runs once, at deployment.
+pub fn init_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+    set_schema_version(env, SCHEMA_VERSION);
+}
+
+pub fn get_admin(env: &Env) -> Result<Address, AutoTradeError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(AutoTradeError::NotInitialized)
+}
+
+/// Replace the contract Wasm. Requires the admin's auth.
+pub fn upgrade(env: &Env, wasm_hash: BytesN<32>) -> Result<(), AutoTradeError> {
+    let admin = get_admin(env)?;
+    admin.require_auth();
+
+    upgrade_contract(env, wasm_hash.clone());
+
+    #[allow(deprecated)]
+    env.events()
+        .publish((Symbol::new(env, "contract_upgraded"), admin), wasm_hash);
+
+    Ok(())
+}
+
+/// Schema version of the data in storage (unversioned deployments are v1)
+pub fn get_schema_version(env: &Env) -> u32 {
+    get_stored_schema_version(env).unwrap_or(1)
+}
+
+/// Rewrite up to `batch` old-layout records. Resumable and permissionless.
+pub fn migrate(env: &Env, _batch: u32) -> MigrationStatus {
+    if get_stored_schema_version(env) != Some(SCHEMA_VERSION) {
+        set_schema_version(env, SCHEMA_VERSION);
+    }
+
+    MigrationStatus {
+        schema_version: get_schema_version(env),
+        target_version: SCHEMA_VERSION,
+        migrated: 0,
+        remaining: 0,
+    }
+}
@@ -182,6 +182,46 @@ pub fn validate_asset_pair(_env: &Env, asset_pair: &String) -> Result<(), AssetP
     Ok(())
 }
 
+/// Build an `Asset` from a validated "XLM" or "CODE:ISSUER" part
+fn parse_asset_part(env: &Env, bytes: &Bytes, start: u32, end: u32) -> Asset {
+    let mut buf = [0u8; 128];
+    let len = (end - start) as usize;
+    bytes.slice(start..end).copy_into_slice(&mut buf[..len]);
+
+    match buf[..len].iter().position(|b| *b == b':') {
+        None => Asset {
+            code: String::from_bytes(env, &buf[..len]),
+            issuer: None,
+        },
+        Some(colon_at) => Asset {
+            code: String::from_bytes(env, &buf[..colon_at]),
+            issuer: Some(Address::from_string(&String::from_bytes(
+                env,
+                &buf[colon_at + 1..len],
+            ))),
+        },
+    }
+}
+
+/// Parse a validated asset pair string into base and quote assets.
+///
+/// Issuers must be valid account strkeys; the format check in
+/// `validate_asset_pair` does not verify the checksum.
+pub fn parse_asset_pair(env: &Env, asset_pair: &String) -> Result<AssetPair, AssetPairError> {
+    validate_asset_pair(env, asset_pair)?;
+
+    let bytes = asset_pair.clone().to_bytes();
+    let mut slash_at = 0;
+    while bytes.get(slash_at).unwrap() != b'/' {
+        slash_at += 1;
+    }
+
+    Ok(AssetPair {
+        base: parse_asset_part(env, &bytes, 0, slash_at),
+        quote: parse_asset_part(env, &bytes, slash_at + 1, bytes.len()),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,4 +311,30 @@ mod tests {
         );
         assert!(validate_asset_pair(&env, &pair).is_ok());
     }
+
+    #[test]
+    fn test_parse_asset_pair() {
+        let env = Env::default();
+        let issuer = "GDUKMGUGDZQK6YHYA5Z6AY2G4XDSZPSZ3SW5UN3ARVMO6QSRDWP5YLEX";
+        let pair = parse_asset_pair(
+            &env,
+            &s(
+                &env,
+                "XLM/USDC:GDUKMGUGDZQK6YHYA5Z6AY2G4XDSZPSZ3SW5UN3ARVMO6QSRDWP5YLEX",
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(pair.base.code, s(&env, "XLM"));
+        assert_eq!(pair.base.issuer, None);
+        assert_eq!(pair.quote.code, s(&env, "USDC"));
+        assert_eq!(
+            pair.quote.issuer,
+            Some(Address::from_string(&s(&env, issuer)))
+        );
+        assert_eq!(
+            parse_asset_pair(&env, &s(&env, "XLM")),
+            Err(AssetPairError::InvalidFormat)
+        );
+    }
 }
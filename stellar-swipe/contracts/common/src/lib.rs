@@ -1,5 +1,11 @@
 #![no_std]
 
 pub mod assets;
+pub mod ttl;
+pub mod upgrade;
 
-pub use assets::{validate_asset_pair, Asset, AssetPair, AssetPairError};
+pub use assets::{parse_asset_pair, validate_asset_pair, Asset, AssetPair, AssetPairError};
+pub use ttl::{bump_instance, bump_persistent, TtlClass, DAY_IN_LEDGERS, MAX_BUMP_KEYS};
+pub use upgrade::{
+    get_stored_schema_version, set_schema_version, upgrade_contract, MigrationStatus, UpgradeKey,
+};
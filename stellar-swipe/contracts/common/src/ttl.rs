@@ -0,0 +1,68 @@
+//! Storage TTL (rent) policy shared by all contracts.
+//!
+//! Every persistent entry belongs to a data class that decides how far its
+//! TTL is extended and when. Hot entries (live signals, indexes, positions,
+//! auth configs) are bumped on every read and write. History entries (settled
+//! signals, executed trades) are bumped on write and through the `bump_ttl`
+//! maintenance entrypoints. Archived data is never bumped and is left to expire.
+
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// ~24 hours at 5s per ledger
+pub const DAY_IN_LEDGERS: u32 = 17280;
+
+/// Maximum keys accepted by a single `bump_ttl` call
+pub const MAX_BUMP_KEYS: u32 = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TtlClass {
+    /// Contract instance (config, counters, admin state)
+    Instance,
+    /// Entries touched on most calls
+    Hot,
+    /// Records kept for queries and analytics but rarely written
+    History,
+}
+
+impl TtlClass {
+    /// Remaining TTL (in ledgers) below which an entry is extended
+    pub const fn threshold(self) -> u32 {
+        match self {
+            TtlClass::Instance => 7 * DAY_IN_LEDGERS,
+            TtlClass::Hot => 7 * DAY_IN_LEDGERS,
+            TtlClass::History => 30 * DAY_IN_LEDGERS,
+        }
+    }
+
+    /// TTL (in ledgers) an entry is extended to
+    pub const fn extend_to(self) -> u32 {
+        match self {
+            TtlClass::Instance => 30 * DAY_IN_LEDGERS,
+            TtlClass::Hot => 30 * DAY_IN_LEDGERS,
+            TtlClass::History => 120 * DAY_IN_LEDGERS,
+        }
+    }
+}
+
+/// Extend the contract instance TTL
+pub fn bump_instance(env: &Env) {
+    let class = TtlClass::Instance;
+    env.storage()
+        .instance()
+        .extend_ttl(class.threshold(), class.extend_to());
+}
+
+/// Extend a persistent entry's TTL according to its class.
+/// Returns false if the entry does not exist.
+pub fn bump_persistent<K>(env: &Env, key: &K, class: TtlClass) -> bool
+where
+    K: IntoVal<Env, Val>,
+{
+    if !env.storage().persistent().has(key) {
+        return false;
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(key, class.threshold(), class.extend_to());
+    true
+}
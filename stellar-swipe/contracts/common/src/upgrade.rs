@@ -0,0 +1,46 @@
+//! Contract upgrade and storage schema versioning shared by all contracts.
+//!
+//! Each contract stores the schema version its data is laid out in. After a
+//! Wasm upgrade that changes the layout, the contract's `migrate(batch)`
+//! entrypoint rewrites old records in resumable batches and bumps the stored
+//! version once nothing is left to convert.
+
+use soroban_sdk::{contracttype, BytesN, Env};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum UpgradeKey {
+    /// Schema version of the data in storage (instance)
+    SchemaVersion,
+}
+
+/// Progress of a `migrate` call
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationStatus {
+    /// Schema version after this call
+    pub schema_version: u32,
+    /// Schema version the running code expects
+    pub target_version: u32,
+    /// Records rewritten by this call
+    pub migrated: u32,
+    /// Records still in the old layout
+    pub remaining: u32,
+}
+
+/// Stored schema version, if one was ever written
+pub fn get_stored_schema_version(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&UpgradeKey::SchemaVersion)
+}
+
+/// Record the schema version of the data in storage
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&UpgradeKey::SchemaVersion, &version);
+}
+
+/// Replace the running contract's Wasm. Callers are responsible for auth.
+pub fn upgrade_contract(env: &Env, wasm_hash: BytesN<32>) {
+    env.deployer().update_current_contract_wasm(wasm_hash);
+}
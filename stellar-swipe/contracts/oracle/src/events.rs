@@ -1,4 +1,11 @@
-use soroban_sdk::{symbol_short, Address, Env};
+use soroban_sdk::{symbol_short, Address, BytesN, Env};
+
+pub fn emit_contract_upgraded(env: &Env, admin: Address, wasm_hash: BytesN<32>) {
+    env.events().publish(
+        (symbol_short!("contract"), symbol_short!("upgraded")),
+        (admin, wasm_hash),
+    );
+}
 
 pub fn emit_oracle_removed(env: &Env, oracle: Address, reason: &str) {
     env.events().publish(
@@ -1,227 +1,37 @@
 #![no_std]
 
- feature/signal-categorization-tagging
- feature/signal-categorization-tagging
-=======
- feature/oracle-price-conversion
- main
 mod conversion;
 mod errors;
-mod storage;
-
-use soroban_sdk::{contract, contractimpl, Address, Env};
-use common::{Asset, AssetPair};
-use errors::OracleError;
-
-pub use conversion::{convert_to_base, ConversionPath};
-pub use storage::{get_base_currency, set_base_currency, get_price, set_price};
-=======
-mod errors;
+#[allow(deprecated)]
 mod events;
 mod reputation;
+mod storage;
 mod types;
+mod upgrade;
 
+use common::{set_schema_version, Asset, AssetPair, MigrationStatus};
 use errors::OracleError;
 use reputation::{
     adjust_oracle_weight, calculate_reputation, get_oracle_stats, should_remove_oracle,
-    slash_oracle, track_oracle_accuracy, SlashReason,
+    slash_oracle, track_oracle_accuracy, SlashReason, MAJOR_DEVIATION_THRESHOLD,
 };
-use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
- feature/signal-categorization-tagging
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
 use types::{ConsensusPriceData, OracleReputation, PriceSubmission, StorageKey};
- main
-=======
-use types::{ConsensusPriceData, OracleReputation, PriceSubmission, StorageKey}; main
- main
+
+pub use conversion::{convert_to_base, ConversionPath};
+pub use storage::{get_base_currency, get_price, set_base_currency, set_price};
+pub use upgrade::SCHEMA_VERSION;
 
 #[contract]
 pub struct OracleContract;
 
 #[contractimpl]
 impl OracleContract {
- feature/signal-categorization-tagging
- feature/signal-categorization-tagging
-=======
- feature/oracle-price-conversion
- main
-    /// Initialize oracle with base currency
-    pub fn initialize(env: Env, admin: Address, base_currency: Asset) {
-        storage::set_base_currency(&env, base_currency);
-    }
-
-    /// Set price for an asset pair
-    pub fn set_price(env: Env, pair: AssetPair, price: i128) -> Result<(), OracleError> {
-        if price <= 0 {
-            return Err(OracleError::InvalidAsset);
-        }
-        storage::set_price(&env, &pair, price);
-        storage::add_available_pair(&env, pair);
-        Ok(())
-    }
-
-    /// Get price for an asset pair
-    pub fn get_price(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
-        storage::get_price(&env, &pair)
-    }
-
-    /// Convert amount to base currency
-    pub fn convert_to_base(env: Env, amount: i128, asset: Asset) -> Result<i128, OracleError> {
-        // Check cache first
-        let base = storage::get_base_currency(&env);
-        if let Some(cached) = storage::get_cached_conversion(&env, &asset, &base) {
-            return Ok(amount.checked_mul(cached.rate)
-                .and_then(|v| v.checked_div(10_000_000))
-                .ok_or(OracleError::ConversionOverflow)?);
-        }
-
-        // Perform conversion
-        let result = conversion::convert_to_base(&env, amount, asset.clone())?;
-        
-        // Cache the rate
-        if amount > 0 {
-            let rate = result.checked_mul(10_000_000)
-                .and_then(|v| v.checked_div(amount))
-                .unwrap_or(0);
-            if rate > 0 {
-                storage::set_cached_conversion(&env, &asset, &base, rate);
-            }
-        }
-        
-        Ok(result)
-    }
-
-    /// Get base currency
-    pub fn get_base_currency(env: Env) -> Asset {
-        storage::get_base_currency(&env)
-    }
-
-    /// Set base currency (admin only)
-    pub fn set_base_currency(env: Env, asset: Asset) {
-        storage::set_base_currency(&env, asset);
-    }
-
-    /// Add available trading pair
-    pub fn add_pair(env: Env, pair: AssetPair) {
-        storage::add_available_pair(&env, pair);
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use soroban_sdk::{testutils::Address as _, String};
-
-    fn xlm(env: &Env) -> Asset {
-        Asset {
-            code: String::from_str(env, "XLM"),
-            issuer: None,
-        }
-    }
-
-    fn usdc(env: &Env) -> Asset {
-        Asset {
-            code: String::from_str(env, "USDC"),
-            issuer: Some(Address::generate(env)),
-        }
-    }
-
-    fn token(env: &Env, code: &str) -> Asset {
-        Asset {
-            code: String::from_str(env, code),
-            issuer: Some(Address::generate(env)),
-        }
-    }
-
-    #[test]
-    fn test_initialize_and_get_base() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, OracleContract);
-        let client = OracleContractClient::new(&env, &contract_id);
-        let admin = Address::generate(&env);
-
-        client.initialize(&admin, &xlm(&env));
-        let base = client.get_base_currency();
-        assert_eq!(base.code, String::from_str(&env, "XLM"));
-    }
-
-    #[test]
-    fn test_set_and_get_price() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, OracleContract);
-        let client = OracleContractClient::new(&env, &contract_id);
-
-        let pair = AssetPair {
-            base: usdc(&env),
-            quote: xlm(&env),
-        };
-        
-        client.set_price(&pair, &10_000_000); // 1 USDC = 1 XLM
-        let price = client.get_price(&pair).unwrap();
-        assert_eq!(price, 10_000_000);
-    }
-
-    #[test]
-    fn test_convert_to_base_direct() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, OracleContract);
-        let client = OracleContractClient::new(&env, &contract_id);
-        let admin = Address::generate(&env);
-
-        let xlm = xlm(&env);
-        let usdc = usdc(&env);
-
-        client.initialize(&admin, &xlm);
-
-        // Set price: 1 USDC = 10 XLM
-        let pair = AssetPair {
-            base: usdc.clone(),
-            quote: xlm.clone(),
-        };
-        client.set_price(&pair, &100_000_000); // 10 XLM per USDC
-
-        // Convert 100 USDC to XLM
-        let result = client.convert_to_base(&100_0000000, &usdc).unwrap();
-        assert_eq!(result, 1000_0000000); // 1000 XLM
-    }
-
-    #[test]
-    fn test_convert_same_asset() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, OracleContract);
-        let client = OracleContractClient::new(&env, &contract_id);
-        let admin = Address::generate(&env);
-
-        let xlm = xlm(&env);
-        client.initialize(&admin, &xlm);
-
-        let result = client.convert_to_base(&1000_0000000, &xlm).unwrap();
-        assert_eq!(result, 1000_0000000);
-    }
-
-    #[test]
-    fn test_base_currency_change() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, OracleContract);
-        let client = OracleContractClient::new(&env, &contract_id);
-        let admin = Address::generate(&env);
-
-        let xlm = xlm(&env);
-        let usdc = usdc(&env);
-
-        client.initialize(&admin, &xlm);
-        assert_eq!(client.get_base_currency().code, String::from_str(&env, "XLM"));
-
-        client.set_base_currency(&usdc);
-        assert_eq!(client.get_base_currency().code, String::from_str(&env, "USDC"));
-    }
-}
-=======
-    /// Initialize the contract with an admin
-    pub fn initialize(env: Env, admin: Address) {
-        if env.storage().instance().has(&StorageKey::Admin) {
-            panic!("already initialized");
-        }
+    /// Set the admin at deploy time, so nobody can claim the contract
+    /// between deployment and setup
+    pub fn __constructor(env: Env, admin: Address) {
         env.storage().instance().set(&StorageKey::Admin, &admin);
+        set_schema_version(&env, upgrade::SCHEMA_VERSION);
     }
 
     /// Register a new oracle
@@ -308,7 +118,7 @@ mod tests {
 
             // Check for major deviation and slash if needed
             let deviation = ((submission.price - consensus_price).abs() * 10000) / consensus_price;
-            if deviation > 2000 {
+            if deviation > MAJOR_DEVIATION_THRESHOLD {
                 // 20%
                 slash_oracle(&env, &submission.oracle, SlashReason::MajorDeviation);
                 events::emit_oracle_slashed(&env, submission.oracle.clone(), "major_deviation", 20);
@@ -396,16 +206,26 @@ mod tests {
         Ok(())
     }
 
+    /// Replace the contract Wasm (admin only)
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), OracleError> {
+        upgrade::upgrade(&env, wasm_hash)
+    }
+
+    /// Schema version of the data in storage
+    pub fn get_schema_version(env: Env) -> u32 {
+        upgrade::get_schema_version(&env)
+    }
+
+    /// Rewrite up to `batch` old-layout records into the current layout.
+    /// Permissionless and resumable; call until `remaining` is 0.
+    pub fn migrate(env: Env, batch: u32) -> MigrationStatus {
+        upgrade::migrate(&env, batch)
+    }
+
     // Internal helpers
 
     fn require_admin(env: &Env, caller: &Address) -> Result<(), OracleError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(OracleError::Unauthorized)?;
-
-        if caller != &admin {
+        if *caller != upgrade::get_admin(env)? {
             return Err(OracleError::Unauthorized);
         }
         Ok(())
@@ -473,131 +293,183 @@ mod tests {
             .set(&StorageKey::Oracles, &new_oracles);
     }
 
-    /// returns aggregated price
-    pub fn get_price(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
-        let (price, _) = Self::get_price_with_confidence(env, pair)?;
-        Ok(price)
+    /// Set price for an asset pair (admin only)
+    pub fn set_price(env: Env, pair: AssetPair, price: i128) -> Result<(), OracleError> {
+        upgrade::get_admin(&env)?.require_auth();
+        if price <= 0 {
+            return Err(OracleError::InvalidAsset);
+        }
+        storage::set_price(&env, &pair, price);
+        storage::add_available_pair(&env, pair);
+        Ok(())
     }
 
-    pub fn get_price_with_confidence(env: Env, pair: AssetPair) -> Result<(i128, u32), OracleError> {
-        let key = StorageKey::PriceMap(pair.clone());
-        let prices: Vec<PriceData> = env.storage().temporary().get(&key).ok_or(OracleError::PriceNotFound)?;
-
-        let current_time = env.ledger().timestamp();
-        let mut fresh_prices: Vec<PriceData> = Vec::new(&env);
+    /// Get price for an asset pair
+    pub fn get_price(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
+        storage::get_price(&env, &pair)
+    }
 
-        // 1. Filter stale prices (TTL: 300s / 5 mins)
-        for p in prices.iter() {
-            if current_time.saturating_sub(p.timestamp) < 300 {
-                fresh_prices.push_back(p);
-            }
+    /// Convert amount to base currency
+    pub fn convert_to_base(env: Env, amount: i128, asset: Asset) -> Result<i128, OracleError> {
+        // Check cache first
+        let base = storage::get_base_currency(&env);
+        if let Some(cached) = storage::get_cached_conversion(&env, &asset, &base) {
+            return amount
+                .checked_mul(cached.rate)
+                .and_then(|v| v.checked_div(10_000_000))
+                .ok_or(OracleError::ConversionOverflow);
         }
 
-        if fresh_prices.is_empty() {
-            return Err(OracleError::StalePrice);
-        }
+        // Perform conversion
+        let result = conversion::convert_to_base(&env, amount, asset.clone())?;
 
-        // 2. Median Aggregation
-        // Sort by price
-        let mut sorted = fresh_prices;
-        let len = sorted.len();
-        for i in 0..len {
-            for j in 0..(len - i - 1) {
-                if sorted.get(j).unwrap().price > sorted.get(j + 1).unwrap().price {
-                    let temp = sorted.get(j).unwrap();
-                    sorted.set(j, sorted.get(j + 1).unwrap());
-                    sorted.set(j + 1, temp);
-                }
+        // Cache the rate
+        if amount > 0 {
+            let rate = result
+                .checked_mul(10_000_000)
+                .and_then(|v| v.checked_div(amount))
+                .unwrap_or(0);
+            if rate > 0 {
+                storage::set_cached_conversion(&env, &asset, &base, rate);
             }
         }
 
-        let median_data = sorted.get(len / 2).unwrap();
-        
-        // 3. Check for 10% deviation (Edge Case)
-        let min_p = sorted.get(0).unwrap().price;
-        let max_p = sorted.get(len - 1).unwrap().price;
-        if (max_p - min_p) * 100 / min_p > 10 {
-            // Price sources disagree by > 10%
-            return Err(OracleError::UnreliablePrice); 
-        }
+        Ok(result)
+    }
 
-        Ok((median_data.price, median_data.confidence))
+    /// Get base currency
+    pub fn get_base_currency(env: Env) -> Asset {
+        storage::get_base_currency(&env)
     }
 
-    pub fn add_price_source(env: Env, admin: Address, source: Address, weight: u32) -> Result<(), OracleError> {
-        admin.require_auth();
-        // Check if caller is admin (logic from your existing lib.rs)
-        Self::is_admin(&env, &admin)?;
-        
-        env.storage().persistent().set(&StorageKey::OracleWeight(source), &weight);
+    /// Set base currency (admin only)
+    pub fn set_base_currency(env: Env, asset: Asset) -> Result<(), OracleError> {
+        upgrade::get_admin(&env)?.require_auth();
+        storage::set_base_currency(&env, asset);
         Ok(())
     }
 
-    pub fn submit_price(env: Env, source: Address, pair: AssetPair, price: i128, confidence: u32) -> Result<(), OracleError> {
-        source.require_auth();
-        
-        // Ensure source is a registered oracle
-        let weight: u32 = env.storage().persistent().get(&StorageKey::OracleWeight(source.clone())).unwrap_or(0);
-        if weight == 0 { return Err(OracleError::Unauthorized); }
+    /// Add available trading pair (admin only)
+    pub fn add_pair(env: Env, pair: AssetPair) -> Result<(), OracleError> {
+        upgrade::get_admin(&env)?.require_auth();
+        storage::add_available_pair(&env, pair);
+        Ok(())
+    }
+}
 
-        let key = StorageKey::PriceMap(pair.clone());
-        let mut prices: Vec<PriceData> = env.storage().temporary().get(&key).unwrap_or(Vec::new(&env));
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, String};
 
-        let new_entry = PriceData {
-            asset_pair: pair,
-            price,
-            timestamp: env.ledger().timestamp(),
-            source,
-            confidence,
+    fn xlm(env: &Env) -> Asset {
+        Asset {
+            code: String::from_str(env, "XLM"),
+            issuer: None,
+        }
+    }
+
+    fn usdc(env: &Env) -> Asset {
+        Asset {
+            code: String::from_str(env, "USDC"),
+            issuer: Some(Address::generate(env)),
+        }
+    }
+
+    #[test]
+    fn test_set_and_get_base() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(OracleContract, (admin,));
+        let client = OracleContractClient::new(&env, &contract_id);
+
+        client.set_base_currency(&xlm(&env));
+        let base = client.get_base_currency();
+        assert_eq!(base.code, String::from_str(&env, "XLM"));
+    }
+
+    #[test]
+    fn test_set_and_get_price() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(OracleContract, (Address::generate(&env),));
+        let client = OracleContractClient::new(&env, &contract_id);
+
+        let pair = AssetPair {
+            base: usdc(&env),
+            quote: xlm(&env),
         };
 
-        prices.push_back(new_entry);
-        
-        // Cache management: Keep prices for 5 mins
-        env.storage().temporary().set(&key, &prices);
-        env.storage().temporary().extend_ttl(&key, 60, 60); 
-        
-        Ok(())
+        client.set_price(&pair, &10_000_000); // 1 USDC = 1 XLM
+        let price = client.get_price(&pair);
+        assert_eq!(price, 10_000_000);
     }
 
-    pub fn refresh_from_sdex(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
-        // 1. In a real Soroban scenario, you would interface with the 
-        // Liquidity Pool or a specialized SDEX oracle contract.
-        // For this issue, we assume we fetch the orderbook.
-        let orderbook = fetch_sdex_orderbook(&env, &pair)?;
+    #[test]
+    fn test_convert_to_base_direct() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(OracleContract, (admin,));
+        let client = OracleContractClient::new(&env, &contract_id);
+
+        let xlm = xlm(&env);
+        let usdc = usdc(&env);
 
-        // 2. Calculate price
-        let price = calculate_spot_price(&env, orderbook)?;
+        client.set_base_currency(&xlm);
 
-        // 3. Store in the PriceMap (from Issue #32)
-        let source_address = env.current_contract_address();
-        Self::submit_internal(&env, source_address, pair, price, 100)?;
+        // Set price: 1 USDC = 10 XLM
+        let pair = AssetPair {
+            base: usdc.clone(),
+            quote: xlm.clone(),
+        };
+        client.set_price(&pair, &100_000_000); // 10 XLM per USDC
 
-        Ok(price)
+        // Convert 100 USDC to XLM
+        let result = client.convert_to_base(&100_0000000, &usdc);
+        assert_eq!(result, 1000_0000000); // 1000 XLM
     }
 
-    pub fn update_with_external_data(env: Env, prices: Vec<ExternalPrice>) -> Result<i128, OracleError> {
-        // Process the external prices using our new adapter
-        let consensus_price = crate::external_adapter::process_external_prices(&env, prices)?;
+    #[test]
+    fn test_convert_same_asset() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(OracleContract, (admin,));
+        let client = OracleContractClient::new(&env, &contract_id);
 
-        // Store the result using your existing storage logic
-        // (Assuming the first price in the vec defines the pair for this update)
-        if let Some(first) = prices.get(0) {
-            storage::set_price(&env, &first.asset_pair, consensus_price);
-        }
+        let xlm = xlm(&env);
+        client.set_base_currency(&xlm);
 
-        Ok(consensus_price)
+        let result = client.convert_to_base(&1000_0000000, &xlm);
+        assert_eq!(result, 1000_0000000);
     }
 
-// Internal helper to represent the SDEX query
-fn fetch_sdex_orderbook(env: &Env, pair: &AssetPair) -> Result<OrderBook, OracleError> {
-    // Note: Actual Soroban host functions for SDEX are currently limited 
-    // to Liquidity Pool swaps. For Order Books, one typically uses 
-    // a Cross-Chain/Bridge approach or a Trusted Observer.
-    // Here we implement the interface logic.
-    unimplemented!("SDEX Orderbook Host Interface");
+    #[test]
+    fn test_base_currency_change() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(OracleContract, (admin,));
+        let client = OracleContractClient::new(&env, &contract_id);
+
+        let xlm = xlm(&env);
+        let usdc = usdc(&env);
+
+        client.set_base_currency(&xlm);
+        assert_eq!(
+            client.get_base_currency().code,
+            String::from_str(&env, "XLM")
+        );
+
+        client.set_base_currency(&usdc);
+        assert_eq!(
+            client.get_base_currency().code,
+            String::from_str(&env, "USDC")
+        );
+    }
 }
 
 #[cfg(test)]
 mod test;
- main
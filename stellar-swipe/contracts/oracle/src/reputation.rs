@@ -4,7 +4,7 @@ use crate::types::{OracleReputation, StorageKey};
 
 const ACCURACY_THRESHOLD_TIGHT: i128 = 100; // 1% in basis points
 const ACCURACY_THRESHOLD_MODERATE: i128 = 500; // 5% in basis points
-const MAJOR_DEVIATION_THRESHOLD: i128 = 2000; // 20% in basis points
+pub const MAJOR_DEVIATION_THRESHOLD: i128 = 2000; // 20% in basis points
 const WEEK_IN_SECONDS: u64 = 86400 * 7;
 
 pub fn get_oracle_stats(env: &Env, oracle: &Address) -> OracleReputation {
@@ -59,7 +59,7 @@ pub fn calculate_reputation(env: &Env, oracle: &Address) -> u32 {
     };
 
     let total = accuracy_score as i128 + deviation_score + consistency_score as i128;
-    total.min(100).max(0) as u32
+    total.clamp(0, 100) as u32
 }
 
 pub fn adjust_oracle_weight(env: &Env, oracle: &Address) -> u32 {
@@ -113,7 +113,6 @@ pub fn slash_oracle(env: &Env, oracle: &Address, reason: SlashReason) {
 
     let penalty = match reason {
         SlashReason::MajorDeviation => 20,
-        SlashReason::SignatureFailure => 30,
     };
 
     stats.reputation_score = stats.reputation_score.saturating_sub(penalty);
@@ -140,5 +139,4 @@ pub fn should_remove_oracle(env: &Env, oracle: &Address) -> bool {
 
 pub enum SlashReason {
     MajorDeviation,
-    SignatureFailure,
 }
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
 
 fn create_test_env() -> (Env, Address, Address, Address, Address) {
     let env = Env::default();
@@ -15,24 +15,69 @@ fn create_test_env() -> (Env, Address, Address, Address, Address) {
 }
 
 #[test]
-fn test_initialize() {
-    let (env, admin, _, _, _) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+fn test_constructor_sets_admin_and_schema() {
+    let (env, admin, oracle1, _, _) = create_test_env();
+    let contract_id = env.register(OracleContract, (admin.clone(),));
+    let client = OracleContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(
+        client.try_register_oracle(&oracle1, &oracle1),
+        Err(Ok(OracleError::Unauthorized))
+    );
+    client.register_oracle(&admin, &oracle1);
+}
+
+#[test]
+fn test_upgrade_requires_admin_auth() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
+    // Nobody has signed, so the admin check fails before the Wasm is touched
+    let hash = BytesN::from_array(&env, &[0; 32]);
+    assert!(client.try_upgrade(&hash).is_err());
+}
 
-    // Should panic on second init
-    // client.initialize(&admin); // Uncomment to test panic
+#[test]
+fn test_migrate_stamps_unversioned_deployments() {
+    let (env, admin, _, _, _) = create_test_env();
+    let contract_id = env.register(OracleContract, (admin.clone(),));
+    let client = OracleContractClient::new(&env, &contract_id);
+
+    // Deployments that predate versioning have no stored version
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .remove(&common::UpgradeKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 1);
+
+    let status = client.migrate(&10);
+    assert_eq!(
+        status,
+        MigrationStatus {
+            schema_version: SCHEMA_VERSION,
+            target_version: SCHEMA_VERSION,
+            migrated: 0,
+            remaining: 0,
+        }
+    );
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            common::get_stored_schema_version(&env),
+            Some(SCHEMA_VERSION)
+        );
+    });
 }
 
 #[test]
 fn test_register_oracle() {
     let (env, admin, oracle1, _, _) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
     client.register_oracle(&admin, &oracle1);
 
     let reputation = client.get_oracle_reputation(&oracle1);
@@ -44,10 +89,9 @@ fn test_register_oracle() {
 #[test]
 fn test_submit_price() {
     let (env, admin, oracle1, _, _) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
     client.register_oracle(&admin, &oracle1);
 
     client.submit_price(&oracle1, &100_000_000);
@@ -60,10 +104,9 @@ fn test_submit_price() {
 #[test]
 fn test_reputation_calculation_accurate_oracle() {
     let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
     client.register_oracle(&admin, &oracle1);
     client.register_oracle(&admin, &oracle2);
     client.register_oracle(&admin, &oracle3);
@@ -91,10 +134,9 @@ fn test_reputation_calculation_accurate_oracle() {
 #[test]
 fn test_weight_adjustment() {
     let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
     client.register_oracle(&admin, &oracle1);
     client.register_oracle(&admin, &oracle2);
     client.register_oracle(&admin, &oracle3);
@@ -117,10 +159,9 @@ fn test_weight_adjustment() {
 #[test]
 fn test_slash_for_major_deviation() {
     let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
     client.register_oracle(&admin, &oracle1);
     client.register_oracle(&admin, &oracle2);
     client.register_oracle(&admin, &oracle3);
@@ -141,10 +182,9 @@ fn test_slash_for_major_deviation() {
 #[test]
 fn test_oracle_removal_for_poor_performance() {
     let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
     client.register_oracle(&admin, &oracle1);
     client.register_oracle(&admin, &oracle2);
     client.register_oracle(&admin, &oracle3);
@@ -177,10 +217,9 @@ fn test_oracle_removal_for_poor_performance() {
 #[test]
 fn test_reputation_recovery() {
     let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
     client.register_oracle(&admin, &oracle1);
     client.register_oracle(&admin, &oracle2);
     client.register_oracle(&admin, &oracle3);
@@ -213,10 +252,9 @@ fn test_reputation_recovery() {
 #[test]
 fn test_weighted_median() {
     let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
     client.register_oracle(&admin, &oracle1);
     client.register_oracle(&admin, &oracle2);
     client.register_oracle(&admin, &oracle3);
@@ -239,10 +277,9 @@ fn test_weighted_median() {
 #[test]
 fn test_minimum_oracles_maintained() {
     let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
     client.register_oracle(&admin, &oracle1);
     client.register_oracle(&admin, &oracle2);
     client.register_oracle(&admin, &oracle3);
@@ -251,7 +288,7 @@ fn test_minimum_oracles_maintained() {
     assert_eq!(oracles_before.len(), 3);
 
     // All oracles submit terrible data
-    for i in 0..50 {
+    for _ in 0..50 {
         let rep1 = client.get_oracle_reputation(&oracle1);
         let rep2 = client.get_oracle_reputation(&oracle2);
         let rep3 = client.get_oracle_reputation(&oracle3);
@@ -284,10 +321,9 @@ fn test_minimum_oracles_maintained() {
 #[test]
 fn test_invalid_price_rejected() {
     let (env, admin, oracle1, _, _) = create_test_env();
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
     client.register_oracle(&admin, &oracle1);
 
     let result = client.try_submit_price(&oracle1, &0);
@@ -301,11 +337,9 @@ fn test_invalid_price_rejected() {
 fn test_unregistered_oracle_cannot_submit() {
     let (env, admin, _, _, _) = create_test_env();
     let unregistered = Address::generate(&env);
-    let contract_id = env.register_contract(None, OracleContract);
+    let contract_id = env.register(OracleContract, (admin.clone(),));
     let client = OracleContractClient::new(&env, &contract_id);
 
-    client.initialize(&admin);
-
     let result = client.try_submit_price(&unregistered, &100_000_000);
     assert!(result.is_err());
 }
@@ -114,11 +114,27 @@ pub fn require_admin(env: &Env, caller: &Address) -> Result<(), AdminError> {
     }
 }
 
+/// Verify caller can change a parameter without a proposal.
+/// Once multi-sig is enabled with a threshold above one, parameter changes
+/// must go through `multisig::propose` and collect approvals.
+pub fn require_direct_admin(env: &Env, caller: &Address) -> Result<(), AdminError> {
+    require_admin(env, caller)?;
+
+    if is_multisig_enabled(env) && get_multisig_threshold(env) > 1 {
+        return Err(AdminError::InsufficientSignatures);
+    }
+    Ok(())
+}
+
 /// Transfer admin to new address
 pub fn transfer_admin(env: &Env, caller: &Address, new_admin: Address) -> Result<(), AdminError> {
-    require_admin(env, caller)?;
+    require_direct_admin(env, caller)?;
     caller.require_auth();
 
+    apply_transfer_admin(env, new_admin)
+}
+
+pub(crate) fn apply_transfer_admin(env: &Env, new_admin: Address) -> Result<(), AdminError> {
     let old_admin = get_admin(env)?;
     env.storage()
         .instance()
@@ -130,9 +146,13 @@ pub fn transfer_admin(env: &Env, caller: &Address, new_admin: Address) -> Result
 
 /// Set minimum stake requirement
 pub fn set_min_stake(env: &Env, caller: &Address, new_amount: i128) -> Result<(), AdminError> {
-    require_admin(env, caller)?;
+    require_direct_admin(env, caller)?;
     caller.require_auth();
 
+    apply_min_stake(env, new_amount)
+}
+
+pub(crate) fn apply_min_stake(env: &Env, new_amount: i128) -> Result<(), AdminError> {
     if new_amount <= 0 {
         return Err(AdminError::InvalidParameter);
     }
@@ -166,9 +186,13 @@ pub fn get_min_stake(env: &Env) -> i128 {
 
 /// Set trade fee in basis points
 pub fn set_trade_fee(env: &Env, caller: &Address, new_fee_bps: u32) -> Result<(), AdminError> {
-    require_admin(env, caller)?;
+    require_direct_admin(env, caller)?;
     caller.require_auth();
 
+    apply_trade_fee(env, new_fee_bps)
+}
+
+pub(crate) fn apply_trade_fee(env: &Env, new_fee_bps: u32) -> Result<(), AdminError> {
     if new_fee_bps > MAX_FEE_BPS {
         return Err(AdminError::InvalidFeeRate);
     }
@@ -207,9 +231,17 @@ pub fn set_risk_defaults(
     stop_loss: u32,
     position_limit: u32,
 ) -> Result<(), AdminError> {
-    require_admin(env, caller)?;
+    require_direct_admin(env, caller)?;
     caller.require_auth();
 
+    apply_risk_defaults(env, stop_loss, position_limit)
+}
+
+pub(crate) fn apply_risk_defaults(
+    env: &Env,
+    stop_loss: u32,
+    position_limit: u32,
+) -> Result<(), AdminError> {
     if stop_loss > MAX_RISK_PERCENTAGE || position_limit > MAX_RISK_PERCENTAGE {
         return Err(AdminError::InvalidRiskParameter);
     }
@@ -267,9 +299,14 @@ pub fn get_default_position_limit(env: &Env) -> u32 {
 
 /// Pause trading
 pub fn pause_trading(env: &Env, caller: &Address) -> Result<(), AdminError> {
-    require_admin(env, caller)?;
+    require_direct_admin(env, caller)?;
     caller.require_auth();
 
+    apply_pause(env, caller);
+    Ok(())
+}
+
+pub(crate) fn apply_pause(env: &Env, paused_by: &Address) {
     let now = env.ledger().timestamp();
     let expires_at = now + PAUSE_DURATION;
 
@@ -283,15 +320,19 @@ pub fn pause_trading(env: &Env, caller: &Address) -> Result<(), AdminError> {
         .instance()
         .set(&AdminStorageKey::PauseInfo, &pause_info);
 
-    emit_trading_paused(env, caller.clone(), expires_at);
-    Ok(())
+    emit_trading_paused(env, paused_by.clone(), expires_at);
 }
 
 /// Unpause trading
 pub fn unpause_trading(env: &Env, caller: &Address) -> Result<(), AdminError> {
-    require_admin(env, caller)?;
+    require_direct_admin(env, caller)?;
     caller.require_auth();
 
+    apply_unpause(env, caller);
+    Ok(())
+}
+
+pub(crate) fn apply_unpause(env: &Env, unpaused_by: &Address) {
     let pause_info = PauseInfo {
         is_paused: false,
         paused_at: 0,
@@ -302,8 +343,7 @@ pub fn unpause_trading(env: &Env, caller: &Address) -> Result<(), AdminError> {
         .instance()
         .set(&AdminStorageKey::PauseInfo, &pause_info);
 
-    emit_trading_unpaused(env, caller.clone());
-    Ok(())
+    emit_trading_unpaused(env, unpaused_by.clone());
 }
 
 /// Check if trading is paused
@@ -372,14 +412,16 @@ pub fn get_admin_config(env: &Env) -> AdminConfig {
 
 // ==================== Multi-Sig Functions ====================
 
-/// Enable multi-sig admin with specified signers and threshold
+/// Enable multi-sig admin with specified signers and threshold.
+/// Reconfiguring an active multi-sig with a threshold above one needs a
+/// proposal to disable it first.
 pub fn enable_multisig(
     env: &Env,
     caller: &Address,
     signers: Vec<Address>,
     threshold: u32,
 ) -> Result<(), AdminError> {
-    require_admin(env, caller)?;
+    require_direct_admin(env, caller)?;
     caller.require_auth();
 
     if threshold == 0 || threshold > signers.len() {
@@ -410,14 +452,17 @@ pub fn enable_multisig(
 
 /// Disable multi-sig admin
 pub fn disable_multisig(env: &Env, caller: &Address) -> Result<(), AdminError> {
-    require_admin(env, caller)?;
+    require_direct_admin(env, caller)?;
     caller.require_auth();
 
+    apply_disable_multisig(env);
+    Ok(())
+}
+
+pub(crate) fn apply_disable_multisig(env: &Env) {
     env.storage()
         .instance()
         .set(&AdminStorageKey::MultiSigEnabled, &false);
-
-    Ok(())
 }
 
 /// Check if multi-sig is enabled
@@ -471,9 +516,17 @@ pub fn add_multisig_signer(
     caller: &Address,
     new_signer: Address,
 ) -> Result<(), AdminError> {
-    require_admin(env, caller)?;
+    require_direct_admin(env, caller)?;
     caller.require_auth();
 
+    apply_add_multisig_signer(env, new_signer, caller)
+}
+
+pub(crate) fn apply_add_multisig_signer(
+    env: &Env,
+    new_signer: Address,
+    added_by: &Address,
+) -> Result<(), AdminError> {
     if !is_multisig_enabled(env) {
         return Err(AdminError::NotInitialized);
     }
@@ -492,7 +545,7 @@ pub fn add_multisig_signer(
         .instance()
         .set(&AdminStorageKey::MultiSigSigners, &signers);
 
-    emit_multisig_signer_added(env, new_signer, caller.clone());
+    emit_multisig_signer_added(env, new_signer, added_by.clone());
     Ok(())
 }
 
@@ -502,9 +555,17 @@ pub fn remove_multisig_signer(
     caller: &Address,
     signer_to_remove: Address,
 ) -> Result<(), AdminError> {
-    require_admin(env, caller)?;
+    require_direct_admin(env, caller)?;
     caller.require_auth();
 
+    apply_remove_multisig_signer(env, signer_to_remove, caller)
+}
+
+pub(crate) fn apply_remove_multisig_signer(
+    env: &Env,
+    signer_to_remove: Address,
+    removed_by: &Address,
+) -> Result<(), AdminError> {
     if !is_multisig_enabled(env) {
         return Err(AdminError::NotInitialized);
     }
@@ -537,6 +598,6 @@ pub fn remove_multisig_signer(
         .instance()
         .set(&AdminStorageKey::MultiSigSigners, &new_signers);
 
-    emit_multisig_signer_removed(env, signer_to_remove, caller.clone());
+    emit_multisig_signer_removed(env, signer_to_remove, removed_by.clone());
     Ok(())
 }
@@ -1,10 +1,82 @@
-use soroban_sdk::{Address, Env, Map, String, Vec};
+use crate::categories::SignalCategory;
+use crate::profiles::{self, ProviderProfile};
+use crate::social;
+use crate::storage::{self, SignalIndex};
+use crate::swipes;
 use crate::types::{Signal, SignalStatus};
-use crate::social::get_follower_count;
+use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
 
 const MIN_SIGNALS_FOR_ANALYTICS: u32 = 10;
 const HOURS_24: u64 = 86400;
+/// Days of signal activity the windowed queries look back over. Day buckets
+/// live for `TtlClass::History` after they are written, so this stays
+/// below it.
+pub const ACTIVITY_DAYS: u64 = 90;
+/// Window `ProviderAnalytics::follower_growth_rate` is measured over
+pub const FOLLOWER_GROWTH_WINDOW_DAYS: u32 = 30;
 
+/// Entries returned by `get_trending_assets`
+pub const MAX_TRENDING_RESULTS: u32 = 10;
+/// Entries `get_popular_tags` returns at most
+pub const MAX_POPULAR_TAGS: u32 = 50;
+
+// Activity points a signal contributes to its window
+const TREND_SIGNAL_POINTS: i128 = 10;
+const TREND_COPY_POINTS: i128 = 5;
+/// Copied volume worth one point (one whole unit at 7 decimals)
+const TREND_VOLUME_PER_POINT: i128 = 10_000_000;
+const TREND_RIGHT_SWIPE_POINTS: i128 = 2;
+const TREND_LEFT_SWIPE_POINTS: i128 = 1;
+
+/// Activity of an asset pair or tag in the current window compared with
+/// the window before it
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrendEntry {
+    /// Asset pair or tag
+    pub name: String,
+    /// Signals posted in the current window
+    pub signals: u32,
+    /// Activity points earned in the current window
+    pub activity: i128,
+    /// Activity points earned in the previous window
+    pub previous_activity: i128,
+    /// `activity - previous_activity`
+    pub velocity: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum AnalyticsStorageKey {
+    /// provider -> ProviderTotals
+    SignalTotals(Address),
+    /// day -> ids of signals that took effect that day, oldest first
+    SignalsByDay(u64),
+}
+
+/// Running totals of a provider's closed signals, plus its win streak
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProviderTotals {
+    pub signals: u32,
+    /// Sum of `expiry - timestamp`
+    pub lifetime_sum: u64,
+    /// Sum of the average trade ROI of signals that were traded
+    pub roi_sum: i128,
+    pub roi_signals: u32,
+    /// Asset pair -> summed average trade ROI
+    pub pair_roi: Map<String, i128>,
+    /// Posting hour (UTC) -> summed average trade ROI
+    pub hour_roi: Map<u32, i128>,
+    /// Posting hour (UTC) -> traded signals
+    pub hour_signals: Map<u32, u32>,
+    /// Successful calls since the last failed one
+    pub win_streak: u32,
+    pub best_win_streak: u32,
+}
+
+#[contracttype]
 #[derive(Clone, Debug)]
 pub struct ProviderAnalytics {
     pub provider: Address,
@@ -14,9 +86,12 @@ pub struct ProviderAnalytics {
     pub best_time_of_day: u32,
     pub win_streak: u32,
     pub avg_signal_lifetime: u64,
+    /// Follower growth over `FOLLOWER_GROWTH_WINDOW_DAYS` in basis points
     pub follower_growth_rate: i128,
+    pub profile: ProviderProfile,
 }
 
+#[contracttype]
 #[derive(Clone, Debug)]
 pub struct GlobalAnalytics {
     pub total_signals_24h: u32,
@@ -25,55 +100,244 @@ pub struct GlobalAnalytics {
     pub total_volume_24h: i128,
 }
 
-pub fn calculate_provider_analytics(
-    env: &Env,
-    signals_map: &Map<u64, Signal>,
-    provider: &Address,
-) -> Option<ProviderAnalytics> {
-    let signals = get_provider_signals(signals_map, provider);
-    let total = signals.len();
-    
-    if total < MIN_SIGNALS_FOR_ANALYTICS {
+pub fn calculate_provider_analytics(env: &Env, provider: &Address) -> Option<ProviderAnalytics> {
+    // Closed signals come from the running totals, open ones from the index
+    let mut totals = get_provider_totals(env, provider);
+    let open = storage::get_all_index_signals(env, &SignalIndex::Provider(provider.clone()));
+    for signal in open.iter() {
+        add_signal(&mut totals, &signal);
+    }
+
+    if totals.signals < MIN_SIGNALS_FOR_ANALYTICS {
         return None;
     }
 
-    let avg_roi = calculate_avg_roi(&signals);
-    let best_asset_pair = find_best_asset_pair(env, &signals);
-    let best_time_of_day = find_best_time_of_day(&signals);
-    let win_streak = calculate_win_streak(&signals);
-    let avg_signal_lifetime = calculate_avg_lifetime(&signals);
-    let follower_growth_rate = calculate_follower_growth(env, provider);
+    let avg_roi = if totals.roi_signals > 0 {
+        totals.roi_sum / totals.roi_signals as i128
+    } else {
+        0
+    };
+    let follower_growth_rate =
+        social::calculate_follower_growth(env, provider, FOLLOWER_GROWTH_WINDOW_DAYS);
 
     Some(ProviderAnalytics {
         provider: provider.clone(),
-        total_signals: total,
+        total_signals: totals.signals,
         avg_roi,
-        best_asset_pair,
-        best_time_of_day,
-        win_streak,
-        avg_signal_lifetime,
+        best_asset_pair: find_best_asset_pair(env, &totals.pair_roi),
+        best_time_of_day: find_best_time_of_day(&totals),
+        win_streak: totals.best_win_streak,
+        avg_signal_lifetime: totals.lifetime_sum / totals.signals as u64,
         follower_growth_rate,
+        profile: profiles::get_profile_or_default(env, provider),
     })
 }
 
+fn get_provider_totals(env: &Env, provider: &Address) -> ProviderTotals {
+    let key = AnalyticsStorageKey::SignalTotals(provider.clone());
+    if let Some(totals) = env.storage().persistent().get(&key) {
+        bump_persistent(env, &key, TtlClass::History);
+        return totals;
+    }
+    ProviderTotals {
+        signals: 0,
+        lifetime_sum: 0,
+        roi_sum: 0,
+        roi_signals: 0,
+        pair_roi: Map::new(env),
+        hour_roi: Map::new(env),
+        hour_signals: Map::new(env),
+        win_streak: 0,
+        best_win_streak: 0,
+    }
+}
+
+fn save_provider_totals(env: &Env, provider: &Address, totals: &ProviderTotals) {
+    let key = AnalyticsStorageKey::SignalTotals(provider.clone());
+    env.storage().persistent().set(&key, totals);
+    bump_persistent(env, &key, TtlClass::History);
+}
+
+/// Extend the TTL of a provider's running totals. Returns false if the
+/// provider has none.
+pub fn bump_provider_totals(env: &Env, provider: &Address) -> bool {
+    let key = AnalyticsStorageKey::SignalTotals(provider.clone());
+    bump_persistent(env, &key, TtlClass::History)
+}
+
+/// Fold a signal's lifetime and trade ROI into `totals`
+fn add_signal(totals: &mut ProviderTotals, signal: &Signal) {
+    totals.signals += 1;
+    totals.lifetime_sum = totals
+        .lifetime_sum
+        .saturating_add(signal.expiry.saturating_sub(signal.timestamp));
+
+    if signal.executions > 0 {
+        let roi = signal.total_roi / signal.executions as i128;
+        totals.roi_sum = totals.roi_sum.saturating_add(roi);
+        totals.roi_signals += 1;
+
+        let pair_roi = totals.pair_roi.get(signal.asset_pair.clone()).unwrap_or(0);
+        totals
+            .pair_roi
+            .set(signal.asset_pair.clone(), pair_roi.saturating_add(roi));
+
+        let hour = ((signal.timestamp % HOURS_24) / 3600) as u32;
+        let hour_roi = totals.hour_roi.get(hour).unwrap_or(0);
+        totals.hour_roi.set(hour, hour_roi.saturating_add(roi));
+        let hour_signals = totals.hour_signals.get(hour).unwrap_or(0);
+        totals.hour_signals.set(hour, hour_signals + 1);
+    }
+}
+
+/// Add a signal that just closed to its provider's running totals.
+/// Called when the signal leaves the provider index.
+pub fn record_closed_signal(env: &Env, signal: &Signal) {
+    let mut totals = get_provider_totals(env, &signal.provider);
+    add_signal(&mut totals, signal);
+    save_provider_totals(env, &signal.provider, &totals);
+}
+
+/// Extend or reset the provider's win streak with a call that just resolved
+pub fn record_resolution(env: &Env, provider: &Address, successful: bool) {
+    let mut totals = get_provider_totals(env, provider);
+    if successful {
+        totals.win_streak += 1;
+        totals.best_win_streak = totals.best_win_streak.max(totals.win_streak);
+    } else {
+        totals.win_streak = 0;
+    }
+    save_provider_totals(env, provider, &totals);
+}
+
+/// Asset pairs whose activity grew the most over the last `window_hours`
+/// compared with the `window_hours` before, optionally within one category.
+/// Only pairs active in the current window are listed, fastest riser first.
 pub fn get_trending_assets(
     env: &Env,
-    signals_map: &Map<u64, Signal>,
     window_hours: u64,
-) -> Vec<(String, u32)> {
-    let cutoff = env.ledger().timestamp().saturating_sub(window_hours * 3600);
-    let mut pair_counts: Map<String, u32> = Map::new(env);
+    category: Option<SignalCategory>,
+) -> Vec<TrendEntry> {
+    let entries = collect_trends(env, window_hours, &category, false);
+    rank_trends(env, &entries, true, MAX_TRENDING_RESULTS)
+}
 
-    for i in 0..signals_map.keys().len() {
-        if let Some(key) = signals_map.keys().get(i) {
-            if let Some(signal) = signals_map.get(key) {
-                if signal.timestamp >= cutoff {
-                    let count = pair_counts.get(signal.asset_pair.clone()).unwrap_or(0);
-                    pair_counts.set(signal.asset_pair.clone(), count + 1);
-                }
+/// Tags with the most activity over the last `window_hours`, optionally
+/// within one category, with their velocity against the previous window
+pub fn get_popular_tags(
+    env: &Env,
+    window_hours: u64,
+    category: Option<SignalCategory>,
+    limit: u32,
+) -> Vec<TrendEntry> {
+    let entries = collect_trends(env, window_hours, &category, true);
+    rank_trends(env, &entries, false, limit.min(MAX_POPULAR_TAGS))
+}
+
+/// Activity points of a signal: one post, its copies, copied volume and
+/// swipes. Credited to the window the signal was posted in.
+fn signal_activity(env: &Env, signal: &Signal) -> i128 {
+    let swipes = swipes::get_counts(env, signal.id);
+    TREND_SIGNAL_POINTS
+        + signal.executions as i128 * TREND_COPY_POINTS
+        + signal.total_volume / TREND_VOLUME_PER_POINT
+        + swipes.right as i128 * TREND_RIGHT_SWIPE_POINTS
+        + swipes.left as i128 * TREND_LEFT_SWIPE_POINTS
+}
+
+/// Current and previous window activity per asset pair, or per tag
+fn collect_trends(
+    env: &Env,
+    window_hours: u64,
+    category: &Option<SignalCategory>,
+    by_tag: bool,
+) -> Vec<TrendEntry> {
+    let window = window_hours.saturating_mul(3600);
+    let current_start = env.ledger().timestamp().saturating_sub(window);
+    let mut trends: Map<String, TrendEntry> = Map::new(env);
+
+    let recent = get_signals_since(env, current_start.saturating_sub(window));
+    for signal in recent.iter() {
+        if let Some(category) = category {
+            if signal.category != *category {
+                continue;
+            }
+        }
+        let activity = signal_activity(env, &signal);
+        let current = signal.timestamp >= current_start;
+
+        let names = if by_tag {
+            signal.tags.clone()
+        } else {
+            Vec::from_array(env, [signal.asset_pair.clone()])
+        };
+        for name in names.iter() {
+            let mut entry = trends.get(name.clone()).unwrap_or(TrendEntry {
+                name: name.clone(),
+                signals: 0,
+                activity: 0,
+                previous_activity: 0,
+                velocity: 0,
+            });
+            if current {
+                entry.signals += 1;
+                entry.activity += activity;
+            } else {
+                entry.previous_activity += activity;
+            }
+            entry.velocity = entry.activity - entry.previous_activity;
+            trends.set(name, entry);
+        }
+    }
+    trends.values()
+}
+
+/// Entries active in the current window, ranked by velocity or by activity
+/// (the other breaks ties), highest first
+fn rank_trends(
+    env: &Env,
+    entries: &Vec<TrendEntry>,
+    by_velocity: bool,
+    limit: u32,
+) -> Vec<TrendEntry> {
+    let key = |e: &TrendEntry| {
+        if by_velocity {
+            (e.velocity, e.activity)
+        } else {
+            (e.activity, e.velocity)
+        }
+    };
+
+    let mut ranked: Vec<TrendEntry> = Vec::new(env);
+    for entry in entries.iter() {
+        if entry.activity == 0 {
+            continue;
+        }
+        let mut pos = ranked.len();
+        while pos > 0 && key(&ranked.get(pos - 1).unwrap()) < key(&entry) {
+            pos -= 1;
+        }
+        if pos < limit {
+            ranked.insert(pos, entry);
+            if ranked.len() > limit {
+                ranked.pop_back();
             }
         }
     }
+    ranked
+}
+
+/// Signal count per asset pair over the last `window_hours`, most first
+fn count_signals_by_pair(env: &Env, window_hours: u64) -> Vec<(String, u32)> {
+    let cutoff = env.ledger().timestamp().saturating_sub(window_hours * 3600);
+    let mut pair_counts: Map<String, u32> = Map::new(env);
+
+    let recent = get_signals_since(env, cutoff);
+    for i in 0..recent.len() {
+        let signal = recent.get(i).unwrap();
+        let count = pair_counts.get(signal.asset_pair.clone()).unwrap_or(0);
+        pair_counts.set(signal.asset_pair.clone(), count + 1);
+    }
 
     let mut sorted = Vec::new(env);
     for i in 0..pair_counts.keys().len() {
@@ -103,55 +367,72 @@ pub fn get_trending_assets(
     result
 }
 
-pub fn calculate_global_analytics(
-    env: &Env,
-    signals_map: &Map<u64, Signal>,
-) -> GlobalAnalytics {
+pub fn calculate_global_analytics(env: &Env) -> GlobalAnalytics {
     let cutoff = env.ledger().timestamp().saturating_sub(HOURS_24);
-    let mut total_signals_24h = 0u32;
     let mut total_volume_24h = 0i128;
-    let mut successful = 0u32;
-    let mut terminal = 0u32;
 
-    for i in 0..signals_map.keys().len() {
-        if let Some(key) = signals_map.keys().get(i) {
-            if let Some(signal) = signals_map.get(key) {
-                if signal.timestamp >= cutoff {
-                    total_signals_24h += 1;
-                    total_volume_24h = total_volume_24h.saturating_add(signal.total_volume);
-                }
-                if matches!(signal.status, SignalStatus::Successful | SignalStatus::Failed) {
-                    terminal += 1;
-                    if signal.status == SignalStatus::Successful {
-                        successful += 1;
-                    }
-                }
-            }
-        }
+    let recent = get_signals_since(env, cutoff);
+    let total_signals_24h = recent.len();
+    for i in 0..recent.len() {
+        let signal = recent.get(i).unwrap();
+        total_volume_24h = total_volume_24h.saturating_add(signal.total_volume);
     }
 
-    let avg_success_rate = if terminal > 0 {
-        (successful * 10000) / terminal
-    } else {
-        0
-    };
+    let successful = storage::index_len(env, &SignalIndex::Status(SignalStatus::Successful));
+    let failed = storage::index_len(env, &SignalIndex::Status(SignalStatus::Failed));
+    let terminal = successful + failed;
+
+    let avg_success_rate = (successful * 10000).checked_div(terminal).unwrap_or(0);
 
     GlobalAnalytics {
         total_signals_24h,
-        most_traded_pairs: get_trending_assets(env, signals_map, 24),
+        most_traded_pairs: count_signals_by_pair(env, 24),
         avg_success_rate,
         total_volume_24h,
     }
 }
 
-fn get_provider_signals(signals_map: &Map<u64, Signal>, provider: &Address) -> Vec<Signal> {
-    let env = signals_map.env();
-    let mut result = Vec::new(&env);
-    
-    for i in 0..signals_map.keys().len() {
-        if let Some(key) = signals_map.keys().get(i) {
-            if let Some(signal) = signals_map.get(key) {
-                if signal.provider == *provider {
+/// List a signal under the day it took effect: its creation, or its
+/// activation for signals that start out pending
+pub fn record_activity(env: &Env, signal: &Signal) {
+    let key = AnalyticsStorageKey::SignalsByDay(signal.timestamp / HOURS_24);
+    let mut ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    ids.push_back(signal.id);
+    env.storage().persistent().set(&key, &ids);
+    bump_persistent(env, &key, TtlClass::History);
+}
+
+/// Undo `record_activity` for a signal that went back to pending
+pub fn remove_activity(env: &Env, signal: &Signal) {
+    let key = AnalyticsStorageKey::SignalsByDay(signal.timestamp / HOURS_24);
+    let Some(mut ids) = env.storage().persistent().get::<_, Vec<u64>>(&key) else {
+        return;
+    };
+    if let Some(pos) = ids.last_index_of(signal.id) {
+        ids.remove(pos);
+        env.storage().persistent().set(&key, &ids);
+    }
+}
+
+/// Signals that took effect at or after `cutoff`, read from the day buckets
+/// of at most the last `ACTIVITY_DAYS`
+fn get_signals_since(env: &Env, cutoff: u64) -> Vec<Signal> {
+    let mut result = Vec::new(env);
+    let today = env.ledger().timestamp() / HOURS_24;
+    let first_day = (cutoff / HOURS_24).max(today.saturating_sub(ACTIVITY_DAYS - 1));
+
+    for day in first_day..=today {
+        let ids: Option<Vec<u64>> = env
+            .storage()
+            .persistent()
+            .get(&AnalyticsStorageKey::SignalsByDay(day));
+        for id in ids.into_iter().flatten() {
+            if let Some(signal) = storage::get_signal(env, id) {
+                if signal.timestamp >= cutoff {
                     result.push_back(signal);
                 }
             }
@@ -160,120 +441,33 @@ fn get_provider_signals(signals_map: &Map<u64, Signal>, provider: &Address) -> V
     result
 }
 
-fn calculate_avg_roi(signals: &Vec<Signal>) -> i128 {
-    if signals.is_empty() {
-        return 0;
-    }
-    
-    let mut total = 0i128;
-    let mut count = 0u32;
-    
-    for i in 0..signals.len() {
-        let signal = signals.get(i).unwrap();
-        if signal.executions > 0 {
-            total = total.saturating_add(signal.total_roi / signal.executions as i128);
-            count += 1;
-        }
-    }
-    
-    if count > 0 { total / count as i128 } else { 0 }
-}
-
-fn find_best_asset_pair(env: &Env, signals: &Vec<Signal>) -> String {
-    let mut pair_roi: Map<String, i128> = Map::new(env);
-    
-    for i in 0..signals.len() {
-        let signal = signals.get(i).unwrap();
-        if signal.executions > 0 {
-            let roi = signal.total_roi / signal.executions as i128;
-            let current = pair_roi.get(signal.asset_pair.clone()).unwrap_or(0);
-            pair_roi.set(signal.asset_pair.clone(), current + roi);
-        }
-    }
-    
+fn find_best_asset_pair(env: &Env, pair_roi: &Map<String, i128>) -> String {
     let mut best_pair = String::from_str(env, "");
     let mut best_roi = i128::MIN;
-    
-    for i in 0..pair_roi.keys().len() {
-        if let Some(key) = pair_roi.keys().get(i) {
-            if let Some(roi) = pair_roi.get(key.clone()) {
-                if roi > best_roi {
-                    best_roi = roi;
-                    best_pair = key;
-                }
-            }
+
+    for (pair, roi) in pair_roi.iter() {
+        if roi > best_roi {
+            best_roi = roi;
+            best_pair = pair;
         }
     }
-    
+
     best_pair
 }
 
-fn find_best_time_of_day(signals: &Vec<Signal>) -> u32 {
-    let mut hour_roi = [0i128; 24];
-    let mut hour_counts = [0u32; 24];
-    
-    for i in 0..signals.len() {
-        let signal = signals.get(i).unwrap();
-        if signal.executions > 0 {
-            let hour = ((signal.timestamp % 86400) / 3600) as usize;
-            if hour < 24 {
-                hour_roi[hour] = hour_roi[hour].saturating_add(signal.total_roi / signal.executions as i128);
-                hour_counts[hour] += 1;
-            }
-        }
-    }
-    
+fn find_best_time_of_day(totals: &ProviderTotals) -> u32 {
     let mut best_hour = 0u32;
     let mut best_avg = i128::MIN;
-    
-    for h in 0..24 {
-        if hour_counts[h] > 0 {
-            let avg = hour_roi[h] / hour_counts[h] as i128;
+
+    for (hour, count) in totals.hour_signals.iter() {
+        if count > 0 {
+            let avg = totals.hour_roi.get(hour).unwrap_or(0) / count as i128;
             if avg > best_avg {
                 best_avg = avg;
-                best_hour = h as u32;
-            }
-        }
-    }
-    
-    best_hour
-}
-
-fn calculate_win_streak(signals: &Vec<Signal>) -> u32 {
-    let mut streak = 0u32;
-    let mut max_streak = 0u32;
-    
-    for i in 0..signals.len() {
-        let signal = signals.get(i).unwrap();
-        if signal.status == SignalStatus::Successful {
-            streak += 1;
-            if streak > max_streak {
-                max_streak = streak;
+                best_hour = hour;
             }
-        } else if signal.status == SignalStatus::Failed {
-            streak = 0;
         }
     }
-    
-    max_streak
-}
 
-fn calculate_avg_lifetime(signals: &Vec<Signal>) -> u64 {
-    if signals.is_empty() {
-        return 0;
-    }
-    
-    let mut total = 0u64;
-    for i in 0..signals.len() {
-        let signal = signals.get(i).unwrap();
-        total = total.saturating_add(signal.expiry.saturating_sub(signal.timestamp));
-    }
-    
-    total / signals.len() as u64
-}
-
-fn calculate_follower_growth(env: &Env, provider: &Address) -> i128 {
-    // Simplified: return current follower count as growth rate
-    // Full implementation would track historical data
-    get_follower_count(env, provider) as i128
+    best_hour
 }
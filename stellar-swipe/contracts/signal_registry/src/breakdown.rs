@@ -0,0 +1,239 @@
+//! Performance analytics broken down by signal category, risk level and tag.
+//!
+//! Only resolved (Successful or Failed) signals count. A signal's ROI is its
+//! oracle outcome when it has one, otherwise the average ROI of its trades.
+//! Both the provider breakdown and the platform-wide category ranking read
+//! running totals that are updated as each signal resolves.
+
+use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
+use crate::categories::{RiskLevel, SignalCategory};
+use crate::types::{Signal, SignalStatus};
+
+/// Tags returned in a provider breakdown
+pub const MAX_BREAKDOWN_TAGS: u32 = 5;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum BreakdownStorageKey {
+    /// Map<SignalCategory, SegmentPerformance> of platform-wide totals, with
+    /// `avg_roi` holding the ROI sum (instance)
+    CategoryTotals,
+    /// provider -> ProviderSegments, with `avg_roi` holding ROI sums
+    ProviderSegments(Address),
+}
+
+/// Running per-category, per-risk-level and per-tag totals of a provider
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProviderSegments {
+    pub categories: Map<SignalCategory, SegmentPerformance>,
+    pub risk_levels: Map<RiskLevel, SegmentPerformance>,
+    pub tags: Map<String, SegmentPerformance>,
+}
+
+/// Results of one slice of resolved signals
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SegmentPerformance {
+    pub resolved_signals: u32,
+    pub successful_signals: u32,
+    /// Success rate in basis points (10000 = 100%)
+    pub success_rate: u32,
+    /// Average signal ROI in basis points
+    pub avg_roi: i128,
+    pub total_volume: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CategoryPerformance {
+    pub category: SignalCategory,
+    pub performance: SegmentPerformance,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RiskLevelPerformance {
+    pub risk_level: RiskLevel,
+    pub performance: SegmentPerformance,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TagPerformance {
+    pub tag: String,
+    pub performance: SegmentPerformance,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PerformanceBreakdown {
+    pub by_category: Vec<CategoryPerformance>,
+    pub by_risk_level: Vec<RiskLevelPerformance>,
+    /// Tags with the most resolved signals, at most `MAX_BREAKDOWN_TAGS`
+    pub top_tags: Vec<TagPerformance>,
+}
+
+/// Add a resolved signal to a segment. `avg_roi` holds the ROI sum until
+/// `finish` is called.
+fn add(segment: &mut SegmentPerformance, signal: &Signal, roi: i128) {
+    segment.resolved_signals += 1;
+    if signal.status == SignalStatus::Successful {
+        segment.successful_signals += 1;
+    }
+    segment.avg_roi = segment.avg_roi.saturating_add(roi);
+    segment.total_volume = segment.total_volume.saturating_add(signal.total_volume);
+}
+
+fn finish(mut segment: SegmentPerformance) -> SegmentPerformance {
+    if let Some(rate) = (segment.successful_signals * 10_000).checked_div(segment.resolved_signals)
+    {
+        segment.success_rate = rate;
+        segment.avg_roi /= segment.resolved_signals as i128;
+    }
+    segment
+}
+
+fn category_results(
+    categories: &Map<SignalCategory, SegmentPerformance>,
+) -> Vec<CategoryPerformance> {
+    let mut result = Vec::new(categories.env());
+    for (category, segment) in categories.iter() {
+        result.push_back(CategoryPerformance {
+            category,
+            performance: finish(segment),
+        });
+    }
+    result
+}
+
+fn get_provider_segments(env: &Env, provider: &Address) -> ProviderSegments {
+    let key = BreakdownStorageKey::ProviderSegments(provider.clone());
+    if let Some(segments) = env.storage().persistent().get(&key) {
+        bump_persistent(env, &key, TtlClass::History);
+        return segments;
+    }
+    ProviderSegments {
+        categories: Map::new(env),
+        risk_levels: Map::new(env),
+        tags: Map::new(env),
+    }
+}
+
+/// Extend the TTL of a provider's running totals. Returns false if the
+/// provider has none.
+pub fn bump_provider_segments(env: &Env, provider: &Address) -> bool {
+    let key = BreakdownStorageKey::ProviderSegments(provider.clone());
+    bump_persistent(env, &key, TtlClass::History)
+}
+
+/// Per-category, per-risk-level and top-tag performance of a provider
+pub fn get_provider_breakdown(env: &Env, provider: &Address) -> PerformanceBreakdown {
+    let ProviderSegments {
+        categories,
+        risk_levels,
+        tags,
+    } = get_provider_segments(env, provider);
+
+    let mut by_risk_level = Vec::new(env);
+    for (risk_level, segment) in risk_levels.iter() {
+        by_risk_level.push_back(RiskLevelPerformance {
+            risk_level,
+            performance: finish(segment),
+        });
+    }
+
+    // Insertion sort tags by resolved signals (desc), keeping the top ones
+    let mut top_tags: Vec<TagPerformance> = Vec::new(env);
+    for (tag, segment) in tags.iter() {
+        let mut pos = top_tags.len();
+        while pos > 0
+            && top_tags.get(pos - 1).unwrap().performance.resolved_signals
+                < segment.resolved_signals
+        {
+            pos -= 1;
+        }
+        if pos < MAX_BREAKDOWN_TAGS {
+            top_tags.insert(
+                pos,
+                TagPerformance {
+                    tag,
+                    performance: finish(segment),
+                },
+            );
+            if top_tags.len() > MAX_BREAKDOWN_TAGS {
+                top_tags.pop_back();
+            }
+        }
+    }
+
+    PerformanceBreakdown {
+        by_category: category_results(&categories),
+        by_risk_level,
+        top_tags,
+    }
+}
+
+fn get_category_totals(env: &Env) -> Map<SignalCategory, SegmentPerformance> {
+    env.storage()
+        .instance()
+        .get(&BreakdownStorageKey::CategoryTotals)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Add a signal that just resolved to its provider's totals and to the
+/// platform-wide category totals
+pub fn record_resolution(env: &Env, signal: &Signal, roi: i128) {
+    let mut segments = get_provider_segments(env, &signal.provider);
+    let mut segment = segments
+        .categories
+        .get(signal.category.clone())
+        .unwrap_or_default();
+    add(&mut segment, signal, roi);
+    segments.categories.set(signal.category.clone(), segment);
+
+    let mut segment = segments
+        .risk_levels
+        .get(signal.risk_level.clone())
+        .unwrap_or_default();
+    add(&mut segment, signal, roi);
+    segments.risk_levels.set(signal.risk_level.clone(), segment);
+
+    for tag in signal.tags.iter() {
+        let mut segment = segments.tags.get(tag.clone()).unwrap_or_default();
+        add(&mut segment, signal, roi);
+        segments.tags.set(tag, segment);
+    }
+
+    let key = BreakdownStorageKey::ProviderSegments(signal.provider.clone());
+    env.storage().persistent().set(&key, &segments);
+    bump_persistent(env, &key, TtlClass::History);
+
+    let mut categories = get_category_totals(env);
+    let mut segment = categories.get(signal.category.clone()).unwrap_or_default();
+    add(&mut segment, signal, roi);
+    categories.set(signal.category.clone(), segment);
+    env.storage()
+        .instance()
+        .set(&BreakdownStorageKey::CategoryTotals, &categories);
+}
+
+/// Platform-wide performance per category, best success rate first
+pub fn get_category_performance(env: &Env) -> Vec<CategoryPerformance> {
+    let categories = get_category_totals(env);
+    let unsorted = category_results(&categories);
+    let mut result: Vec<CategoryPerformance> = Vec::new(env);
+    for entry in unsorted.iter() {
+        let mut pos = result.len();
+        while pos > 0
+            && result.get(pos - 1).unwrap().performance.success_rate
+                < entry.performance.success_rate
+        {
+            pos -= 1;
+        }
+        result.insert(pos, entry);
+    }
+    result
+}
@@ -0,0 +1,217 @@
+//! Commit-reveal signal publication.
+//!
+//! A provider first commits the SHA-256 hash of a `SignalReveal` (the XDR
+//! encoding of the signal content plus a random salt). The commit is
+//! timestamped on chain but says nothing about the trade, so it cannot be
+//! front-run. Within `REVEAL_WINDOW_SECONDS` the provider reveals the content;
+//! if it hashes to the commitment the signal is created. The signal keeps its
+//! creation timestamp; for performance it takes effect at the commit time,
+//! which proves when the call was made. Holding time and the leaderboard day
+//! of a revealed signal are measured from `effective_timestamp`.
+//!
+//! A reveal that does not match, or a commit left unrevealed past its window
+//! (lapsed by anyone through `expire_commit`), is forfeited and counted as a
+//! failed signal in the provider's stats. A provider holds at most one pending
+//! commit, so several commits cannot be taken out to reveal only the winner.
+
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, BytesN, Env, String, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
+use crate::categories::{RiskLevel, SignalCategory};
+use crate::errors::AdminError;
+use crate::events::{emit_commit_forfeited, emit_signal_committed, emit_signal_revealed};
+use crate::types::{Signal, SignalAction};
+
+/// Time a provider has to reveal a commit
+pub const REVEAL_WINDOW_SECONDS: u64 = 60 * 60;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum CommitStorageKey {
+    /// Last commit id issued (instance)
+    CommitCounter,
+    /// commit_id -> SignalCommit (persistent)
+    Commit(u64),
+    /// provider -> id of their pending commit (persistent)
+    PendingCommit(Address),
+    /// signal_id -> id of the commit it was revealed from (persistent)
+    SignalCommit(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommitStatus {
+    Pending,
+    Revealed,
+    /// Revealed content did not match the commitment
+    Mismatched,
+    /// Not revealed within the window
+    Lapsed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignalCommit {
+    pub id: u64,
+    pub provider: Address,
+    pub commitment: BytesN<32>,
+    pub committed_at: u64,
+    pub reveal_deadline: u64,
+    pub status: CommitStatus,
+    /// Signal created by the reveal
+    pub signal_id: Option<u64>,
+}
+
+/// Signal content hidden behind a commitment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignalReveal {
+    pub asset_pair: String,
+    pub action: SignalAction,
+    pub price: i128,
+    pub rationale: String,
+    pub expiry: u64,
+    pub category: SignalCategory,
+    pub tags: Vec<String>,
+    pub risk_level: RiskLevel,
+    pub salt: BytesN<32>,
+}
+
+/// SHA-256 of the XDR-encoded reveal
+pub fn commitment_hash(env: &Env, reveal: &SignalReveal) -> BytesN<32> {
+    env.crypto().sha256(&reveal.clone().to_xdr(env)).to_bytes()
+}
+
+pub fn get_commit(env: &Env, commit_id: u64) -> Option<SignalCommit> {
+    env.storage()
+        .persistent()
+        .get(&CommitStorageKey::Commit(commit_id))
+}
+
+/// Commit a signal was revealed from, if any
+pub fn get_signal_commit_id(env: &Env, signal_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&CommitStorageKey::SignalCommit(signal_id))
+}
+
+/// Time a signal took effect: the commit time for a revealed signal,
+/// otherwise its own timestamp
+pub fn effective_timestamp(env: &Env, signal: &Signal) -> u64 {
+    get_signal_commit_id(env, signal.id)
+        .and_then(|commit_id| get_commit(env, commit_id))
+        .map_or(signal.timestamp, |commit| commit.committed_at)
+}
+
+fn save_commit(env: &Env, commit: &SignalCommit) {
+    let key = CommitStorageKey::Commit(commit.id);
+    env.storage().persistent().set(&key, commit);
+    let class = if commit.status == CommitStatus::Pending {
+        TtlClass::Hot
+    } else {
+        TtlClass::History
+    };
+    bump_persistent(env, &key, class);
+}
+
+/// Record a new commitment and return its id. Fails while the provider has
+/// a pending commit; one past its window has to be expired first.
+pub fn commit(env: &Env, provider: &Address, commitment: BytesN<32>) -> Result<u64, AdminError> {
+    let pending_key = CommitStorageKey::PendingCommit(provider.clone());
+    if env.storage().persistent().has(&pending_key) {
+        return Err(AdminError::CommitAlreadyPending);
+    }
+
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&CommitStorageKey::CommitCounter)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&CommitStorageKey::CommitCounter, &id);
+
+    let now = env.ledger().timestamp();
+    let commit = SignalCommit {
+        id,
+        provider: provider.clone(),
+        commitment: commitment.clone(),
+        committed_at: now,
+        reveal_deadline: now + REVEAL_WINDOW_SECONDS,
+        status: CommitStatus::Pending,
+        signal_id: None,
+    };
+    save_commit(env, &commit);
+    env.storage().persistent().set(&pending_key, &id);
+    bump_persistent(env, &pending_key, TtlClass::Hot);
+
+    emit_signal_committed(
+        env,
+        id,
+        provider.clone(),
+        commitment,
+        commit.reveal_deadline,
+    );
+    Ok(id)
+}
+
+fn clear_pending(env: &Env, provider: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&CommitStorageKey::PendingCommit(provider.clone()));
+}
+
+/// Load a pending commit the provider can still reveal
+pub fn load_for_reveal(
+    env: &Env,
+    provider: &Address,
+    commit_id: u64,
+) -> Result<SignalCommit, AdminError> {
+    let commit = get_commit(env, commit_id).ok_or(AdminError::CommitNotFound)?;
+    if commit.provider != *provider {
+        return Err(AdminError::Unauthorized);
+    }
+    if commit.status != CommitStatus::Pending {
+        return Err(AdminError::CommitNotPending);
+    }
+    if env.ledger().timestamp() > commit.reveal_deadline {
+        return Err(AdminError::RevealWindowClosed);
+    }
+    Ok(commit)
+}
+
+/// Link a matching reveal to the signal it created
+pub fn mark_revealed(env: &Env, mut commit: SignalCommit, signal_id: u64) {
+    let key = CommitStorageKey::SignalCommit(signal_id);
+    env.storage().persistent().set(&key, &commit.id);
+    bump_persistent(env, &key, TtlClass::History);
+
+    commit.status = CommitStatus::Revealed;
+    commit.signal_id = Some(signal_id);
+    save_commit(env, &commit);
+    clear_pending(env, &commit.provider);
+
+    emit_signal_revealed(env, commit.id, commit.provider, signal_id);
+}
+
+/// Close a commit without a signal. Callers update provider stats.
+pub fn forfeit(env: &Env, mut commit: SignalCommit, status: CommitStatus) {
+    commit.status = status;
+    save_commit(env, &commit);
+    clear_pending(env, &commit.provider);
+
+    emit_commit_forfeited(env, commit.id, commit.provider, status as u32);
+}
+
+/// Load a pending commit whose reveal window has passed
+pub fn load_lapsed(env: &Env, commit_id: u64) -> Result<SignalCommit, AdminError> {
+    let commit = get_commit(env, commit_id).ok_or(AdminError::CommitNotFound)?;
+    if commit.status != CommitStatus::Pending {
+        return Err(AdminError::CommitNotPending);
+    }
+    if env.ledger().timestamp() <= commit.reveal_deadline {
+        return Err(AdminError::RevealWindowOpen);
+    }
+    Ok(commit)
+}
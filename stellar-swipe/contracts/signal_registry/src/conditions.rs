@@ -0,0 +1,182 @@
+//! Conditional signals.
+//!
+//! A conditional signal is created `Pending` and becomes `Active` once its
+//! condition holds: the oracle price has crossed a trigger level and/or the
+//! `valid_from` time has passed. Keepers call `activate_conditional_signals`
+//! to promote signals whose conditions are met. A trigger must still be on
+//! the far side of the current price at creation, so activation always
+//! follows a real crossing.
+//!
+//! The condition entry is removed on activation, so a stored condition means
+//! the signal is still waiting. Signals whose trigger never fired are expired
+//! by `expiry::expire_untriggered_signals`, keep their condition entry and
+//! are never resolved or counted in provider stats.
+
+use soroban_sdk::{contracttype, Env, String};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
+use crate::errors::AdminError;
+use crate::events::emit_signal_activated;
+use crate::resolution;
+use crate::storage::{self, SignalIndex};
+use crate::types::{Signal, SignalStatus};
+
+/// Maximum number of pending signals checked per activation call
+pub const MAX_ACTIVATION_BATCH_SIZE: u32 = 20;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TriggerDirection {
+    /// Fires when the price rises to or above the level
+    Above,
+    /// Fires when the price falls to or below the level
+    Below,
+}
+
+/// Activation condition of a signal. All set parts must hold.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignalCondition {
+    /// Oracle price level that activates the signal
+    pub trigger_price: Option<i128>,
+    /// Side of `trigger_price` the price must reach; unused without a trigger
+    pub trigger_direction: TriggerDirection,
+    pub valid_from: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum ConditionStorageKey {
+    /// signal_id -> SignalCondition, while the signal waits for activation
+    Condition(u64),
+}
+
+impl SignalCondition {
+    /// Whether `price` has reached the trigger level
+    pub fn trigger_hit(&self, trigger_price: i128, price: i128) -> bool {
+        match self.trigger_direction {
+            TriggerDirection::Above => price >= trigger_price,
+            TriggerDirection::Below => price <= trigger_price,
+        }
+    }
+}
+
+/// Check a condition can be met before `expiry`. Price triggers need a
+/// configured price oracle with a current price for `asset_pair` that has
+/// not reached the trigger yet.
+pub fn validate_condition(
+    env: &Env,
+    condition: &SignalCondition,
+    asset_pair: &String,
+    expiry: u64,
+) -> Result<(), AdminError> {
+    if condition.trigger_price.is_none() && condition.valid_from.is_none() {
+        return Err(AdminError::InvalidCondition);
+    }
+
+    if let Some(trigger_price) = condition.trigger_price {
+        if trigger_price <= 0 {
+            return Err(AdminError::InvalidCondition);
+        }
+        let oracle = resolution::get_price_oracle(env).ok_or(AdminError::InvalidCondition)?;
+        let price = resolution::fetch_pair_price(env, &oracle, asset_pair)
+            .map_err(|_| AdminError::InvalidCondition)?;
+        if condition.trigger_hit(trigger_price, price) {
+            return Err(AdminError::InvalidCondition);
+        }
+    }
+
+    if let Some(valid_from) = condition.valid_from {
+        if valid_from <= env.ledger().timestamp() || valid_from >= expiry {
+            return Err(AdminError::InvalidCondition);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn set_condition(env: &Env, signal_id: u64, condition: &SignalCondition) {
+    let key = ConditionStorageKey::Condition(signal_id);
+    env.storage().persistent().set(&key, condition);
+    bump_persistent(env, &key, TtlClass::Hot);
+}
+
+pub fn get_condition(env: &Env, signal_id: u64) -> Option<SignalCondition> {
+    env.storage()
+        .persistent()
+        .get(&ConditionStorageKey::Condition(signal_id))
+}
+
+/// Keep the condition of an untriggered signal as history
+pub fn archive_condition(env: &Env, signal_id: u64) {
+    bump_persistent(
+        env,
+        &ConditionStorageKey::Condition(signal_id),
+        TtlClass::History,
+    );
+}
+
+/// True while the signal has an unmet activation condition
+pub fn is_waiting(env: &Env, signal_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&ConditionStorageKey::Condition(signal_id))
+}
+
+/// Whether the condition holds now. Triggers are not met while the oracle
+/// has no price for the pair.
+fn is_met(env: &Env, signal: &Signal, condition: &SignalCondition) -> bool {
+    let now = env.ledger().timestamp();
+    if condition.valid_from.is_some_and(|from| now < from) {
+        return false;
+    }
+
+    match condition.trigger_price {
+        None => true,
+        Some(trigger_price) => match resolution::get_price_oracle(env) {
+            Some(oracle) => resolution::fetch_close_price(env, &oracle, signal)
+                .is_ok_and(|price| condition.trigger_hit(trigger_price, price)),
+            None => false,
+        },
+    }
+}
+
+/// Activate pending conditional signals whose condition is met, checking up
+/// to `limit` signals of the pending index from `offset`.
+/// Returns the number of signals activated.
+pub fn activate_signals(env: &Env, offset: u32, limit: u32) -> u32 {
+    let batch_size = if limit == 0 || limit > MAX_ACTIVATION_BATCH_SIZE {
+        MAX_ACTIVATION_BATCH_SIZE
+    } else {
+        limit
+    };
+
+    let now = env.ledger().timestamp();
+    let index = SignalIndex::Status(SignalStatus::Pending);
+    // Ids are read up front because activation moves signals between indexes
+    let ids = storage::get_index_ids(env, &index, offset, batch_size);
+
+    let mut activated = 0u32;
+    for signal_id in ids.iter() {
+        let Some(condition) = get_condition(env, signal_id) else {
+            continue;
+        };
+        let Some(mut signal) = storage::get_signal(env, signal_id) else {
+            continue;
+        };
+        if signal.expiry <= now || !is_met(env, &signal, &condition) {
+            continue;
+        }
+
+        signal.status = SignalStatus::Active;
+        signal.timestamp = now;
+        storage::save_signal(env, &signal);
+        env.storage()
+            .persistent()
+            .remove(&ConditionStorageKey::Condition(signal_id));
+
+        emit_signal_activated(env, signal_id, signal.provider.clone());
+        activated += 1;
+    }
+    activated
+}
@@ -16,6 +16,28 @@ pub enum AdminError {
     DuplicateSigner = 10,
     InvalidAssetPair = 11,
     CannotFollowSelf = 12,
+    InvalidExpiry = 13,
+    InvalidRationale = 14,
+    DuplicateSignal = 15,
+    MigrationFailed = 16,
+    ProposalNotFound = 17,
+    ProposalNotPending = 18,
+    ProposalExpired = 19,
+    AlreadyApproved = 20,
+    MultiSigNotEnabled = 21,
+    InvalidTargets = 22,
+    SignalNotOpen = 23,
+    SignalHasExecutions = 24,
+    OraclePriceUnavailable = 25,
+    CommitNotFound = 26,
+    CommitNotPending = 27,
+    RevealWindowClosed = 28,
+    RevealWindowOpen = 29,
+    InvalidCondition = 30,
+    InvalidStrategy = 31,
+    CommitAlreadyPending = 32,
+    NoStakeFound = 33,
+    StakeLocked = 34,
 }
 
 #[contracterror]
@@ -46,6 +68,9 @@ pub enum PerformanceError {
     InvalidVolume = 203,
     SignalExpired = 204,
     NoExecutions = 205,
+    SignalCancelled = 206,
+    NeutralSignal = 207,
+    SignalNotActive = 208,
 }
 
 #[contracterror]
@@ -86,3 +111,54 @@ pub enum CollaborationError {
     NotCollaborative = 503,
     PendingApproval = 504,
 }
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ResolutionError {
+    OracleNotConfigured = 600,
+    SignalNotFound = 601,
+    NotExpired = 602,
+    AlreadyResolved = 603,
+    PriceUnavailable = 604,
+    InvalidAssetPair = 605,
+    NotActivated = 606,
+    RoiOverflow = 607,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SubscriptionError {
+    InvalidPlan = 700,
+    PlanNotFound = 701,
+    PlanInactive = 702,
+    TooManyPlans = 703,
+    CannotSubscribeSelf = 704,
+    SubscriptionRequired = 705,
+    SignalNotFound = 706,
+    PaymentFailed = 707,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SwipeError {
+    SignalNotFound = 800,
+    SignalNotOpen = 801,
+    AlreadySwiped = 802,
+    CannotSwipeOwnSignal = 803,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ProfileError {
+    InvalidDisplayName = 900,
+    BioTooLong = 901,
+    InvalidLinks = 902,
+    TooManyCategories = 903,
+    NotModerator = 904,
+    BadgeAlreadyGranted = 905,
+    BadgeNotFound = 906,
+}
@@ -1,5 +1,6 @@
+use crate::profiles::ProviderBadge;
 use crate::types::Asset;
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
 
 pub fn emit_admin_transferred(env: &Env, old_admin: Address, new_admin: Address) {
     let topics = (Symbol::new(env, "admin_transferred"), old_admin, new_admin);
@@ -11,6 +12,16 @@ pub fn emit_parameter_updated(env: &Env, parameter: Symbol, old_value: i128, new
     env.events().publish(topics, (old_value, new_value));
 }
 
+pub fn emit_contract_upgraded(env: &Env, upgraded_by: Address, wasm_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "contract_upgraded"), upgraded_by);
+    env.events().publish(topics, wasm_hash);
+}
+
+pub fn emit_schema_migrated(env: &Env, schema_version: u32) {
+    let topics = (Symbol::new(env, "schema_migrated"),);
+    env.events().publish(topics, schema_version);
+}
+
 pub fn emit_trading_paused(env: &Env, paused_by: Address, expires_at: u64) {
     let topics = (Symbol::new(env, "trading_paused"), paused_by);
     let timestamp = env.ledger().timestamp();
@@ -37,6 +48,34 @@ pub fn emit_multisig_signer_removed(env: &Env, signer: Address, removed_by: Addr
     env.events().publish(topics, ());
 }
 
+pub fn emit_proposal_created(env: &Env, proposal_id: u64, proposer: Address, expires_at: u64) {
+    let topics = (Symbol::new(env, "proposal_created"), proposal_id, proposer);
+    env.events().publish(topics, expires_at);
+}
+
+pub fn emit_proposal_approved(env: &Env, proposal_id: u64, signer: Address, approvals: u32) {
+    let topics = (Symbol::new(env, "proposal_approved"), proposal_id, signer);
+    env.events().publish(topics, approvals);
+}
+
+pub fn emit_proposal_executed(env: &Env, proposal_id: u64, executed_by: Address) {
+    let topics = (
+        Symbol::new(env, "proposal_executed"),
+        proposal_id,
+        executed_by,
+    );
+    env.events().publish(topics, ());
+}
+
+pub fn emit_proposal_cancelled(env: &Env, proposal_id: u64, cancelled_by: Address) {
+    let topics = (
+        Symbol::new(env, "proposal_cancelled"),
+        proposal_id,
+        cancelled_by,
+    );
+    env.events().publish(topics, ());
+}
+
 pub fn emit_fee_collected(
     env: &Env,
     asset: Asset,
@@ -56,6 +95,88 @@ pub fn emit_fee_collected(
         .publish(topics, (total_fee, platform_fee, provider_fee));
 }
 
+pub fn emit_price_oracle_updated(env: &Env, oracle: Address) {
+    let topics = (Symbol::new(env, "price_oracle_updated"),);
+    env.events().publish(topics, oracle);
+}
+
+pub fn emit_signal_resolved(
+    env: &Env,
+    signal_id: u64,
+    provider: Address,
+    close_price: i128,
+    roi: i128,
+) {
+    let topics = (Symbol::new(env, "signal_resolved"), signal_id, provider);
+    env.events().publish(topics, (close_price, roi));
+}
+
+pub fn emit_signal_cancelled(env: &Env, signal_id: u64, provider: Address) {
+    let topics = (Symbol::new(env, "signal_cancelled"), signal_id, provider);
+    env.events().publish(topics, ());
+}
+
+pub fn emit_signal_closed(
+    env: &Env,
+    signal_id: u64,
+    provider: Address,
+    close_price: i128,
+    roi: i128,
+) {
+    let topics = (Symbol::new(env, "signal_closed"), signal_id, provider);
+    env.events().publish(topics, (close_price, roi));
+}
+
+pub fn emit_signal_amended(env: &Env, signal_id: u64, provider: Address, revision: u32) {
+    let topics = (Symbol::new(env, "signal_amended"), signal_id, provider);
+    env.events().publish(topics, revision);
+}
+
+pub fn emit_signal_committed(
+    env: &Env,
+    commit_id: u64,
+    provider: Address,
+    commitment: BytesN<32>,
+    reveal_deadline: u64,
+) {
+    let topics = (Symbol::new(env, "signal_committed"), commit_id, provider);
+    env.events().publish(topics, (commitment, reveal_deadline));
+}
+
+pub fn emit_signal_revealed(env: &Env, commit_id: u64, provider: Address, signal_id: u64) {
+    let topics = (Symbol::new(env, "signal_revealed"), commit_id, provider);
+    env.events().publish(topics, signal_id);
+}
+
+pub fn emit_commit_forfeited(env: &Env, commit_id: u64, provider: Address, status: u32) {
+    let topics = (Symbol::new(env, "commit_forfeited"), commit_id, provider);
+    env.events().publish(topics, status);
+}
+
+pub fn emit_signal_activated(env: &Env, signal_id: u64, provider: Address) {
+    let topics = (Symbol::new(env, "signal_activated"), signal_id, provider);
+    env.events().publish(topics, env.ledger().timestamp());
+}
+
+pub fn emit_signal_trigger_expired(env: &Env, signal_id: u64, provider: Address, expiry_time: u64) {
+    let topics = (
+        Symbol::new(env, "signal_trigger_expired"),
+        provider,
+        signal_id,
+    );
+    env.events().publish(topics, expiry_time);
+}
+
+pub fn emit_strategy_created(env: &Env, strategy_id: u64, provider: Address, leg_count: u32) {
+    let topics = (Symbol::new(env, "strategy_created"), strategy_id, provider);
+    env.events().publish(topics, leg_count);
+}
+
+pub fn emit_strategy_resolved(env: &Env, strategy_id: u64, provider: Address, roi: i128) {
+    let topics = (Symbol::new(env, "strategy_resolved"), strategy_id, provider);
+    env.events().publish(topics, roi);
+}
+
 pub fn emit_signal_expired(env: &Env, signal_id: u64, provider: Address, expiry_time: u64) {
     let topics = (Symbol::new(env, "signal_expired"), provider, signal_id);
     env.events().publish(topics, expiry_time);
@@ -114,7 +235,11 @@ pub fn emit_collaborative_signal_created(env: &Env, signal_id: u64, authors: Vec
 }
 
 pub fn emit_collaborative_signal_approved(env: &Env, signal_id: u64, approver: Address) {
-    let topics = (Symbol::new(env, "collab_signal_approved"), signal_id, approver);
+    let topics = (
+        Symbol::new(env, "collab_signal_approved"),
+        signal_id,
+        approver,
+    );
     env.events().publish(topics, ());
 }
 
@@ -122,3 +247,64 @@ pub fn emit_collaborative_signal_published(env: &Env, signal_id: u64) {
     let topics = (Symbol::new(env, "collab_signal_published"), signal_id);
     env.events().publish(topics, ());
 }
+
+pub fn emit_subscription_plan_created(
+    env: &Env,
+    provider: Address,
+    plan_id: u32,
+    price: i128,
+    period_seconds: u64,
+) {
+    let topics = (
+        Symbol::new(env, "subscription_plan_created"),
+        provider,
+        plan_id,
+    );
+    env.events().publish(topics, (price, period_seconds));
+}
+
+pub fn emit_subscription_renewed(
+    env: &Env,
+    subscriber: Address,
+    provider: Address,
+    plan_id: u32,
+    expires_at: u64,
+) {
+    let topics = (
+        Symbol::new(env, "subscription_renewed"),
+        subscriber,
+        provider,
+    );
+    env.events().publish(topics, (plan_id, expires_at));
+}
+
+pub fn emit_signal_swiped(env: &Env, signal_id: u64, user: Address, right: bool) {
+    let topics = (Symbol::new(env, "signal_swiped"), signal_id, user);
+    env.events().publish(topics, right);
+}
+
+pub fn emit_provider_profile_updated(env: &Env, provider: Address) {
+    let topics = (Symbol::new(env, "provider_profile_updated"), provider);
+    env.events().publish(topics, env.ledger().timestamp());
+}
+
+pub fn emit_provider_badge_changed(
+    env: &Env,
+    provider: Address,
+    badge: ProviderBadge,
+    granted: bool,
+    assigned_by: Address,
+) {
+    let topics = (Symbol::new(env, "provider_badge_changed"), provider, badge);
+    env.events().publish(topics, (granted, assigned_by));
+}
+
+pub fn emit_provider_slashed(env: &Env, provider: Address, amount: i128) {
+    let topics = (Symbol::new(env, "provider_slashed"), provider);
+    env.events().publish(topics, amount);
+}
+
+pub fn emit_stake_withdrawn(env: &Env, provider: Address, amount: i128) {
+    let topics = (Symbol::new(env, "stake_withdrawn"), provider);
+    env.events().publish(topics, amount);
+}
@@ -1,12 +1,27 @@
-use soroban_sdk::{Address, Env, Map, Vec};
+use soroban_sdk::{contracttype, Address, Env, Vec};
 
-use crate::events::emit_signal_expired;
+use crate::conditions;
+use crate::events::{emit_signal_expired, emit_signal_trigger_expired};
+use crate::query::MAX_LIMIT;
+use crate::resolution;
+use crate::storage::{self, SignalIndex, OPEN_STATUSES};
 use crate::types::{Signal, SignalStatus};
 
 pub const DEFAULT_EXPIRY_SECONDS: u64 = 24 * 60 * 60; // 24 hours
-pub const MAX_CLEANUP_BATCH_SIZE: u32 = 100; // Process max 100 signals per cleanup call
+pub const MAX_CLEANUP_BATCH_SIZE: u32 = 25; // Each expiry also updates the signal's listing indexes
 pub const ARCHIVE_THRESHOLD_SECONDS: u64 = 30 * 24 * 60 * 60; // 30 days
 
+/// Scan positions kept between batch calls, so signals that are not due yet
+/// do not block the ones behind them
+#[contracttype]
+#[derive(Clone)]
+pub enum ExpiryStorageKey {
+    /// status index -> next position for `cleanup_expired_signals`
+    CleanupCursor(SignalStatus),
+    /// next position in the Pending index for `expire_untriggered_signals`
+    TriggerCursor,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct CleanupResult {
     pub signals_processed: u32,
@@ -33,12 +48,28 @@ pub fn should_archive(env: &Env, signal: &Signal) -> bool {
 /// Update signal to expired status if it has passed expiry time
 /// Returns true if status was changed
 pub fn check_and_update_expiry(env: &Env, signal: &mut Signal) -> bool {
-    // Skip if already expired or executed
-    if signal.status == SignalStatus::Expired || signal.status == SignalStatus::Executed {
+    // Skip signals that already reached a terminal status
+    if matches!(
+        signal.status,
+        SignalStatus::Expired
+            | SignalStatus::Executed
+            | SignalStatus::Cancelled
+            | SignalStatus::Successful
+            | SignalStatus::Failed
+    ) {
         return false;
     }
 
     if is_expired(env, signal) {
+        // Untriggered conditional signals are expired by `expire_untriggered_signals`
+        if signal.status == SignalStatus::Pending && conditions::is_waiting(env, signal.id) {
+            return false;
+        }
+        // An oracle outcome is final
+        if resolution::get_outcome(env, signal.id).is_some() {
+            return false;
+        }
+
         signal.status = SignalStatus::Expired;
 
         // Emit expiry event
@@ -51,20 +82,12 @@ pub fn check_and_update_expiry(env: &Env, signal: &mut Signal) -> bool {
 }
 
 /// Get a signal with automatic expiry checking
-pub fn get_signal_with_expiry_check(
-    env: &Env,
-    signals_map: &Map<u64, Signal>,
-    signal_id: u64,
-) -> Option<Signal> {
-    if let Some(mut signal) = signals_map.get(signal_id) {
+pub fn get_signal_with_expiry_check(env: &Env, signal_id: u64) -> Option<Signal> {
+    if let Some(mut signal) = storage::get_signal(env, signal_id) {
         // Check and update expiry status
         if check_and_update_expiry(env, &mut signal) {
             // Status was updated, save it back
-            let mut updated_map = signals_map.clone();
-            updated_map.set(signal_id, signal.clone());
-            env.storage()
-                .instance()
-                .set(&crate::StorageKey::Signals, &updated_map);
+            storage::save_signal(env, &signal);
         }
         Some(signal)
     } else {
@@ -72,125 +95,116 @@ pub fn get_signal_with_expiry_check(
     }
 }
 
-/// Get all active (non-expired) signals for feed
-pub fn get_active_signals(env: &Env, signals_map: &Map<u64, Signal>) -> Vec<Signal> {
+/// Get up to `MAX_LIMIT` active (non-expired) signals for feed
+pub fn get_active_signals(env: &Env) -> Vec<Signal> {
+    filter_active(env, storage::get_open_signals(env, 0, MAX_LIMIT))
+}
+
+/// Keep only signals that have not passed their expiry time
+fn filter_active(env: &Env, signals: Vec<Signal>) -> Vec<Signal> {
     let mut active_signals = Vec::new(env);
     let current_time = env.ledger().timestamp();
 
-    // Collect all keys first
-    let mut keys = Vec::new(env);
-    for i in 0..signals_map.len() {
-        if let Some(key) = signals_map.keys().get(i) {
-            keys.push_back(key);
-        }
-    }
-
-    // Then get signals by key
-    for i in 0..keys.len() {
-        let key = keys.get(i).unwrap();
-        if let Some(signal) = signals_map.get(key) {
-            // Only include non-expired signals
-            if signal.expiry > current_time
-                && signal.status != SignalStatus::Expired
-                && signal.status != SignalStatus::Executed
-            {
-                active_signals.push_back(signal);
-            }
+    for signal in signals.iter() {
+        // Only include non-expired signals
+        if signal.expiry > current_time && storage::is_open(&signal.status) {
+            active_signals.push_back(signal);
         }
     }
 
     active_signals
 }
 
-/// Check if address is in list
-fn is_in_list(list: &Vec<Address>, addr: &Address) -> bool {
-    for i in 0..list.len() {
-        if list.get(i).unwrap() == *addr {
-            return true;
+/// Get active signals filtered to only those from followed providers,
+/// reading at most `MAX_LIMIT` index entries in total.
+/// If followed_providers is empty, returns empty Vec.
+pub fn get_active_signals_filtered(env: &Env, followed_providers: &Vec<Address>) -> Vec<Signal> {
+    let mut filtered = Vec::new(env);
+    let mut budget = MAX_LIMIT;
+    for provider in followed_providers.iter() {
+        if budget == 0 {
+            break;
         }
+        let provider_signals =
+            storage::get_index_signals(env, &SignalIndex::Provider(provider), 0, budget);
+        budget -= provider_signals.len();
+        filtered.append(&filter_active(env, provider_signals));
     }
-    false
+    filtered
 }
 
-/// Get active signals filtered to only those from followed providers.
-/// If followed_providers is empty, returns empty Vec.
-pub fn get_active_signals_filtered(
+/// Walk `index` from the position stored under `cursor_key`, passing up to
+/// `budget` ids to `visit`. `visit` returns true when it moved the signal out
+/// of the index; the last id is then swapped into that slot, so the position
+/// is scanned again. The cursor wraps to the start once the end is reached.
+/// Returns the number of ids visited.
+fn scan_from_cursor<F>(
     env: &Env,
-    signals_map: &Map<u64, Signal>,
-    followed_providers: &Vec<Address>,
-) -> Vec<Signal> {
-    if followed_providers.is_empty() {
-        return Vec::new(env);
+    index: &SignalIndex,
+    cursor_key: &ExpiryStorageKey,
+    budget: u32,
+    mut visit: F,
+) -> u32
+where
+    F: FnMut(u64) -> bool,
+{
+    let mut len = storage::index_len(env, index);
+    let mut pos: u32 = env.storage().instance().get(cursor_key).unwrap_or(0);
+    if pos >= len {
+        pos = 0;
     }
-    let all_active = get_active_signals(env, signals_map);
-    let mut filtered = Vec::new(env);
-    for i in 0..all_active.len() {
-        let signal = all_active.get(i).unwrap();
-        if is_in_list(followed_providers, &signal.provider) {
-            filtered.push_back(signal);
+
+    let mut visited = 0u32;
+    while visited < budget && pos < len {
+        let signal_id = storage::get_index_ids(env, index, pos, 1).get(0).unwrap();
+        visited += 1;
+        if visit(signal_id) {
+            len -= 1;
+        } else {
+            pos += 1;
         }
     }
-    filtered
+
+    let next = if pos >= len { 0 } else { pos };
+    env.storage().instance().set(cursor_key, &next);
+    visited
 }
 
-/// Cleanup expired signals in batches
+/// Cleanup expired signals in batches, resuming where the previous call stopped
 /// Returns number of signals processed and expired
-pub fn cleanup_expired_signals(
-    env: &Env,
-    signals_map: &Map<u64, Signal>,
-    limit: u32,
-) -> CleanupResult {
+pub fn cleanup_expired_signals(env: &Env, limit: u32) -> CleanupResult {
     let batch_size = if limit == 0 || limit > MAX_CLEANUP_BATCH_SIZE {
         MAX_CLEANUP_BATCH_SIZE
     } else {
         limit
     };
 
-    let current_time = env.ledger().timestamp();
     let mut signals_processed = 0u32;
     let mut signals_expired = 0u32;
-    let mut updated_map = signals_map.clone();
 
-    // Collect all keys first
-    let mut keys = Vec::new(env);
-    for i in 0..signals_map.len() {
-        if let Some(key) = signals_map.keys().get(i) {
-            keys.push_back(key);
-        }
-    }
-
-    // Iterate through keys
-    for i in 0..keys.len() {
+    // Only open signals can expire, so walk the open status indexes
+    for status in OPEN_STATUSES.iter() {
         if signals_processed >= batch_size {
             break;
         }
 
-        let signal_id = keys.get(i).unwrap();
-        if let Some(mut signal) = signals_map.get(signal_id) {
-            // Skip already expired or executed signals
-            if signal.status == SignalStatus::Expired || signal.status == SignalStatus::Executed {
-                continue;
-            }
-
-            signals_processed += 1;
-
-            // Check if expired
-            if signal.expiry < current_time {
-                signal.status = SignalStatus::Expired;
-                updated_map.set(signal_id, signal.clone());
+        signals_processed += scan_from_cursor(
+            env,
+            &SignalIndex::Status(status.clone()),
+            &ExpiryStorageKey::CleanupCursor(status.clone()),
+            batch_size - signals_processed,
+            |signal_id| {
+                let Some(mut signal) = storage::get_signal(env, signal_id) else {
+                    return false;
+                };
+                if !check_and_update_expiry(env, &mut signal) {
+                    return false;
+                }
+                storage::save_signal(env, &signal);
                 signals_expired += 1;
-
-                // Emit expiry event
-                emit_signal_expired(env, signal.id, signal.provider.clone(), signal.expiry);
-            }
-        }
-    }
-
-    // Save updated map if any changes were made
-    if signals_expired > 0 {
-        env.storage()
-            .instance()
-            .set(&crate::StorageKey::Signals, &updated_map);
+                true
+            },
+        );
     }
 
     CleanupResult {
@@ -199,9 +213,11 @@ pub fn cleanup_expired_signals(
     }
 }
 
-/// Archive old expired signals (optional - removes from active storage)
-/// Returns number of signals archived
-pub fn archive_old_signals(env: &Env, signals_map: &Map<u64, Signal>, limit: u32) -> u32 {
+/// Expire conditional signals whose condition was never met before expiry.
+/// They keep their condition entry, so they are never resolved or counted in
+/// provider stats.
+/// Returns number of signals expired
+pub fn expire_untriggered_signals(env: &Env, limit: u32) -> u32 {
     let batch_size = if limit == 0 || limit > MAX_CLEANUP_BATCH_SIZE {
         MAX_CLEANUP_BATCH_SIZE
     } else {
@@ -209,87 +225,89 @@ pub fn archive_old_signals(env: &Env, signals_map: &Map<u64, Signal>, limit: u32
     };
 
     let current_time = env.ledger().timestamp();
-    let mut archived_count = 0u32;
-    let mut updated_map = signals_map.clone();
+    let mut expired = 0u32;
+    scan_from_cursor(
+        env,
+        &SignalIndex::Status(SignalStatus::Pending),
+        &ExpiryStorageKey::TriggerCursor,
+        batch_size,
+        |signal_id| {
+            if !conditions::is_waiting(env, signal_id) {
+                return false;
+            }
+            match storage::get_signal(env, signal_id) {
+                Some(mut signal) if signal.expiry < current_time => {
+                    signal.status = SignalStatus::Expired;
+                    storage::save_signal(env, &signal);
+                    conditions::archive_condition(env, signal_id);
+                    expired += 1;
+
+                    emit_signal_trigger_expired(
+                        env,
+                        signal.id,
+                        signal.provider.clone(),
+                        signal.expiry,
+                    );
+                    true
+                }
+                _ => false,
+            }
+        },
+    );
+    expired
+}
+
+/// Archive old expired signals (drops them from the indexes and lets the entries expire)
+/// Returns number of signals archived
+pub fn archive_old_signals(env: &Env, limit: u32) -> u32 {
+    let batch_size = if limit == 0 || limit > MAX_CLEANUP_BATCH_SIZE {
+        MAX_CLEANUP_BATCH_SIZE
+    } else {
+        limit
+    };
+
+    let index = SignalIndex::Status(SignalStatus::Expired);
+    let ids = storage::get_all_index_ids(env, &index);
 
     // Collect signal IDs to archive
     let mut to_archive = Vec::new(env);
 
-    // Collect all keys first
-    let mut keys = Vec::new(env);
-    for i in 0..signals_map.len() {
-        if let Some(key) = signals_map.keys().get(i) {
-            keys.push_back(key);
-        }
-    }
-
-    for i in 0..keys.len() {
-        if archived_count >= batch_size {
+    for signal_id in ids.iter() {
+        if to_archive.len() >= batch_size {
             break;
         }
 
-        let signal_id = keys.get(i).unwrap();
-        if let Some(signal) = signals_map.get(signal_id) {
+        if let Some(signal) = storage::get_signal(env, signal_id) {
             // Only archive signals expired for more than 30 days
-            if signal.status == SignalStatus::Expired {
-                let time_since_expiry = current_time.saturating_sub(signal.expiry);
-                if time_since_expiry > ARCHIVE_THRESHOLD_SECONDS {
-                    to_archive.push_back(signal_id);
-                    archived_count += 1;
-                }
+            if should_archive(env, &signal) {
+                to_archive.push_back(signal_id);
             }
         }
     }
 
-    // Remove archived signals from active storage
-    for i in 0..to_archive.len() {
-        let signal_id = to_archive.get(i).unwrap();
-        updated_map.remove(signal_id);
+    // Remove archived signals from the indexes
+    for signal_id in to_archive.iter() {
+        storage::archive_signal(env, signal_id);
     }
 
-    // Save updated map if any signals were archived
-    if archived_count > 0 {
-        env.storage()
-            .instance()
-            .set(&crate::StorageKey::Signals, &updated_map);
-    }
-
-    archived_count
+    to_archive.len()
 }
 
 /// Get count of expired signals
-pub fn count_expired_signals(signals_map: &Map<u64, Signal>) -> u32 {
-    let mut count = 0u32;
-
-    for i in 0..signals_map.keys().len() {
-        if let Some(key) = signals_map.keys().get(i) {
-            if let Some(signal) = signals_map.get(key) {
-                if signal.status == SignalStatus::Expired {
-                    count += 1;
-                }
-            }
-        }
-    }
-
-    count
+pub fn count_expired_signals(env: &Env) -> u32 {
+    storage::index_len(env, &SignalIndex::Status(SignalStatus::Expired))
 }
 
-/// Get count of signals pending expiry check
-pub fn count_signals_pending_expiry(env: &Env, signals_map: &Map<u64, Signal>) -> u32 {
+/// Get count of signals pending expiry check among the first
+/// `MAX_CLEANUP_BATCH_SIZE` open signals, so at most one cleanup batch
+pub fn count_signals_pending_expiry(env: &Env) -> u32 {
     let current_time = env.ledger().timestamp();
     let mut count = 0u32;
 
-    for i in 0..signals_map.keys().len() {
-        if let Some(key) = signals_map.keys().get(i) {
-            if let Some(signal) = signals_map.get(key) {
-                // Count signals that are past expiry but not yet marked as expired
-                if signal.expiry < current_time
-                    && signal.status != SignalStatus::Expired
-                    && signal.status != SignalStatus::Executed
-                {
-                    count += 1;
-                }
-            }
+    for signal in storage::get_open_signals(env, 0, MAX_CLEANUP_BATCH_SIZE).iter() {
+        // Count signals that are past expiry but not yet marked as expired
+        if signal.expiry < current_time {
+            count += 1;
         }
     }
 
@@ -299,12 +317,19 @@ pub fn count_signals_pending_expiry(env: &Env, signals_map: &Map<u64, Signal>) -
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::categories::{RiskLevel, SignalCategory};
     use crate::types::SignalAction;
+    use crate::SignalRegistry;
     use soroban_sdk::{
         testutils::{Address as _, Ledger},
         Address, Env, String,
     };
 
+    fn setup(env: &Env) -> Address {
+        #[allow(deprecated)]
+        env.register_contract(None, SignalRegistry)
+    }
+
     fn create_test_signal(env: &Env, id: u64, expiry: u64) -> Signal {
         Signal {
             id,
@@ -320,6 +345,10 @@ mod tests {
             successful_executions: 0,
             total_volume: 0,
             total_roi: 0,
+            category: SignalCategory::SwingTrade,
+            tags: soroban_sdk::Vec::new(env),
+            risk_level: RiskLevel::Medium,
+            is_collaborative: false,
         }
     }
 
@@ -351,21 +380,29 @@ mod tests {
         // Set a known timestamp
         env.ledger().set_timestamp(1000);
         let current_time = env.ledger().timestamp();
-
-        // Active signal that should expire
-        let mut signal = create_test_signal(&env, 1, current_time.saturating_sub(100));
-        assert!(check_and_update_expiry(&env, &mut signal));
-        assert_eq!(signal.status, SignalStatus::Expired);
-
-        // Already expired signal (no change)
-        let mut expired_signal = create_test_signal(&env, 2, current_time.saturating_sub(100));
-        expired_signal.status = SignalStatus::Expired;
-        assert!(!check_and_update_expiry(&env, &mut expired_signal));
-
-        // Executed signal (no change)
-        let mut executed_signal = create_test_signal(&env, 3, current_time.saturating_sub(100));
-        executed_signal.status = SignalStatus::Executed;
-        assert!(!check_and_update_expiry(&env, &mut executed_signal));
+        let contract_id = setup(&env);
+
+        env.as_contract(&contract_id, || {
+            // Active signal that should expire
+            let mut signal = create_test_signal(&env, 1, current_time.saturating_sub(100));
+            assert!(check_and_update_expiry(&env, &mut signal));
+            assert_eq!(signal.status, SignalStatus::Expired);
+
+            // Already expired signal (no change)
+            let mut expired_signal = create_test_signal(&env, 2, current_time.saturating_sub(100));
+            expired_signal.status = SignalStatus::Expired;
+            assert!(!check_and_update_expiry(&env, &mut expired_signal));
+
+            // Executed signal (no change)
+            let mut executed_signal = create_test_signal(&env, 3, current_time.saturating_sub(100));
+            executed_signal.status = SignalStatus::Executed;
+            assert!(!check_and_update_expiry(&env, &mut executed_signal));
+
+            // Resolved signal keeps its outcome status
+            let mut resolved_signal = create_test_signal(&env, 4, current_time.saturating_sub(100));
+            resolved_signal.status = SignalStatus::Successful;
+            assert!(!check_and_update_expiry(&env, &mut resolved_signal));
+        });
     }
 
     #[test]
@@ -375,28 +412,35 @@ mod tests {
         // Set a known timestamp
         env.ledger().set_timestamp(10000);
         let current_time = env.ledger().timestamp();
-        let mut signals = Map::new(&env);
+        let contract_id = setup(&env);
 
-        // Add 3 active signals
-        for i in 0..3 {
-            let signal = create_test_signal(&env, i, current_time + 1000);
-            signals.set(i, signal);
-        }
+        env.as_contract(&contract_id, || {
+            // Add 3 active signals
+            for i in 0..3 {
+                let signal = create_test_signal(&env, i, current_time + 1000);
+                storage::save_signal(&env, &signal);
+            }
 
-        // Add 2 expired signals
-        for i in 3..5 {
-            let mut signal = create_test_signal(&env, i, current_time.saturating_sub(1000));
-            signal.status = SignalStatus::Expired;
-            signals.set(i, signal);
-        }
+            // Add 2 expired signals
+            for i in 3..5 {
+                let mut signal = create_test_signal(&env, i, current_time.saturating_sub(1000));
+                signal.status = SignalStatus::Expired;
+                storage::save_signal(&env, &signal);
+            }
+
+            // Add 1 executed signal
+            let mut executed = create_test_signal(&env, 5, current_time + 1000);
+            executed.status = SignalStatus::Executed;
+            storage::save_signal(&env, &executed);
 
-        // Add 1 executed signal
-        let mut executed = create_test_signal(&env, 5, current_time + 1000);
-        executed.status = SignalStatus::Executed;
-        signals.set(5, executed);
+            // Add 1 resolved signal that has not reached its expiry
+            let mut resolved = create_test_signal(&env, 6, current_time + 1000);
+            resolved.status = SignalStatus::Successful;
+            storage::save_signal(&env, &resolved);
 
-        let active = get_active_signals(&env, &signals);
-        assert_eq!(active.len(), 3); // Only the 3 active, non-expired signals
+            let active = get_active_signals(&env);
+            assert_eq!(active.len(), 3); // Only the 3 active, non-expired signals
+        });
     }
 
     #[test]
@@ -431,22 +475,24 @@ mod tests {
         // Set a known timestamp
         env.ledger().set_timestamp(10000);
         let current_time = env.ledger().timestamp();
-        let mut signals = Map::new(&env);
+        let contract_id = setup(&env);
 
-        // Add 4 expired signals
-        for i in 0..4 {
-            let mut signal = create_test_signal(&env, i, current_time.saturating_sub(1000));
-            signal.status = SignalStatus::Expired;
-            signals.set(i, signal);
-        }
+        env.as_contract(&contract_id, || {
+            // Add 4 expired signals
+            for i in 0..4 {
+                let mut signal = create_test_signal(&env, i, current_time.saturating_sub(1000));
+                signal.status = SignalStatus::Expired;
+                storage::save_signal(&env, &signal);
+            }
 
-        // Add 3 active signals
-        for i in 4..7 {
-            let signal = create_test_signal(&env, i, current_time + 1000);
-            signals.set(i, signal);
-        }
+            // Add 3 active signals
+            for i in 4..7 {
+                let signal = create_test_signal(&env, i, current_time + 1000);
+                storage::save_signal(&env, &signal);
+            }
 
-        assert_eq!(count_expired_signals(&signals), 4);
+            assert_eq!(count_expired_signals(&env), 4);
+        });
     }
 
     #[test]
@@ -456,27 +502,54 @@ mod tests {
         // Set a known timestamp
         env.ledger().set_timestamp(10000);
         let current_time = env.ledger().timestamp();
-        let mut signals = Map::new(&env);
+        let contract_id = setup(&env);
 
-        // Add 3 signals past expiry but not marked expired yet
-        for i in 0..3 {
-            let signal = create_test_signal(&env, i, current_time.saturating_sub(1000));
-            signals.set(i, signal);
-        }
+        env.as_contract(&contract_id, || {
+            // Add 3 signals past expiry but not marked expired yet
+            for i in 0..3 {
+                let signal = create_test_signal(&env, i, current_time.saturating_sub(1000));
+                storage::save_signal(&env, &signal);
+            }
 
-        // Add 2 already marked as expired
-        for i in 3..5 {
-            let mut signal = create_test_signal(&env, i, current_time.saturating_sub(1000));
-            signal.status = SignalStatus::Expired;
-            signals.set(i, signal);
-        }
+            // Add 2 already marked as expired
+            for i in 3..5 {
+                let mut signal = create_test_signal(&env, i, current_time.saturating_sub(1000));
+                signal.status = SignalStatus::Expired;
+                storage::save_signal(&env, &signal);
+            }
 
-        // Add 2 active signals
-        for i in 5..7 {
-            let signal = create_test_signal(&env, i, current_time + 1000);
-            signals.set(i, signal);
-        }
+            // Add 2 active signals
+            for i in 5..7 {
+                let signal = create_test_signal(&env, i, current_time + 1000);
+                storage::save_signal(&env, &signal);
+            }
 
-        assert_eq!(count_signals_pending_expiry(&env, &signals), 3);
+            assert_eq!(count_signals_pending_expiry(&env), 3);
+        });
+    }
+
+    #[test]
+    fn test_archive_drops_index_entries() {
+        let env = Env::default();
+        let current_time = 100 * 24 * 60 * 60; // 100 days
+        env.ledger().set_timestamp(current_time);
+        let contract_id = setup(&env);
+
+        env.as_contract(&contract_id, || {
+            let mut old = create_test_signal(&env, 1, current_time - 31 * 24 * 60 * 60);
+            old.status = SignalStatus::Expired;
+            storage::save_signal(&env, &old);
+
+            let mut recent = create_test_signal(&env, 2, current_time - 24 * 60 * 60);
+            recent.status = SignalStatus::Expired;
+            storage::save_signal(&env, &recent);
+
+            assert_eq!(archive_old_signals(&env, 10), 1);
+            assert_eq!(count_expired_signals(&env), 1);
+            assert_eq!(
+                storage::index_len(&env, &SignalIndex::Provider(old.provider.clone())),
+                0
+            );
+        });
     }
 }
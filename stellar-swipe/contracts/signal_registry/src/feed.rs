@@ -0,0 +1,195 @@
+//! Personalized swipe feed.
+//!
+//! Active signals the user has not swiped yet and that pass the user's
+//! preference filters are scored by how well they match the user: followed
+//! provider, preferred category, watched pair, provider success rate,
+//! provider reputation and freshness. Highest score first, newest first on
+//! ties, then highest id.
+//!
+//! The cursor is the rank key of the last signal returned plus the time the
+//! first page was scored at. Later pages are scored at that same time and only
+//! hold signals ranked after the key, so signals swiped or closed between
+//! calls neither repeat nor shift entries; new signals that rank above the key
+//! show up from the next `Start`. Each page keeps just the best `limit + 1`
+//! candidates instead of sorting every active signal.
+//!
+//! Only the last `FEED_SCAN_LIMIT` entries of the active index are scored.
+//! New signals are appended there, so these are the newest signals (removals
+//! swap the last entry into the freed slot, so the order is only approximate).
+
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
+
+use crate::leaderboard;
+use crate::preferences;
+use crate::query;
+use crate::social;
+use crate::storage::{self, SignalIndex};
+use crate::swipes;
+use crate::types::{ProviderPerformance, Signal, SignalStatus, SignalSummary};
+
+pub const FOLLOWED_PROVIDER_SCORE: u32 = 3_000;
+pub const PREFERRED_CATEGORY_SCORE: u32 = 2_000;
+pub const WATCHED_PAIR_SCORE: u32 = 1_000;
+/// Awarded in full at a 100% provider success rate
+pub const SUCCESS_RATE_MAX_SCORE: u32 = 2_000;
+/// Awarded in full at a perfect (10000) time-decayed reputation score
+pub const REPUTATION_MAX_SCORE: u32 = 2_000;
+/// Awarded in full to a brand new signal, decaying to 0 over `FRESHNESS_WINDOW_SECONDS`
+pub const FRESHNESS_MAX_SCORE: u32 = 2_000;
+pub const FRESHNESS_WINDOW_SECONDS: u64 = 86_400;
+/// Active signals scored per call
+pub const FEED_SCAN_LIMIT: u32 = 40;
+
+/// Position in a user's ranked feed
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FeedCursor {
+    /// First page
+    Start,
+    /// (as_of, score, timestamp, id): after the signal with this rank key, in
+    /// the feed scored at ledger time `as_of`
+    After(u64, u32, u64, u64),
+    /// Past the last page
+    End,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FeedPage {
+    pub signals: Vec<SignalSummary>,
+    /// Cursor for the next page, `End` on the last page
+    pub next_cursor: FeedCursor,
+}
+
+/// Rank key, compared in descending order
+fn rank_key(score: u32, signal: &Signal) -> (u32, u64, u64) {
+    (score, signal.timestamp, signal.id)
+}
+
+fn score_signal(
+    now: u64,
+    signal: &Signal,
+    followed: &Vec<Address>,
+    prefs: &Option<preferences::UserPreferences>,
+    stats: &Map<Address, ProviderPerformance>,
+    reputation_score: u32,
+) -> u32 {
+    let mut score = reputation_score.min(10_000) * REPUTATION_MAX_SCORE / 10_000;
+
+    if followed.contains(&signal.provider) {
+        score += FOLLOWED_PROVIDER_SCORE;
+    }
+
+    if let Some(prefs) = prefs {
+        if prefs.categories.contains(&signal.category) {
+            score += PREFERRED_CATEGORY_SCORE;
+        }
+        if prefs.watched_pairs.contains(&signal.asset_pair) {
+            score += WATCHED_PAIR_SCORE;
+        }
+    }
+
+    if let Some(provider_stats) = stats.get(signal.provider.clone()) {
+        score += provider_stats.success_rate.min(10_000) * SUCCESS_RATE_MAX_SCORE / 10_000;
+    }
+
+    let age = now.saturating_sub(signal.timestamp);
+    if age < FRESHNESS_WINDOW_SECONDS {
+        score += ((FRESHNESS_WINDOW_SECONDS - age) * FRESHNESS_MAX_SCORE as u64
+            / FRESHNESS_WINDOW_SECONDS) as u32;
+    }
+
+    score
+}
+
+/// Page of `user`'s ranked feed after `cursor`
+pub fn get_feed(
+    env: &Env,
+    user: &Address,
+    stats: &Map<Address, ProviderPerformance>,
+    cursor: FeedCursor,
+    limit: u32,
+) -> FeedPage {
+    let (now, after) = match cursor {
+        FeedCursor::Start => (env.ledger().timestamp(), None),
+        FeedCursor::After(as_of, score, timestamp, id) => (as_of, Some((score, timestamp, id))),
+        FeedCursor::End => {
+            return FeedPage {
+                signals: Vec::new(env),
+                next_cursor: FeedCursor::End,
+            }
+        }
+    };
+
+    let followed = social::get_followed_providers(env, user);
+    let prefs = preferences::get_preferences(env, user);
+    let mut reputations: Map<Address, u32> = Map::new(env);
+
+    let limit = query::clamp_limit(limit);
+    let active = storage::index_len(env, &SignalIndex::Status(SignalStatus::Active));
+    let candidates = query::collect_swipeable_signals(
+        env,
+        active.saturating_sub(FEED_SCAN_LIMIT),
+        FEED_SCAN_LIMIT,
+    );
+
+    // Best `limit + 1` candidates ranked after the cursor, in rank order.
+    // The extra one only tells whether another page follows.
+    let mut ranked: Vec<(u32, Signal)> = Vec::new(env);
+    for signal in candidates.iter() {
+        if swipes::get_swipe(env, user, signal.id).is_some()
+            || prefs
+                .as_ref()
+                .is_some_and(|p| !preferences::allows(p, &signal, stats))
+        {
+            continue;
+        }
+        let reputation_score = match reputations.get(signal.provider.clone()) {
+            Some(reputation_score) => reputation_score,
+            None => {
+                let reputation_score = leaderboard::get_reputation_score(env, &signal.provider);
+                reputations.set(signal.provider.clone(), reputation_score);
+                reputation_score
+            }
+        };
+        let score = score_signal(now, &signal, &followed, &prefs, stats, reputation_score);
+        let key = rank_key(score, &signal);
+        if after.is_some_and(|after| key >= after) {
+            continue;
+        }
+
+        let mut pos = ranked.len();
+        while pos > 0 {
+            let (prev_score, prev) = ranked.get(pos - 1).unwrap();
+            if rank_key(prev_score, &prev) > key {
+                break;
+            }
+            pos -= 1;
+        }
+        if pos <= limit {
+            ranked.insert(pos, (score, signal));
+            if ranked.len() > limit + 1 {
+                ranked.pop_back();
+            }
+        }
+    }
+
+    let has_more = ranked.len() > limit;
+    if has_more {
+        ranked.pop_back();
+    }
+
+    let mut signals = Vec::new(env);
+    let mut next_cursor = FeedCursor::End;
+    for (score, signal) in ranked.iter() {
+        if has_more {
+            next_cursor = FeedCursor::After(now, score, signal.timestamp, signal.id);
+        }
+        signals.push_back(query::to_summary(env, signal));
+    }
+
+    FeedPage {
+        signals,
+        next_cursor,
+    }
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, Map};
+use soroban_sdk::{token, Address, Env, Map};
 
 use crate::errors::FeeError;
 use crate::events::emit_fee_collected;
@@ -10,6 +10,7 @@ pub const BPS_DENOMINATOR: u32 = 10000; // 100% = 10000 bps
 pub const PLATFORM_SHARE_PERCENTAGE: u32 = 70; // 70%
                                                // pub const PROVIDER_SHARE_PERCENTAGE: u32 = 30; // 30%
 pub const MIN_TRADE_AMOUNT: i128 = 1000; // Minimum trade to ensure non-zero fee
+pub const SUBSCRIPTION_PLATFORM_FEE_BPS: u32 = 1000; // 10% of subscription payments
 
 /// Calculate fee for a given trade amount
 /// Returns (fee_amount, amount_after_fee)
@@ -143,6 +144,50 @@ pub fn collect_and_distribute_fee(
     Ok(breakdown)
 }
 
+/// Collect a subscription payment in `asset`. The platform share is held by
+/// the contract and tracked in the treasury, the rest goes to the provider.
+/// Returns the platform fee.
+pub fn collect_subscription_payment(
+    env: &Env,
+    asset: Asset,
+    subscriber: &Address,
+    provider: &Address,
+    amount: i128,
+) -> Result<i128, FeeError> {
+    if amount <= 0 {
+        return Err(FeeError::InvalidAmount);
+    }
+
+    let platform_fee = amount
+        .checked_mul(SUBSCRIPTION_PLATFORM_FEE_BPS as i128)
+        .ok_or(FeeError::ArithmeticOverflow)?
+        / BPS_DENOMINATOR as i128;
+    let provider_share = amount - platform_fee;
+
+    let token = token::Client::new(env, &asset.contract);
+    if provider_share > 0 {
+        token.transfer(subscriber, provider, &provider_share);
+    }
+    if platform_fee > 0 {
+        token.transfer(subscriber, env.current_contract_address(), &platform_fee);
+        add_to_treasury(env, asset.clone(), platform_fee)?;
+    }
+
+    let platform_treasury =
+        get_platform_treasury(env).unwrap_or_else(|| env.current_contract_address());
+    emit_fee_collected(
+        env,
+        asset,
+        amount,
+        platform_fee,
+        provider_share,
+        provider.clone(),
+        platform_treasury,
+    );
+
+    Ok(platform_fee)
+}
+
 /// Set platform treasury address (admin only)
 pub fn set_platform_treasury(env: &Env, treasury: Address) {
     env.storage()
@@ -0,0 +1,106 @@
+//! Platform-wide daily aggregates for dashboards.
+//!
+//! Each UTC day has one `DailyGlobalStats` entry that is updated in place
+//! whenever a signal is created, copied or resolved, so the history query
+//! reads one entry per day instead of scanning signals. Distinct active
+//! providers are counted through per-day markers in temporary storage that
+//! expire once the day is over.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass, DAY_IN_LEDGERS};
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Days returned by a single history query
+pub const MAX_GLOBAL_HISTORY_DAYS: u64 = 90;
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DailyGlobalStats {
+    /// Days since the Unix epoch
+    pub day: u64,
+    pub signals_created: u32,
+    pub executions: u32,
+    pub volume: i128,
+    pub resolved_signals: u32,
+    pub successful_signals: u32,
+    /// Providers that posted at least one signal that day
+    pub active_providers: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum GlobalStatsKey {
+    Day(u64),
+    ActiveProvider(u64, Address),
+}
+
+fn today(env: &Env) -> u64 {
+    env.ledger().timestamp() / SECONDS_PER_DAY
+}
+
+fn load(env: &Env, day: u64) -> DailyGlobalStats {
+    env.storage()
+        .persistent()
+        .get(&GlobalStatsKey::Day(day))
+        .unwrap_or(DailyGlobalStats {
+            day,
+            ..Default::default()
+        })
+}
+
+fn save(env: &Env, stats: &DailyGlobalStats) {
+    let key = GlobalStatsKey::Day(stats.day);
+    env.storage().persistent().set(&key, stats);
+    bump_persistent(env, &key, TtlClass::History);
+}
+
+/// Count a newly stored signal and mark its provider active today
+pub fn record_signal_created(env: &Env, provider: &Address) {
+    let day = today(env);
+    let mut stats = load(env, day);
+    stats.signals_created = stats.signals_created.saturating_add(1);
+
+    let marker = GlobalStatsKey::ActiveProvider(day, provider.clone());
+    if !env.storage().temporary().has(&marker) {
+        env.storage().temporary().set(&marker, &true);
+        env.storage()
+            .temporary()
+            .extend_ttl(&marker, DAY_IN_LEDGERS, DAY_IN_LEDGERS);
+        stats.active_providers = stats.active_providers.saturating_add(1);
+    }
+    save(env, &stats);
+}
+
+/// Count a copied trade and its volume
+pub fn record_execution(env: &Env, volume: i128) {
+    let mut stats = load(env, today(env));
+    stats.executions = stats.executions.saturating_add(1);
+    stats.volume = stats.volume.saturating_add(volume);
+    save(env, &stats);
+}
+
+/// Count a signal reaching a terminal outcome
+pub fn record_resolution(env: &Env, successful: bool) {
+    let mut stats = load(env, today(env));
+    stats.resolved_signals = stats.resolved_signals.saturating_add(1);
+    if successful {
+        stats.successful_signals = stats.successful_signals.saturating_add(1);
+    }
+    save(env, &stats);
+}
+
+/// One entry per day in `[from_day, to_day]`, oldest first. Days without
+/// activity are zero. Ranges longer than `MAX_GLOBAL_HISTORY_DAYS` keep the
+/// most recent days.
+pub fn get_global_history(env: &Env, from_day: u64, to_day: u64) -> Vec<DailyGlobalStats> {
+    let mut history = Vec::new(env);
+    if to_day < from_day {
+        return history;
+    }
+    let from_day = from_day.max(to_day.saturating_sub(MAX_GLOBAL_HISTORY_DAYS - 1));
+    for day in from_day..=to_day {
+        history.push_back(load(env, day));
+    }
+    history
+}
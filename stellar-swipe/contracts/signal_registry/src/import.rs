@@ -1,6 +1,7 @@
 use crate::errors::ImportError;
 use crate::types::SignalAction;
-use soroban_sdk::{Address, Bytes, Env, Map, String, Vec};
+use soroban_sdk::{Address, Bytes, Env, Map, String};
+use stellar_swipe_common::{bump_persistent, TtlClass};
 
 const MAX_BATCH_SIZE: u32 = 100;
 const MAX_RATIONALE_LEN: u32 = 500;
@@ -11,7 +12,7 @@ pub struct ImportResult {
 }
 
 pub fn import_signals_csv(
-    env: &Env,
+    _env: &Env,
     _provider: &Address,
     data: Bytes,
     validate_only: bool,
@@ -31,13 +32,13 @@ pub fn import_signals_csv(
     }
 
     // Skip header (first line)
-    for i in 1..lines.len().min(MAX_BATCH_SIZE as usize + 1) {
+    for line in lines.iter().skip(1).take(MAX_BATCH_SIZE as usize) {
         if success_count >= MAX_BATCH_SIZE {
             error_count += 1;
             break;
         }
 
-        match validate_csv_line(&lines[i]) {
+        match validate_csv_line(line) {
             Ok(_) => {
                 if !validate_only {
                     success_count += 1;
@@ -141,7 +142,7 @@ fn validate_csv_line(fields: &[alloc::vec::Vec<u8>]) -> Result<(), ImportError>
 }
 
 fn contains_byte(data: &[u8], byte: u8) -> bool {
-    data.iter().any(|&b| b == byte)
+    data.contains(&byte)
 }
 
 fn validate_action(data: &[u8]) -> Result<SignalAction, ImportError> {
@@ -168,6 +169,19 @@ fn validate_action(data: &[u8]) -> Result<SignalAction, ImportError> {
         if &upper == b"SELL" {
             return Ok(SignalAction::Sell);
         }
+        if &upper == b"HOLD" {
+            return Ok(SignalAction::Hold);
+        }
+    }
+
+    if trimmed.len() == 7 {
+        let mut upper = [0u8; 7];
+        for (i, b) in trimmed.iter().enumerate() {
+            upper[i] = to_upper(*b);
+        }
+        if &upper == b"NEUTRAL" {
+            return Ok(SignalAction::Hold);
+        }
     }
 
     Err(ImportError::InvalidAction)
@@ -187,7 +201,7 @@ fn parse_i128_from_bytes(data: &[u8]) -> Result<i128, ImportError> {
         if byte == b'-' && !started {
             negative = true;
             started = true;
-        } else if byte >= b'0' && byte <= b'9' {
+        } else if byte.is_ascii_digit() {
             result = result * 10 + (byte - b'0') as i128;
             started = true;
         } else if byte == b'.' {
@@ -206,7 +220,7 @@ fn parse_i128_from_bytes(data: &[u8]) -> Result<i128, ImportError> {
 
 fn parse_u32_from_bytes(data: &[u8]) -> Result<u32, ImportError> {
     let value = parse_i128_from_bytes(data)?;
-    if value < 0 || value > u32::MAX as i128 {
+    if !(0..=u32::MAX as i128).contains(&value) {
         return Err(ImportError::InvalidFormat);
     }
     Ok(value as u32)
@@ -228,31 +242,7 @@ fn trim_bytes(data: &[u8]) -> &[u8] {
 }
 
 fn to_upper(byte: u8) -> u8 {
-    if byte >= b'a' && byte <= b'z' {
-        byte - 32
-    } else {
-        byte
-    }
-}
-
-// External ID mapping
-pub fn store_external_id_mapping(
-    env: &Env,
-    provider: &Address,
-    external_id: &String,
-    signal_id: u64,
-) {
-    let key = (provider.clone(), external_id.clone());
-    let mut mappings: Map<(Address, String), u64> = env
-        .storage()
-        .persistent()
-        .get(&crate::StorageKey::ExternalIdMappings)
-        .unwrap_or(Map::new(env));
-
-    mappings.set(key, signal_id);
-    env.storage()
-        .persistent()
-        .set(&crate::StorageKey::ExternalIdMappings, &mappings);
+    byte.to_ascii_uppercase()
 }
 
 pub fn get_signal_by_external_id(
@@ -264,6 +254,7 @@ pub fn get_signal_by_external_id(
         .storage()
         .persistent()
         .get(&crate::StorageKey::ExternalIdMappings)?;
+    bump_persistent(env, &crate::StorageKey::ExternalIdMappings, TtlClass::Hot);
 
     mappings.get((provider.clone(), external_id.clone()))
 }
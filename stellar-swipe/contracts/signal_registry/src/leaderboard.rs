@@ -1,7 +1,14 @@
 //! Leaderboard query functions for signal providers.
 //!
-//! Returns top providers ranked by success rate, total volume, or followers.
-//! Rankings are computed on query from current stats (real-time updates).
+//! Returns top providers ranked by success rate, total volume, followers,
+//! risk-adjusted return, or time-decayed reputation. Rankings are computed on query from current stats
+//! (real-time updates).
+//! 7 and 30 day windows rank on daily per-provider buckets of resolved
+//! signals, keyed by the day each call took effect (the commit time for
+//! revealed signals), so only calls made inside the window count. Risk-adjusted
+//! metrics rank on each provider's full return series; the window still
+//! decides who qualifies. The same resolutions extend each provider's
+//! equity curve.
 //!
 //! # Gas Costs
 //! - get_leaderboard: O(P²) for P qualified providers (bubble sort)
@@ -9,8 +16,14 @@
 //! - Leaderboard returns in <300ms (query uses current snapshot)
 
 use soroban_sdk::{contracttype, Address, Env, Map, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
 
-use crate::types::ProviderPerformance;
+use crate::admin;
+use crate::performance::{self, EquityPoint, ProviderReputation, ProviderRiskStats};
+use crate::profiles::{self, ProviderProfile};
+use crate::social;
+use crate::stake;
+use crate::types::{ProviderPerformance, SignalStatus};
 
 /// Minimum signals a provider must have to appear on the leaderboard
 pub const MIN_SIGNALS_QUALIFICATION: u32 = 5;
@@ -21,15 +34,70 @@ pub const DEFAULT_LEADERBOARD_LIMIT: u32 = 10;
 /// Maximum number of providers that can be requested
 pub const MAX_LEADERBOARD_LIMIT: u32 = 50;
 
+pub const DAY_SECONDS: u64 = 86_400;
+
+/// Daily buckets kept per provider, enough for the longest window
+pub const MAX_WINDOW_DAYS: u64 = 30;
+
+/// Equity curve points returned when no limit is given
+pub const DEFAULT_EQUITY_POINTS: u32 = 50;
+
+/// Equity curve points kept per provider; older points are dropped
+pub const MAX_EQUITY_POINTS: u32 = 100;
+
 /// Metric used to rank providers on the leaderboard
 #[contracttype]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LeaderboardMetric {
     SuccessRate,
     Volume,
-    Followers, // Future feature - returns empty for MVP
+    Followers,
+    /// Mean ROI over its standard deviation (Sharpe-like)
+    RiskAdjustedReturn,
+    /// Cumulative ROI over max drawdown (Calmar-like)
+    DrawdownAdjustedReturn,
+    /// Time-decayed reputation score, including stake and slashes
+    Reputation,
+}
+
+/// Period of resolved signals a leaderboard ranks on
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeaderboardWindow {
+    SevenDays,
+    ThirtyDays,
+    AllTime,
 }
 
+impl LeaderboardWindow {
+    /// Days covered, None for all time
+    pub fn days(self) -> Option<u64> {
+        match self {
+            LeaderboardWindow::SevenDays => Some(7),
+            LeaderboardWindow::ThirtyDays => Some(MAX_WINDOW_DAYS),
+            LeaderboardWindow::AllTime => None,
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum LeaderboardStorageKey {
+    /// provider -> Map<day, ProviderPerformance> of resolved signals that took
+    /// effect that day
+    DailyStats(Address),
+    /// provider -> ProviderRiskStats over every resolved signal
+    RiskStats(Address),
+    /// provider -> ProviderReputation
+    Reputation(Address),
+    /// provider -> Vec<EquityPoint>, oldest first
+    EquityCurve(Address),
+}
+
+/// (provider, stats in the window, follower count, all-time risk stats,
+/// reputation score)
+type Candidate = (Address, ProviderPerformance, u32, ProviderRiskStats, u32);
+
 /// Single entry in the leaderboard
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -39,40 +107,45 @@ pub struct ProviderLeaderboard {
     pub success_rate: u32,
     pub total_volume: i128,
     pub total_signals: u32,
+    pub followers: u32,
+    pub risk_adjusted_return: i128,
+    pub drawdown_adjusted_return: i128,
+    pub max_drawdown: i128,
+    pub reputation_score: u32,
+    pub profile: ProviderProfile,
 }
 
-/// Check if a provider qualifies for the leaderboard
+/// Check if a provider qualifies for a performance leaderboard
 #[inline]
 fn is_qualified(stats: &ProviderPerformance) -> bool {
     stats.total_signals >= MIN_SIGNALS_QUALIFICATION && stats.success_rate > 0
 }
 
-/// Sort qualified vec by success rate (desc), tie-break by total_signals (desc)
-fn sort_by_success_rate(qualified: &mut Vec<(Address, ProviderPerformance)>) {
-    let len = qualified.len();
-    if len <= 1 {
-        return;
-    }
-    for _i in 0..len {
-        let max_j = len - 1;
-        for j in 0..max_j {
-            let j_next = j + 1;
-            let curr = qualified.get(j).unwrap();
-            let next = qualified.get(j_next).unwrap();
-            let swap = curr.1.success_rate < next.1.success_rate
-                || (curr.1.success_rate == next.1.success_rate
-                    && curr.1.total_signals < next.1.total_signals);
-            if swap {
-                qualified.set(j, next.clone());
-                qualified.set(j_next, curr);
-            }
+/// Whether `a` ranks strictly above `b` for `metric`.
+/// Success rate ties break by total_signals; followers ties by success rate.
+fn ranks_above(metric: LeaderboardMetric, a: &Candidate, b: &Candidate) -> bool {
+    match metric {
+        LeaderboardMetric::SuccessRate => {
+            a.1.success_rate > b.1.success_rate
+                || (a.1.success_rate == b.1.success_rate && a.1.total_signals > b.1.total_signals)
+        }
+        LeaderboardMetric::Volume => a.1.total_volume > b.1.total_volume,
+        LeaderboardMetric::Followers => {
+            a.2 > b.2 || (a.2 == b.2 && a.1.success_rate > b.1.success_rate)
+        }
+        LeaderboardMetric::RiskAdjustedReturn => {
+            a.3.risk_adjusted_return > b.3.risk_adjusted_return
         }
+        LeaderboardMetric::DrawdownAdjustedReturn => {
+            a.3.drawdown_adjusted_return > b.3.drawdown_adjusted_return
+        }
+        LeaderboardMetric::Reputation => a.4 > b.4,
     }
 }
 
-/// Sort qualified vec by total volume (desc)
-fn sort_by_volume(qualified: &mut Vec<(Address, ProviderPerformance)>) {
-    let len = qualified.len();
+/// Bubble sort candidates best first for `metric`
+fn sort_by_metric(candidates: &mut Vec<Candidate>, metric: LeaderboardMetric) {
+    let len = candidates.len();
     if len <= 1 {
         return;
     }
@@ -80,24 +153,23 @@ fn sort_by_volume(qualified: &mut Vec<(Address, ProviderPerformance)>) {
         let max_j = len - 1;
         for j in 0..max_j {
             let j_next = j + 1;
-            let curr = qualified.get(j).unwrap();
-            let next = qualified.get(j_next).unwrap();
-            if curr.1.total_volume < next.1.total_volume {
-                qualified.set(j, next.clone());
-                qualified.set(j_next, curr);
+            let curr = candidates.get(j).unwrap();
+            let next = candidates.get(j_next).unwrap();
+            if ranks_above(metric, &next, &curr) {
+                candidates.set(j, next);
+                candidates.set(j_next, curr);
             }
         }
     }
 }
 
 /// Assign ranks (with tie handling: same rank, next rank skips) and build result.
-/// For success rate: ties when success_rate and total_signals match.
-/// For volume: ties when total_volume matches.
+/// Entries tie when neither ranks above the other.
 fn assign_ranks_and_build(
     env: &Env,
-    sorted: &Vec<(Address, ProviderPerformance)>,
+    sorted: &Vec<Candidate>,
     limit: u32,
-    by_success_rate: bool,
+    metric: LeaderboardMetric,
 ) -> Vec<ProviderLeaderboard> {
     let mut result = Vec::new(env);
     let take = limit.min(sorted.len());
@@ -105,27 +177,28 @@ fn assign_ranks_and_build(
     let mut rank: u32 = 1;
 
     for i in 0..take {
-        let (provider, stats) = sorted.get(i).unwrap();
+        let (provider, stats, followers, risk, reputation_score) = sorted.get(i).unwrap();
         let entry = ProviderLeaderboard {
             rank,
             provider: provider.clone(),
             success_rate: stats.success_rate,
             total_volume: stats.total_volume,
             total_signals: stats.total_signals,
+            followers,
+            risk_adjusted_return: risk.risk_adjusted_return,
+            drawdown_adjusted_return: risk.drawdown_adjusted_return,
+            max_drawdown: risk.max_drawdown,
+            reputation_score,
+            profile: profiles::get_profile_or_default(env, &provider),
         };
         result.push_back(entry);
 
         // Next rank: if next entry ties, keep same rank; else rank = i + 2
         let i_plus_1 = i + 1;
         if i_plus_1 < take {
-            let curr = &sorted.get(i).unwrap().1;
-            let next = &sorted.get(i_plus_1).unwrap().1;
-            let tied = if by_success_rate {
-                curr.success_rate == next.success_rate && curr.total_signals == next.total_signals
-            } else {
-                curr.total_volume == next.total_volume
-            };
-            if !tied {
+            let curr = sorted.get(i).unwrap();
+            let next = sorted.get(i_plus_1).unwrap();
+            if ranks_above(metric, &curr, &next) {
                 rank = i + 2;
             }
         }
@@ -134,31 +207,185 @@ fn assign_ranks_and_build(
     result
 }
 
-/// Get the leaderboard for a given metric.
+fn get_daily_stats(env: &Env, provider: &Address) -> Map<u64, ProviderPerformance> {
+    env.storage()
+        .persistent()
+        .get(&LeaderboardStorageKey::DailyStats(provider.clone()))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn get_risk_stats(env: &Env, provider: &Address) -> Option<ProviderRiskStats> {
+    env.storage()
+        .persistent()
+        .get(&LeaderboardStorageKey::RiskStats(provider.clone()))
+}
+
+/// The provider's most recent `limit` equity curve points, oldest first
+/// (0 = `DEFAULT_EQUITY_POINTS`, capped at `MAX_EQUITY_POINTS`)
+pub fn get_equity_curve(env: &Env, provider: &Address, limit: u32) -> Vec<EquityPoint> {
+    let limit = match limit {
+        0 => DEFAULT_EQUITY_POINTS,
+        n => n.min(MAX_EQUITY_POINTS),
+    };
+    let curve: Vec<EquityPoint> = env
+        .storage()
+        .persistent()
+        .get(&LeaderboardStorageKey::EquityCurve(provider.clone()))
+        .unwrap_or(Vec::new(env));
+    curve.slice(curve.len().saturating_sub(limit)..)
+}
+
+/// Current reputation score (0-10000) of a provider
+pub fn get_reputation_score(env: &Env, provider: &Address) -> u32 {
+    let reputation: ProviderReputation = env
+        .storage()
+        .persistent()
+        .get(&LeaderboardStorageKey::Reputation(provider.clone()))
+        .unwrap_or_default();
+    let now = env.ledger().timestamp();
+    let mut slash_ages = Vec::new(env);
+    for slash in stake::get_slash_history(env, provider).iter() {
+        slash_ages.push_back(now.saturating_sub(slash.timestamp));
+    }
+    performance::calculate_reputation_score(
+        &reputation,
+        stake::get_stake_amount(env, provider),
+        admin::get_min_stake(env),
+        &slash_ages,
+        now,
+    )
+}
+
+/// Add a settled signal to the provider's bucket for the day it took effect,
+/// its return series and its reputation. Holding time runs from
+/// `effective_at`. Buckets older than the longest window are dropped.
+pub fn record_outcome(
+    env: &Env,
+    provider: &Address,
+    old_status: &SignalStatus,
+    new_status: &SignalStatus,
+    roi: i128,
+    volume: i128,
+    effective_at: u64,
+) {
+    let now = env.ledger().timestamp();
+    let today = now / DAY_SECONDS;
+    let day = effective_at / DAY_SECONDS;
+    let mut daily = get_daily_stats(env, provider);
+    let mut bucket = daily.get(day).unwrap_or_default();
+    let counted = bucket.total_signals;
+    performance::update_provider_performance(&mut bucket, old_status, new_status, roi, volume);
+    if bucket.total_signals == counted {
+        return;
+    }
+    daily.set(day, bucket);
+
+    let oldest_kept = today.saturating_sub(MAX_WINDOW_DAYS - 1);
+    for day in daily.keys() {
+        if day < oldest_kept {
+            daily.remove(day);
+        }
+    }
+
+    let key = LeaderboardStorageKey::DailyStats(provider.clone());
+    env.storage().persistent().set(&key, &daily);
+    bump_persistent(env, &key, TtlClass::History);
+
+    let mut risk = get_risk_stats(env, provider).unwrap_or_default();
+    performance::update_risk_stats(&mut risk, roi, now.saturating_sub(effective_at));
+    let key = LeaderboardStorageKey::RiskStats(provider.clone());
+    env.storage().persistent().set(&key, &risk);
+    bump_persistent(env, &key, TtlClass::History);
+
+    let key = LeaderboardStorageKey::EquityCurve(provider.clone());
+    let mut curve: Vec<EquityPoint> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    let equity = match curve.last() {
+        Some(point) => point.equity,
+        None => performance::EQUITY_START_VALUE,
+    };
+    curve.push_back(EquityPoint {
+        resolved_at: env.ledger().timestamp(),
+        roi,
+        equity: performance::compound_equity(equity, roi),
+    });
+    if curve.len() > MAX_EQUITY_POINTS {
+        curve.pop_front();
+    }
+    env.storage().persistent().set(&key, &curve);
+    bump_persistent(env, &key, TtlClass::History);
+
+    record_reputation(env, provider, *new_status == SignalStatus::Successful);
+}
+
+/// Count a forfeited commit against the provider's reputation. A forfeit has
+/// no return, so buckets, risk stats and the equity curve are left alone.
+pub fn record_forfeit(env: &Env, provider: &Address) {
+    record_reputation(env, provider, false);
+}
+
+fn record_reputation(env: &Env, provider: &Address, successful: bool) {
+    let key = LeaderboardStorageKey::Reputation(provider.clone());
+    let mut reputation: ProviderReputation =
+        env.storage().persistent().get(&key).unwrap_or_default();
+    performance::update_reputation(&mut reputation, successful, env.ledger().timestamp());
+    env.storage().persistent().set(&key, &reputation);
+    bump_persistent(env, &key, TtlClass::History);
+}
+
+/// Provider performance over resolved signals that took effect in the last
+/// `days` days (today included)
+pub fn get_windowed_stats(env: &Env, provider: &Address, days: u64) -> ProviderPerformance {
+    let today = env.ledger().timestamp() / DAY_SECONDS;
+    let oldest = today.saturating_sub(days.saturating_sub(1));
+
+    let mut stats = ProviderPerformance::default();
+    let mut roi_sum: i128 = 0;
+    for (day, bucket) in get_daily_stats(env, provider).iter() {
+        if day < oldest {
+            continue;
+        }
+        stats.total_signals += bucket.total_signals;
+        stats.successful_signals += bucket.successful_signals;
+        stats.failed_signals += bucket.failed_signals;
+        stats.total_volume += bucket.total_volume;
+        roi_sum += bucket.avg_return * bucket.total_signals as i128;
+    }
+    stats.success_rate = (stats.successful_signals * 10_000)
+        .checked_div(stats.total_signals)
+        .unwrap_or(0);
+    stats.avg_return = roi_sum
+        .checked_div(stats.total_signals as i128)
+        .unwrap_or(0);
+    stats
+}
+
+/// Get the leaderboard for a given metric and window.
 ///
 /// # Arguments
 /// * `env` - Contract environment
-/// * `stats_map` - Map of provider address to performance stats
-/// * `metric` - Ranking metric (SuccessRate, Volume, or Followers)
+/// * `stats_map` - Map of provider address to all-time performance stats
+/// * `metric` - Ranking metric
+/// * `window` - Only count signals resolved in the last 7 or 30 days, or all
 /// * `limit` - Max providers to return (1-50, default 10 if 0)
 ///
 /// # Returns
-/// Top N qualified providers. Followers returns empty for MVP.
+/// Top N qualified providers.
 ///
 /// # Minimum qualification
-/// - >= 5 signals submitted (terminal state)
-/// - success_rate > 0 (exclude all-failed providers)
+/// - SuccessRate, Volume and risk-adjusted metrics: >= 5 signals resolved in
+///   the window and success_rate > 0 (exclude all-failed providers)
+/// - Followers: at least one follower and one signal resolved in the window
 pub fn get_leaderboard(
     env: &Env,
     stats_map: &Map<Address, ProviderPerformance>,
     metric: LeaderboardMetric,
+    window: LeaderboardWindow,
     limit: u32,
 ) -> Vec<ProviderLeaderboard> {
-    // Followers: return empty for MVP
-    if metric == LeaderboardMetric::Followers {
-        return Vec::new(env);
-    }
-
     // Clamp limit: default 10, max 50
     let limit = if limit == 0 {
         DEFAULT_LEADERBOARD_LIMIT
@@ -169,25 +396,24 @@ pub fn get_leaderboard(
     };
 
     // Collect qualified providers (snapshot for consistency)
-    let mut qualified: Vec<(Address, ProviderPerformance)> = Vec::new(env);
-    for key in stats_map.keys() {
-        if let Some(stats) = stats_map.get(key.clone()) {
-            if is_qualified(&stats) {
-                qualified.push_back((key, stats));
-            }
+    let mut qualified: Vec<Candidate> = Vec::new(env);
+    for (provider, all_time) in stats_map.iter() {
+        let stats = match window.days() {
+            Some(days) => get_windowed_stats(env, &provider, days),
+            None => all_time,
+        };
+        let followers = social::get_follower_count(env, &provider);
+        let eligible = match metric {
+            LeaderboardMetric::Followers => followers > 0 && stats.total_signals > 0,
+            _ => is_qualified(&stats),
+        };
+        if eligible {
+            let risk = get_risk_stats(env, &provider).unwrap_or_default();
+            let reputation_score = get_reputation_score(env, &provider);
+            qualified.push_back((provider, stats, followers, risk, reputation_score));
         }
     }
 
-    // Sort and build by metric
-    match metric {
-        LeaderboardMetric::SuccessRate => {
-            sort_by_success_rate(&mut qualified);
-            assign_ranks_and_build(env, &qualified, limit, true)
-        }
-        LeaderboardMetric::Volume => {
-            sort_by_volume(&mut qualified);
-            assign_ranks_and_build(env, &qualified, limit, false)
-        }
-        LeaderboardMetric::Followers => Vec::new(env), // Already handled above
-    }
+    sort_by_metric(&mut qualified, metric);
+    assign_ranks_and_build(env, &qualified, limit, metric)
 }
@@ -1,45 +1,90 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
 mod admin;
 mod analytics;
- feature/signal-categorization-tagging
+mod breakdown;
 mod categories;
-=======
- main
 mod collaboration;
+mod commit_reveal;
+mod conditions;
 mod errors;
 #[allow(deprecated)]
 mod events;
 #[allow(dead_code)]
 mod expiry;
+mod feed;
 #[allow(dead_code)]
 mod fees;
+mod global_stats;
 mod import;
 mod leaderboard;
+mod lifecycle;
+mod multisig;
 mod performance;
+mod preferences;
+mod profiles;
 mod query;
+mod resolution;
 mod social;
 mod stake;
+mod storage;
+mod strategy;
 mod submission;
+mod subscriptions;
+mod swipes;
+mod targets;
 pub mod templates;
+#[allow(clippy::enum_variant_names)]
 mod types;
+mod upgrade;
 
-use admin::
+use admin::{
     get_admin, get_admin_config, get_pause_info, init_admin, is_trading_paused, require_not_paused,
     AdminConfig, PauseInfo,
 };
+pub use analytics::TrendEntry;
+pub use breakdown::{
+    CategoryPerformance, PerformanceBreakdown, RiskLevelPerformance, SegmentPerformance,
+    TagPerformance,
+};
 use categories::{RiskLevel, SignalCategory};
-use errors::{AdminError, TemplateError};
-pub use leaderboard::{get_leaderboard, LeaderboardMetric, ProviderLeaderboard};
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, Env, Map, String, Vec};
-use stellar_swipe_common::{validate_asset_pair as validate_asset_pair_common, AssetPairError};
+pub use commit_reveal::{CommitStatus, SignalCommit, SignalReveal};
+pub use conditions::{SignalCondition, TriggerDirection};
+use errors::{
+    AdminError, ProfileError, ResolutionError, SubscriptionError, SwipeError, TemplateError,
+};
+pub use feed::{FeedCursor, FeedPage};
+pub use global_stats::DailyGlobalStats;
+pub use leaderboard::{get_leaderboard, LeaderboardMetric, LeaderboardWindow, ProviderLeaderboard};
+pub use lifecycle::{SignalAmendment, SignalRevision};
+pub use multisig::{AdminAction, Proposal, ProposalStatus};
+pub use performance::{EquityPoint, ProviderRiskStats};
+pub use preferences::UserPreferences;
+pub use profiles::{ProviderBadge, ProviderProfile};
+pub use resolution::{OutcomeReason, SignalOutcome};
+pub use social::FollowerSnapshot;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, Address, Bytes, BytesN, Env, Map, String, Vec,
+};
+pub use stake::SlashRecord;
+use stellar_swipe_common::{
+    validate_asset_pair as validate_asset_pair_common, AssetPairError, MigrationStatus,
+};
+pub use storage::{SignalIndex, TtlKey};
+pub use strategy::{StrategyLeg, StrategySignal};
+pub use subscriptions::{Subscription, SubscriptionPlan};
+pub use swipes::{SwipeCounts, SwipeDirection};
+pub use targets::SignalTargets;
 use templates::{SignalTemplate, DEFAULT_TEMPLATE_EXPIRY_HOURS};
 use types::{
-    Asset, FeeBreakdown, ImportResultView, ProviderPerformance, Signal, SignalAction,
-    SignalPerformanceView, SignalStatus, SignalSummary, SortOption, TradeExecution,
+    Asset, FeeBreakdown, ImportResultView, ProviderPerformance, ProviderPerformanceView, Signal,
+    SignalAction, SignalPerformanceView, SignalStatus, SignalSummary, SortOption, TradeExecution,
 };
 
 const MAX_EXPIRY_SECONDS: u64 = 30 * 24 * 60 * 60;
+const DUPLICATE_WINDOW_SECONDS: u64 = 60 * 60; // 1 hour
+const DUPLICATE_WINDOW_LEDGERS: u32 = 720; // ~1 hour at 5s per ledger
 
 #[contract]
 pub struct SignalRegistry;
@@ -48,12 +93,15 @@ pub struct SignalRegistry;
 #[derive(Clone)]
 pub enum StorageKey {
     SignalCounter,
+    /// Legacy v1 layout: Map<u64, Signal> of every signal, drained by `migrate`
     Signals,
     ProviderStats,
     TradeExecutions,
     TemplateCounter,
     Templates,
     ExternalIdMappings,
+    /// (provider, asset_pair, action, price) -> timestamp of the latest matching signal
+    RecentSignal(Address, String, SignalAction, i128),
 }
 
 #[contractimpl]
@@ -64,7 +112,29 @@ impl SignalRegistry {
 
     /// Initialize contract with admin
     pub fn initialize(env: Env, admin: Address) -> Result<(), AdminError> {
-        init_admin(&env, admin)
+        init_admin(&env, admin)?;
+        stellar_swipe_common::set_schema_version(&env, upgrade::SCHEMA_VERSION);
+        Ok(())
+    }
+
+    /* =========================
+       UPGRADE & MIGRATION
+    ========================== */
+
+    /// Replace the contract Wasm. Admin only, or through a multisig proposal.
+    pub fn upgrade(env: Env, caller: Address, wasm_hash: BytesN<32>) -> Result<(), AdminError> {
+        upgrade::upgrade(&env, &caller, wasm_hash)
+    }
+
+    /// Schema version of the data in storage
+    pub fn get_schema_version(env: Env) -> u32 {
+        upgrade::get_schema_version(&env)
+    }
+
+    /// Rewrite up to `batch` old-layout records into the current layout.
+    /// Permissionless and resumable; call until `remaining` is 0.
+    pub fn migrate(env: Env, batch: u32) -> Result<MigrationStatus, AdminError> {
+        upgrade::migrate(&env, batch)
     }
 
     /* =========================
@@ -158,6 +228,40 @@ impl SignalRegistry {
         admin::remove_multisig_signer(&env, &caller, signer_to_remove)
     }
 
+    // Multi-sig proposals
+
+    /// Propose an admin action (signers only). Returns the proposal id.
+    pub fn propose_admin_action(
+        env: Env,
+        proposer: Address,
+        action: AdminAction,
+    ) -> Result<u64, AdminError> {
+        multisig::propose(&env, &proposer, action)
+    }
+
+    /// Approve a proposal; it executes once the threshold is met
+    pub fn approve_proposal(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<ProposalStatus, AdminError> {
+        multisig::approve(&env, &signer, proposal_id)
+    }
+
+    /// Execute a proposal whose approvals meet the current threshold
+    pub fn execute_proposal(env: Env, caller: Address, proposal_id: u64) -> Result<(), AdminError> {
+        multisig::execute(&env, &caller, proposal_id)
+    }
+
+    /// Cancel a pending proposal (proposer or admin)
+    pub fn cancel_proposal(env: Env, caller: Address, proposal_id: u64) -> Result<(), AdminError> {
+        multisig::cancel(&env, &caller, proposal_id)
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Option<Proposal> {
+        multisig::get_proposal(&env, proposal_id)
+    }
+
     /* =========================
        INTERNAL HELPERS
     ========================== */
@@ -178,17 +282,6 @@ impl SignalRegistry {
         counter
     }
 
-    fn get_signals_map(env: &Env) -> Map<u64, Signal> {
-        env.storage()
-            .instance()
-            .get(&StorageKey::Signals)
-            .unwrap_or(Map::new(env))
-    }
-
-    fn save_signals_map(env: &Env, map: &Map<u64, Signal>) {
-        env.storage().instance().set(&StorageKey::Signals, map);
-    }
-
     fn get_provider_stats_map(env: &Env) -> Map<Address, ProviderPerformance> {
         env.storage()
             .instance()
@@ -202,6 +295,152 @@ impl SignalRegistry {
             .set(&StorageKey::ProviderStats, map);
     }
 
+    /// Update provider stats and emit events after a signal's status changed
+    fn apply_status_change(env: &Env, signal: &Signal, old_status: SignalStatus, signal_roi: i128) {
+        let new_status = signal.status.clone();
+        if !performance::should_update_provider_stats(&old_status, &new_status) {
+            return;
+        }
+
+        // Emit status change event
+        events::emit_signal_status_changed(
+            env,
+            signal.id,
+            signal.provider.clone(),
+            old_status.clone() as u32,
+            new_status.clone() as u32,
+        );
+
+        let counted = Self::record_provider_outcome(
+            env,
+            &signal.provider,
+            &old_status,
+            &new_status,
+            signal_roi,
+            signal.total_volume,
+            commit_reveal::effective_timestamp(env, signal),
+        );
+        if counted {
+            breakdown::record_resolution(env, signal, signal_roi);
+            analytics::record_resolution(
+                env,
+                &signal.provider,
+                new_status == SignalStatus::Successful,
+            );
+        }
+    }
+
+    /// Add a settled call that took effect at `effective_at` to the
+    /// provider's stats and emit the update.
+    /// Returns whether the call was counted as newly resolved.
+    fn record_provider_outcome(
+        env: &Env,
+        provider: &Address,
+        old_status: &SignalStatus,
+        new_status: &SignalStatus,
+        roi: i128,
+        volume: i128,
+        effective_at: u64,
+    ) -> bool {
+        let mut provider_stats_map = Self::get_provider_stats_map(env);
+        let mut provider_stats = provider_stats_map.get(provider.clone()).unwrap_or_default();
+        let counted = provider_stats.total_signals;
+
+        performance::update_provider_performance(
+            &mut provider_stats,
+            old_status,
+            new_status,
+            roi,
+            volume,
+        );
+        leaderboard::record_outcome(
+            env,
+            provider,
+            old_status,
+            new_status,
+            roi,
+            volume,
+            effective_at,
+        );
+        let newly_resolved = provider_stats.total_signals != counted;
+        if newly_resolved {
+            global_stats::record_resolution(env, *new_status == SignalStatus::Successful);
+        }
+
+        provider_stats_map.set(provider.clone(), provider_stats.clone());
+        Self::save_provider_stats_map(env, &provider_stats_map);
+
+        // Emit provider stats updated event
+        events::emit_provider_stats_updated(
+            env,
+            provider.clone(),
+            provider_stats.success_rate,
+            provider_stats.avg_return,
+            provider_stats.total_volume,
+        );
+        newly_resolved
+    }
+
+    /// Count a forfeited commit as a failed signal in the provider's success
+    /// rate and reputation. Nothing was traded, so returns, daily buckets and
+    /// platform totals are left alone.
+    fn record_forfeited_commit(env: &Env, provider: &Address) {
+        let mut provider_stats_map = Self::get_provider_stats_map(env);
+        let mut provider_stats = provider_stats_map.get(provider.clone()).unwrap_or_default();
+        performance::update_provider_performance(
+            &mut provider_stats,
+            &SignalStatus::Active,
+            &SignalStatus::Failed,
+            0,
+            0,
+        );
+        provider_stats_map.set(provider.clone(), provider_stats.clone());
+        Self::save_provider_stats_map(env, &provider_stats_map);
+        leaderboard::record_forfeit(env, provider);
+
+        events::emit_provider_stats_updated(
+            env,
+            provider.clone(),
+            provider_stats.success_rate,
+            provider_stats.avg_return,
+            provider_stats.total_volume,
+        );
+    }
+
+    fn is_duplicate_signal(
+        env: &Env,
+        provider: &Address,
+        asset_pair: &String,
+        action: &SignalAction,
+        price: i128,
+        now: u64,
+    ) -> bool {
+        let key =
+            StorageKey::RecentSignal(provider.clone(), asset_pair.clone(), action.clone(), price);
+        match env.storage().temporary().get::<_, u64>(&key) {
+            Some(last_timestamp) => now < last_timestamp.saturating_add(DUPLICATE_WINDOW_SECONDS),
+            None => false,
+        }
+    }
+
+    fn record_recent_signal(
+        env: &Env,
+        provider: &Address,
+        asset_pair: &String,
+        action: &SignalAction,
+        price: i128,
+        now: u64,
+    ) {
+        let key =
+            StorageKey::RecentSignal(provider.clone(), asset_pair.clone(), action.clone(), price);
+        env.storage().temporary().set(&key, &now);
+        env.storage().temporary().extend_ttl(
+            &key,
+            DUPLICATE_WINDOW_LEDGERS,
+            DUPLICATE_WINDOW_LEDGERS,
+        );
+    }
+
     fn validate_asset_pair(env: &Env, asset_pair: &String) -> Result<(), AdminError> {
         validate_asset_pair_common(env, asset_pair).map_err(|e| match e {
             AssetPairError::InvalidFormat
@@ -228,7 +467,92 @@ impl SignalRegistry {
         risk_level: RiskLevel,
     ) -> Result<u64, AdminError> {
         provider.require_auth();
-        Self::create_signal_internal(&env, provider, asset_pair, action, price, rationale, expiry, category, tags, risk_level)
+        Self::create_signal_internal(
+            &env, provider, asset_pair, action, price, rationale, expiry, category, tags,
+            risk_level,
+        )
+    }
+
+    /// Create a signal with take-profit and/or stop-loss targets.
+    /// Targets must be on the profitable/losing side of `price` for the action.
+    pub fn create_signal_with_targets(
+        env: Env,
+        provider: Address,
+        asset_pair: String,
+        action: SignalAction,
+        price: i128,
+        rationale: String,
+        expiry: u64,
+        category: SignalCategory,
+        tags: Vec<String>,
+        risk_level: RiskLevel,
+        targets: SignalTargets,
+    ) -> Result<u64, AdminError> {
+        provider.require_auth();
+        targets::validate_targets(&action, price, &targets)?;
+
+        let id = Self::create_signal_internal(
+            &env, provider, asset_pair, action, price, rationale, expiry, category, tags,
+            risk_level,
+        )?;
+        targets::set_targets(&env, id, &targets);
+        Ok(id)
+    }
+
+    pub fn get_signal_targets(env: Env, signal_id: u64) -> Option<SignalTargets> {
+        if subscriptions::is_subscriber_only(&env, signal_id) {
+            return None;
+        }
+        targets::get_targets(&env, signal_id)
+    }
+
+    /// Create a signal that stays Pending until its condition is met: an
+    /// oracle price trigger and/or a `valid_from` time.
+    /// Price triggers require an oracle price for the pair that has not
+    /// reached the trigger yet.
+    pub fn create_conditional_signal(
+        env: Env,
+        provider: Address,
+        asset_pair: String,
+        action: SignalAction,
+        price: i128,
+        rationale: String,
+        expiry: u64,
+        category: SignalCategory,
+        tags: Vec<String>,
+        risk_level: RiskLevel,
+        condition: SignalCondition,
+    ) -> Result<u64, AdminError> {
+        provider.require_auth();
+        conditions::validate_condition(&env, &condition, &asset_pair, expiry)?;
+
+        let id = Self::create_signal_internal(
+            &env, provider, asset_pair, action, price, rationale, expiry, category, tags,
+            risk_level,
+        )?;
+        if let Some(mut signal) = storage::get_signal(&env, id) {
+            signal.status = SignalStatus::Pending;
+            storage::save_signal(&env, &signal);
+        }
+        conditions::set_condition(&env, id, &condition);
+        Ok(id)
+    }
+
+    /// Condition of a signal still waiting for activation, or of one that
+    /// expired untriggered. None for subscriber-only signals, whose trigger
+    /// price is not public either.
+    pub fn get_signal_condition(env: Env, signal_id: u64) -> Option<SignalCondition> {
+        if subscriptions::is_subscriber_only(&env, signal_id) {
+            return None;
+        }
+        conditions::get_condition(&env, signal_id)
+    }
+
+    /// Activate pending conditional signals whose condition is met. Keeper
+    /// entrypoint, permissionless. Checks up to `limit` pending signals from
+    /// `offset` and returns the number activated.
+    pub fn activate_conditional_signals(env: Env, offset: u32, limit: u32) -> u32 {
+        conditions::activate_signals(&env, offset, limit)
     }
 
     fn create_signal_internal(
@@ -247,22 +571,29 @@ impl SignalRegistry {
         require_not_paused(env)?;
 
         Self::validate_asset_pair(env, &asset_pair)?;
-        
+
         // Validate and deduplicate tags
         categories::validate_tags(&tags)?;
         let unique_tags = categories::deduplicate_tags(env, tags);
 
+        let rationale_len = rationale.len();
+        if rationale_len == 0 || rationale_len > templates::MAX_SIGNAL_RATIONALE_BYTES {
+            return Err(AdminError::InvalidRationale);
+        }
+
         let now = env.ledger().timestamp();
 
-        if expiry <= now {
-            panic!("expiry must be in the future");
+        if expiry <= now || expiry > now + MAX_EXPIRY_SECONDS {
+            return Err(AdminError::InvalidExpiry);
         }
 
-        if expiry > now + MAX_EXPIRY_SECONDS {
-            panic!("expiry exceeds max 30 days");
+        // Reject the same call (provider, pair, action, price) within the duplicate window
+        if Self::is_duplicate_signal(env, &provider, &asset_pair, &action, price, now) {
+            return Err(AdminError::DuplicateSignal);
         }
 
         let id = Self::next_signal_id(env);
+        Self::record_recent_signal(env, &provider, &asset_pair, &action, price, now);
 
         let signal = Signal {
             id,
@@ -281,19 +612,18 @@ impl SignalRegistry {
             total_roi: 0,
             // Categorization fields
             category,
-            tags: unique_tags.clone(),
+            tags: unique_tags,
             risk_level,
             // Collaboration field
             is_collaborative: false,
         };
 
-        // Store signal
-        let mut signals = Self::get_signals_map(env);
-        signals.set(id, signal);
-        Self::save_signals_map(env, &signals);
-        
-        // Update tag popularity
-        categories::increment_tag_popularity(env, &unique_tags);
+        // Store signal. Only new signals count towards the daily aggregates,
+        // not records written back by migrations.
+        storage::save_signal(env, &signal);
+        global_stats::record_signal_created(env, &signal.provider);
+        stake::lock_for_signal(env, &signal.provider);
+        stellar_swipe_common::bump_instance(env);
 
         // Initialize provider stats on first submission
         let mut stats = Self::get_provider_stats_map(env);
@@ -305,9 +635,10 @@ impl SignalRegistry {
         Ok(id)
     }
 
+    /// Public signal details. Subscriber-only signals are not returned here;
+    /// see `get_signal_for_subscriber`.
     pub fn get_signal(env: Env, signal_id: u64) -> Option<Signal> {
-        let signals = Self::get_signals_map(&env);
-        signals.get(signal_id)
+        storage::get_signal(&env, signal_id).filter(|s| subscriptions::is_public(&env, s))
     }
 
     pub fn get_provider_stats(env: Env, provider: Address) -> Option<ProviderPerformance> {
@@ -324,7 +655,7 @@ impl SignalRegistry {
     ) -> Result<u64, TemplateError> {
         provider.require_auth();
 
-        if name.len() == 0 || rationale_template.len() == 0 {
+        if name.is_empty() || rationale_template.is_empty() {
             return Err(TemplateError::InvalidTemplate);
         }
 
@@ -409,27 +740,15 @@ impl SignalRegistry {
         if expiry > env.ledger().timestamp() + MAX_EXPIRY_SECONDS {
             return Err(TemplateError::InvalidExpiry);
         }
-        
+
         // Default category, tags, and risk_level for templates
         let category = SignalCategory::SwingTrade;
         let tags = Vec::new(&env);
         let risk_level = RiskLevel::Medium;
 
         let signal_id = Self::create_signal_internal(
- feature/signal-categorization-tagging
-            &env,
-            submitter,
-            asset_pair,
-            action,
-            price,
-            rationale,
-            expiry,
-            category,
-            tags,
+            &env, submitter, asset_pair, action, price, rationale, expiry, category, tags,
             risk_level,
-=======
-            &env, submitter, asset_pair, action, price, rationale, expiry,
- main
         )
         .map_err(|_| TemplateError::InvalidTemplate)?;
 
@@ -462,10 +781,19 @@ impl SignalRegistry {
         }
 
         // Load signal
-        let mut signals = Self::get_signals_map(&env);
-        let mut signal = signals
-            .get(signal_id)
-            .ok_or(errors::PerformanceError::SignalNotFound)?;
+        let mut signal =
+            storage::get_signal(&env, signal_id).ok_or(errors::PerformanceError::SignalNotFound)?;
+        if signal.status == SignalStatus::Cancelled {
+            return Err(errors::PerformanceError::SignalCancelled);
+        }
+        // Conditional signals cannot be copied before they activate
+        if conditions::is_waiting(&env, signal_id) {
+            return Err(errors::PerformanceError::SignalNotActive);
+        }
+        // Hold calls carry no trade to copy
+        if signal.action == SignalAction::Hold {
+            return Err(errors::PerformanceError::NeutralSignal);
+        }
 
         // Calculate ROI
         let roi = performance::calculate_roi(entry_price, exit_price, &signal.action);
@@ -487,64 +815,219 @@ impl SignalRegistry {
         // Update signal stats
         performance::update_signal_stats(&mut signal, &trade);
 
-        // Evaluate new status
+        // Evaluate new status. With a price oracle configured, outcomes are
+        // settled by `resolve_signal` and self-reported trades never change it.
         let now = env.ledger().timestamp();
-        let new_status = performance::evaluate_signal_status(&signal, now);
-        signal.status = new_status.clone();
+        if !resolution::is_oracle_mode(&env) {
+            signal.status = performance::evaluate_signal_status(&signal, now);
+        }
 
         // Save updated signal
-        signals.set(signal_id, signal.clone());
-        Self::save_signals_map(&env, &signals);
+        storage::save_signal(&env, &signal);
 
         // Emit trade executed event
         events::emit_trade_executed(&env, signal_id, executor.clone(), roi, volume);
+        global_stats::record_execution(&env, volume);
 
         // Check if status changed and update provider stats
-        if performance::should_update_provider_stats(&old_status, &new_status) {
-            let mut provider_stats_map = Self::get_provider_stats_map(&env);
-            let mut provider_stats = provider_stats_map
-                .get(signal.provider.clone())
-                .unwrap_or_default();
-
-            let signal_avg_roi = performance::get_signal_average_roi(&signal);
-
-            performance::update_provider_performance(
-                &mut provider_stats,
-                &old_status,
-                &new_status,
-                signal_avg_roi,
-                signal.total_volume,
-            );
+        let signal_avg_roi = performance::get_signal_average_roi(&signal);
+        Self::apply_status_change(&env, &signal, old_status, signal_avg_roi);
 
-            provider_stats_map.set(signal.provider.clone(), provider_stats.clone());
-            Self::save_provider_stats_map(&env, &provider_stats_map);
+        Ok(())
+    }
 
-            // Emit status change event
-            events::emit_signal_status_changed(
-                &env,
-                signal_id,
-                signal.provider.clone(),
-                old_status as u32,
-                new_status as u32,
-            );
+    /// Settle an expired signal against the price oracle.
+    /// Permissionless; each signal can be resolved once.
+    pub fn resolve_signal(env: Env, signal_id: u64) -> Result<SignalOutcome, ResolutionError> {
+        let (outcome, old_status, signal) = resolution::resolve_signal(&env, signal_id)?;
+        Self::apply_status_change(&env, &signal, old_status, outcome.roi);
+        Ok(outcome)
+    }
 
-            // Emit provider stats updated event
-            events::emit_provider_stats_updated(
-                &env,
-                signal.provider,
-                provider_stats.success_rate,
-                provider_stats.avg_return,
-                provider_stats.total_volume,
-            );
+    /* =========================
+       STRATEGY SIGNAL FUNCTIONS
+    ========================== */
+
+    /// Publish a multi-leg strategy (pair trade or weighted basket) under a
+    /// single id. Leg weights are in basis points and must sum to 10000.
+    pub fn create_strategy_signal(
+        env: Env,
+        provider: Address,
+        rationale: String,
+        legs: Vec<StrategyLeg>,
+        expiry: u64,
+    ) -> Result<u64, AdminError> {
+        provider.require_auth();
+        require_not_paused(&env)?;
+        strategy::create_strategy(&env, &provider, rationale, legs, expiry)
+    }
+
+    pub fn get_strategy_signal(env: Env, strategy_id: u64) -> Option<StrategySignal> {
+        strategy::get_strategy(&env, strategy_id)
+    }
+
+    /// Settle an expired strategy against oracle prices for all legs.
+    /// Permissionless; each strategy can be resolved once.
+    pub fn resolve_strategy(env: Env, strategy_id: u64) -> Result<StrategySignal, ResolutionError> {
+        let (strategy, old_status) = strategy::resolve_strategy(&env, strategy_id)?;
+        Self::record_provider_outcome(
+            &env,
+            &strategy.provider,
+            &old_status,
+            &strategy.status,
+            strategy.roi.unwrap_or(0),
+            0,
+            strategy.timestamp,
+        );
+        Ok(strategy)
+    }
+
+    /// Get the oracle-verified outcome of a resolved signal. None for
+    /// subscriber-only signals, whose entry price is not public.
+    pub fn get_signal_outcome(env: Env, signal_id: u64) -> Option<SignalOutcome> {
+        if subscriptions::is_subscriber_only(&env, signal_id) {
+            return None;
         }
+        resolution::get_outcome(&env, signal_id)
+    }
 
+    /// Set the price oracle used to resolve signals. Admin only.
+    pub fn set_price_oracle(env: Env, caller: Address, oracle: Address) -> Result<(), AdminError> {
+        admin::require_direct_admin(&env, &caller)?;
+        caller.require_auth();
+        resolution::store_price_oracle(&env, oracle);
         Ok(())
     }
 
+    pub fn get_price_oracle(env: Env) -> Option<Address> {
+        resolution::get_price_oracle(&env)
+    }
+
+    /* =========================
+       COMMIT-REVEAL FUNCTIONS
+    ========================== */
+
+    /// Commit to a signal without publishing it. `commitment` is the hash
+    /// returned by `compute_signal_commitment` for the content to reveal.
+    /// A provider can hold one pending commit at a time.
+    pub fn commit_signal(
+        env: Env,
+        provider: Address,
+        commitment: BytesN<32>,
+    ) -> Result<u64, AdminError> {
+        provider.require_auth();
+        require_not_paused(&env)?;
+        commit_reveal::commit(&env, &provider, commitment)
+    }
+
+    /// Reveal a committed signal within the reveal window.
+    /// Returns the new signal id, or `None` if the content did not match the
+    /// commitment; the commit is then forfeited and counted as a failed signal.
+    pub fn reveal_signal(
+        env: Env,
+        provider: Address,
+        commit_id: u64,
+        reveal: SignalReveal,
+    ) -> Result<Option<u64>, AdminError> {
+        provider.require_auth();
+        let commit = commit_reveal::load_for_reveal(&env, &provider, commit_id)?;
+
+        if commit_reveal::commitment_hash(&env, &reveal) != commit.commitment {
+            commit_reveal::forfeit(&env, commit, CommitStatus::Mismatched);
+            Self::record_forfeited_commit(&env, &provider);
+            return Ok(None);
+        }
+
+        let signal_id = Self::create_signal_internal(
+            &env,
+            provider,
+            reveal.asset_pair,
+            reveal.action,
+            reveal.price,
+            reveal.rationale,
+            reveal.expiry,
+            reveal.category,
+            reveal.tags,
+            reveal.risk_level,
+        )?;
+        commit_reveal::mark_revealed(&env, commit, signal_id);
+        Ok(Some(signal_id))
+    }
+
+    /// Forfeit a commit that was not revealed in time. Permissionless.
+    pub fn expire_commit(env: Env, commit_id: u64) -> Result<(), AdminError> {
+        let commit = commit_reveal::load_lapsed(&env, commit_id)?;
+        let provider = commit.provider.clone();
+        commit_reveal::forfeit(&env, commit, CommitStatus::Lapsed);
+        Self::record_forfeited_commit(&env, &provider);
+        Ok(())
+    }
+
+    pub fn get_signal_commit(env: Env, commit_id: u64) -> Option<SignalCommit> {
+        commit_reveal::get_commit(&env, commit_id)
+    }
+
+    /// Commit a revealed signal came from; its `committed_at` is when the
+    /// call was made
+    pub fn get_commit_for_signal(env: Env, signal_id: u64) -> Option<SignalCommit> {
+        commit_reveal::get_signal_commit_id(&env, signal_id)
+            .and_then(|commit_id| commit_reveal::get_commit(&env, commit_id))
+    }
+
+    /// Commitment hash for a signal reveal
+    pub fn compute_signal_commitment(env: Env, reveal: SignalReveal) -> BytesN<32> {
+        commit_reveal::commitment_hash(&env, &reveal)
+    }
+
+    /* =========================
+       SIGNAL LIFECYCLE FUNCTIONS
+    ========================== */
+
+    /// Cancel a signal that has not been executed yet. Provider only.
+    pub fn cancel_signal(env: Env, provider: Address, signal_id: u64) -> Result<(), AdminError> {
+        provider.require_auth();
+        lifecycle::cancel_signal(&env, &provider, signal_id)
+    }
+
+    /// Close a live signal early. Provider only.
+    /// `exit_price` is used only when no price oracle is configured.
+    pub fn close_signal(
+        env: Env,
+        provider: Address,
+        signal_id: u64,
+        exit_price: i128,
+    ) -> Result<SignalOutcome, AdminError> {
+        provider.require_auth();
+        let (outcome, old_status, signal) =
+            lifecycle::close_signal(&env, &provider, signal_id, exit_price)?;
+        Self::apply_status_change(&env, &signal, old_status, outcome.roi);
+        Ok(outcome)
+    }
+
+    /// Amend rationale, price or expiry before any execution. Provider only.
+    /// Returns the revision number recorded for the replaced content.
+    pub fn amend_signal(
+        env: Env,
+        provider: Address,
+        signal_id: u64,
+        amendment: SignalAmendment,
+    ) -> Result<u32, AdminError> {
+        provider.require_auth();
+        lifecycle::amend_signal(&env, &provider, signal_id, amendment)
+    }
+
+    /// Previous versions of an amended signal, oldest first. Empty for
+    /// subscriber-only signals, whose earlier prices are not public either.
+    pub fn get_signal_revisions(env: Env, signal_id: u64) -> Vec<SignalRevision> {
+        if subscriptions::is_subscriber_only(&env, signal_id) {
+            return Vec::new(&env);
+        }
+        lifecycle::get_revisions(&env, signal_id)
+    }
+
     /// Get signal performance metrics
     pub fn get_signal_performance(env: Env, signal_id: u64) -> Option<SignalPerformanceView> {
-        let signals = Self::get_signals_map(&env);
-        let signal = signals.get(signal_id)?;
+        let signal = storage::get_signal(&env, signal_id)?;
 
         let average_roi = performance::get_signal_average_roi(&signal);
 
@@ -557,27 +1040,130 @@ impl SignalRegistry {
         })
     }
 
-    /// Get provider performance stats (alias for get_provider_stats)
-    pub fn get_provider_performance(env: Env, provider: Address) -> Option<ProviderPerformance> {
-        Self::get_provider_stats(env, provider)
+    /// Get provider performance stats together with the provider's current
+    /// time-decayed reputation score
+    pub fn get_provider_performance(
+        env: Env,
+        provider: Address,
+    ) -> Option<ProviderPerformanceView> {
+        let reputation_score = leaderboard::get_reputation_score(&env, &provider);
+        Self::get_provider_stats(env, provider).map(|stats| ProviderPerformanceView {
+            stats,
+            reputation_score,
+        })
+    }
+
+    /// Return series statistics of a provider's resolved signals: variance,
+    /// max drawdown, average holding time and risk-adjusted returns
+    pub fn get_provider_risk_stats(env: Env, provider: Address) -> Option<ProviderRiskStats> {
+        leaderboard::get_risk_stats(&env, &provider)
     }
 
-    /// Get leaderboard of top providers by metric
+    /// Cumulative return of following every resolved signal of a provider,
+    /// starting from 10000. Returns the most recent `limit` points, oldest
+    /// first (0 = default 50, max 100).
+    pub fn get_provider_equity_curve(env: Env, provider: Address, limit: u32) -> Vec<EquityPoint> {
+        leaderboard::get_equity_curve(&env, &provider, limit)
+    }
+
+    /// Set the token contract providers post stake in. Admin only.
+    pub fn set_stake_token(env: Env, caller: Address, token: Address) -> Result<(), AdminError> {
+        admin::require_direct_admin(&env, &caller)?;
+        caller.require_auth();
+        stake::set_stake_token(&env, token);
+        Ok(())
+    }
+
+    pub fn get_stake_token(env: Env) -> Option<Address> {
+        stake::get_stake_token(&env)
+    }
+
+    /// Post `amount` of the stake token as the provider's stake. The tokens
+    /// are held by the contract; stake and slashes feed into the provider's
+    /// reputation score. Top-ups stay in the token the stake was first
+    /// posted in.
+    pub fn add_provider_stake(env: Env, provider: Address, amount: i128) -> Result<(), AdminError> {
+        provider.require_auth();
+        stake::deposit(&env, &provider, amount)
+    }
+
+    /// Withdraw the provider's whole stake. Fails with `StakeLocked` until
+    /// the unstake lock period has passed since their last signal. Returns
+    /// the amount paid out.
+    pub fn withdraw_provider_stake(env: Env, provider: Address) -> Result<i128, AdminError> {
+        provider.require_auth();
+        stake::withdraw(&env, &provider)
+    }
+
+    /// Slash up to `amount` of a provider's stake (admin only) and move the
+    /// slashed tokens to the platform treasury. Returns the amount taken;
+    /// the slash lowers the provider's reputation.
+    pub fn slash_provider(
+        env: Env,
+        caller: Address,
+        provider: Address,
+        amount: i128,
+    ) -> Result<i128, AdminError> {
+        admin::require_direct_admin(&env, &caller)?;
+        caller.require_auth();
+        stake::apply_slash(&env, &provider, amount)
+    }
+
+    pub fn get_provider_stake(env: Env, provider: Address) -> i128 {
+        stake::get_stake_amount(&env, &provider)
+    }
+
+    pub fn get_slash_history(env: Env, provider: Address) -> Vec<SlashRecord> {
+        stake::get_slash_history(&env, &provider)
+    }
+
+    /// Success rate, average ROI and volume of a provider's resolved signals
+    /// per category, per risk level and for its most used tags
+    pub fn get_performance_breakdown(env: Env, provider: Address) -> PerformanceBreakdown {
+        breakdown::get_provider_breakdown(&env, &provider)
+    }
+
+    /// Platform-wide performance per category, best success rate first
+    pub fn get_category_performance(env: Env) -> Vec<CategoryPerformance> {
+        breakdown::get_category_performance(&env)
+    }
+
+    /// Platform-wide daily aggregates for days `from_day..=to_day` (days since
+    /// the Unix epoch), oldest first. At most 90 days are returned.
+    pub fn get_global_history(env: Env, from_day: u64, to_day: u64) -> Vec<DailyGlobalStats> {
+        global_stats::get_global_history(&env, from_day, to_day)
+    }
+
+    /// Get leaderboard of top providers by metric, over all time
     ///
     /// # Arguments
-    /// * `metric` - SuccessRate, Volume, or Followers (empty for MVP)
+    /// * `metric` - SuccessRate, Volume, or Followers
     /// * `limit` - Max providers to return (0 = default 10, max 50)
     ///
     /// # Minimum qualification
     /// - >= 5 signals with terminal status
     /// - success_rate > 0 (exclude all-failed)
+    /// - Followers: at least one follower and one resolved signal instead
     pub fn get_leaderboard(
         env: Env,
         metric: LeaderboardMetric,
         limit: u32,
     ) -> Vec<ProviderLeaderboard> {
         let stats_map = Self::get_provider_stats_map(&env);
-        get_leaderboard(&env, &stats_map, metric, limit)
+        get_leaderboard(&env, &stats_map, metric, LeaderboardWindow::AllTime, limit)
+    }
+
+    /// `get_leaderboard` ranked on resolved signals that took effect in the
+    /// last 7 or 30 days, or all time. The 5-signal minimum applies within
+    /// the window.
+    pub fn get_leaderboard_windowed(
+        env: Env,
+        metric: LeaderboardMetric,
+        window: LeaderboardWindow,
+        limit: u32,
+    ) -> Vec<ProviderLeaderboard> {
+        let stats_map = Self::get_provider_stats_map(&env);
+        get_leaderboard(&env, &stats_map, metric, window, limit)
     }
 
     /// Get top providers sorted by success rate
@@ -628,7 +1214,7 @@ impl SignalRegistry {
         caller: Address,
         treasury: Address,
     ) -> Result<(), AdminError> {
-        admin::require_admin(&env, &caller)?;
+        admin::require_direct_admin(&env, &caller)?;
         caller.require_auth();
         fees::set_platform_treasury(&env, treasury);
         Ok(())
@@ -657,7 +1243,9 @@ impl SignalRegistry {
        API: QUERY SIGNALS
     ========================== */
 
-    /// Get all active (non-expired) signals for feed, paginated and sorted.
+    /// Get active (non-expired) signals for feed, paginated and sorted.
+    /// `offset` and `limit` are positions in the provider index, or in the
+    /// open status indexes without a provider; each page is sorted on its own.
     pub fn get_active_signals(
         env: Env,
         offset: u32,
@@ -665,8 +1253,82 @@ impl SignalRegistry {
         sort_by: SortOption,
         provider: Option<Address>,
     ) -> Vec<SignalSummary> {
-        let signals_map = Self::get_signals_map(&env);
-        query::get_active_signals(&env, &signals_map, provider, offset, limit, sort_by)
+        query::get_active_signals(&env, provider, offset, limit, sort_by)
+    }
+
+    /// Swipe right (interested) or left (pass) on an open signal. One
+    /// decision per user and signal.
+    pub fn swipe(
+        env: Env,
+        user: Address,
+        signal_id: u64,
+        direction: SwipeDirection,
+    ) -> Result<SwipeCounts, SwipeError> {
+        user.require_auth();
+        swipes::swipe(&env, &user, signal_id, direction)
+    }
+
+    pub fn get_swipe(env: Env, user: Address, signal_id: u64) -> Option<SwipeDirection> {
+        swipes::get_swipe(&env, &user, signal_id)
+    }
+
+    pub fn get_swipe_counts(env: Env, signal_id: u64) -> SwipeCounts {
+        swipes::get_counts(&env, signal_id)
+    }
+
+    /// Personalized feed for the user's swipe deck: unswiped active signals
+    /// ranked by followed providers, preferences, provider success rate and
+    /// freshness. Start from `FeedCursor::Start` and pass the returned
+    /// `next_cursor` to get the next page.
+    pub fn get_feed(env: Env, user: Address, cursor: FeedCursor, limit: u32) -> FeedPage {
+        let stats = Self::get_provider_stats_map(&env);
+        feed::get_feed(&env, &user, &stats, cursor, limit)
+    }
+
+    pub fn set_user_preferences(
+        env: Env,
+        user: Address,
+        preferences: UserPreferences,
+    ) -> Result<(), AdminError> {
+        user.require_auth();
+        preferences::set_preferences(&env, &user, &preferences)
+    }
+
+    pub fn get_user_preferences(env: Env, user: Address) -> Option<UserPreferences> {
+        preferences::get_preferences(&env, &user)
+    }
+
+    /// Whether a signal is a Hold/neutral call, which has no trade to copy.
+    /// auto_trade checks this before copying the signal.
+    pub fn is_neutral_signal(env: Env, signal_id: u64) -> bool {
+        storage::get_signal(&env, signal_id).is_some_and(|s| s.action == SignalAction::Hold)
+    }
+
+    /// Whether an auto-copy for `user` should follow the signal under their
+    /// preferences: passes the filters and matches preferred categories and
+    /// watched pairs. True when the user has no preferences. auto_trade
+    /// checks this before copying the signal.
+    pub fn signal_matches_preferences(env: Env, user: Address, signal_id: u64) -> bool {
+        let Some(signal) = storage::get_signal(&env, signal_id) else {
+            return false;
+        };
+        let stats = Self::get_provider_stats_map(&env);
+        preferences::matches_for_auto_copy(&env, &user, &signal, &stats)
+    }
+
+    /// Strategy counterpart of `signal_matches_preferences`
+    pub fn strategy_matches_preferences(env: Env, user: Address, strategy_id: u64) -> bool {
+        let Some(strategy) = strategy::get_strategy(&env, strategy_id) else {
+            return false;
+        };
+        let stats = Self::get_provider_stats_map(&env);
+        preferences::strategy_matches_for_auto_copy(&env, &user, &strategy, &stats)
+    }
+
+    /// Active signals the user has not swiped yet among the open signals at
+    /// positions `[offset, offset + limit)`, newest first
+    pub fn get_swipe_queue(env: Env, user: Address, offset: u32, limit: u32) -> Vec<SignalSummary> {
+        query::get_unswiped_signals(&env, &user, offset, limit)
     }
 
     /// Legacy fallback if front-ends rely on Old behavior
@@ -676,13 +1338,15 @@ impl SignalRegistry {
         user: Address,
         followed_only: bool,
     ) -> Vec<Signal> {
-        let signals = Self::get_signals_map(&env);
-        if followed_only {
+        let signals = if followed_only {
             let followed = social::get_followed_providers(&env, &user);
-            expiry::get_active_signals_filtered(&env, &signals, &followed)
+            expiry::get_active_signals_filtered(&env, &followed)
         } else {
-            expiry::get_active_signals(&env, &signals)
-        }
+            expiry::get_active_signals(&env)
+        };
+        let stats = Self::get_provider_stats_map(&env);
+        let signals = preferences::filter_signals(&env, &user, signals, &stats);
+        subscriptions::public_only(&env, signals)
     }
 
     /* =========================
@@ -709,39 +1373,214 @@ impl SignalRegistry {
         social::get_follower_count(&env, &provider)
     }
 
+    /// Follower count at the end of each of the last `days` days (max 365),
+    /// oldest first
+    pub fn get_follower_history(env: Env, provider: Address, days: u32) -> Vec<FollowerSnapshot> {
+        social::get_follower_history(&env, &provider, days)
+    }
+
+    /// Follower growth over the last `window_days` days in basis points
+    pub fn get_follower_growth_rate(env: Env, provider: Address, window_days: u32) -> i128 {
+        social::calculate_follower_growth(&env, &provider, window_days)
+    }
+
+    /* =========================
+       PROVIDER PROFILES
+    ========================== */
+
+    /// Create or replace the caller's public profile
+    pub fn set_provider_profile(
+        env: Env,
+        provider: Address,
+        display_name: String,
+        bio: String,
+        avatar_hash: Option<BytesN<32>>,
+        links: Vec<String>,
+        categories: Vec<SignalCategory>,
+    ) -> Result<(), ProfileError> {
+        provider.require_auth();
+        profiles::set_profile(
+            &env,
+            &provider,
+            display_name,
+            bio,
+            avatar_hash,
+            links,
+            categories,
+        )
+    }
+
+    /// Profile with verification badges, None if never set
+    pub fn get_provider_profile(env: Env, provider: Address) -> Option<ProviderProfile> {
+        profiles::get_profile(&env, &provider)
+    }
+
+    /// Appoint or remove a badge moderator (admin only)
+    pub fn set_moderator(
+        env: Env,
+        caller: Address,
+        moderator: Address,
+        enabled: bool,
+    ) -> Result<(), AdminError> {
+        admin::require_direct_admin(&env, &caller)?;
+        caller.require_auth();
+        profiles::set_moderator(&env, &moderator, enabled);
+        Ok(())
+    }
+
+    pub fn is_moderator(env: Env, account: Address) -> bool {
+        profiles::is_moderator(&env, &account)
+    }
+
+    /// Grant a verification badge (admin or moderator)
+    pub fn grant_badge(
+        env: Env,
+        caller: Address,
+        provider: Address,
+        badge: ProviderBadge,
+    ) -> Result<(), ProfileError> {
+        caller.require_auth();
+        profiles::set_badge(&env, &caller, &provider, badge, true)
+    }
+
+    /// Revoke a verification badge (admin or moderator)
+    pub fn revoke_badge(
+        env: Env,
+        caller: Address,
+        provider: Address,
+        badge: ProviderBadge,
+    ) -> Result<(), ProfileError> {
+        caller.require_auth();
+        profiles::set_badge(&env, &caller, &provider, badge, false)
+    }
+
+    /* =========================
+       SUBSCRIPTIONS
+    ========================== */
+
+    /// Add a subscription plan: `price` of `asset` per `period_seconds`
+    pub fn create_subscription_plan(
+        env: Env,
+        provider: Address,
+        asset: Asset,
+        price: i128,
+        period_seconds: u64,
+    ) -> Result<u32, SubscriptionError> {
+        provider.require_auth();
+        subscriptions::create_plan(&env, &provider, asset, price, period_seconds)
+    }
+
+    /// Open or close a plan to new subscriptions and renewals
+    pub fn set_subscription_plan_active(
+        env: Env,
+        provider: Address,
+        plan_id: u32,
+        active: bool,
+    ) -> Result<(), SubscriptionError> {
+        provider.require_auth();
+        subscriptions::set_plan_active(&env, &provider, plan_id, active)
+    }
+
+    pub fn get_subscription_plans(env: Env, provider: Address) -> Vec<SubscriptionPlan> {
+        subscriptions::get_plans(&env, &provider)
+    }
+
+    /// Subscribe to a provider, or renew, paying one period of the plan
+    pub fn subscribe(
+        env: Env,
+        subscriber: Address,
+        provider: Address,
+        plan_id: u32,
+    ) -> Result<Subscription, SubscriptionError> {
+        subscriber.require_auth();
+        subscriptions::subscribe(&env, &subscriber, &provider, plan_id)
+    }
+
+    pub fn get_subscription(
+        env: Env,
+        subscriber: Address,
+        provider: Address,
+    ) -> Option<Subscription> {
+        subscriptions::get_subscription(&env, &subscriber, &provider)
+    }
+
+    /// Create a signal visible in full only to the provider's active
+    /// subscribers. Public feeds list it without price or targets.
+    pub fn create_subscriber_signal(
+        env: Env,
+        provider: Address,
+        asset_pair: String,
+        action: SignalAction,
+        price: i128,
+        rationale: String,
+        expiry: u64,
+        category: SignalCategory,
+        tags: Vec<String>,
+        risk_level: RiskLevel,
+    ) -> Result<u64, AdminError> {
+        provider.require_auth();
+        let id = Self::create_signal_internal(
+            &env, provider, asset_pair, action, price, rationale, expiry, category, tags,
+            risk_level,
+        )?;
+        subscriptions::set_subscriber_only(&env, id);
+        Ok(id)
+    }
+
+    pub fn is_subscriber_only(env: Env, signal_id: u64) -> bool {
+        subscriptions::is_subscriber_only(&env, signal_id)
+    }
+
+    /// Full signal details for the provider or an active subscriber
+    pub fn get_signal_for_subscriber(
+        env: Env,
+        viewer: Address,
+        signal_id: u64,
+    ) -> Result<Signal, SubscriptionError> {
+        viewer.require_auth();
+        subscriptions::get_signal_for(&env, &viewer, signal_id)
+    }
+
     /// Cleanup expired signals in batches
     /// Returns (signals_processed, signals_expired)
     pub fn cleanup_expired_signals(env: Env, limit: u32) -> (u32, u32) {
-        let signals = Self::get_signals_map(&env);
-        let result = expiry::cleanup_expired_signals(&env, &signals, limit);
+        let result = expiry::cleanup_expired_signals(&env, limit);
         (result.signals_processed, result.signals_expired)
     }
 
+    /// Expire conditional signals whose trigger never fired
+    /// Returns number of signals expired
+    pub fn expire_untriggered_signals(env: Env, limit: u32) -> u32 {
+        expiry::expire_untriggered_signals(&env, limit)
+    }
+
     /// Archive old expired signals (30+ days old)
     /// Returns number of signals archived
     pub fn archive_old_signals(env: Env, limit: u32) -> u32 {
-        let signals = Self::get_signals_map(&env);
-        expiry::archive_old_signals(&env, &signals, limit)
+        expiry::archive_old_signals(&env, limit)
+    }
+
+    /// Extend the TTL of signal registry entries. Permissionless so anyone
+    /// can keep live data from expiring. Expired signals are skipped.
+    /// Returns the number of ledger entries bumped.
+    pub fn bump_ttl(env: Env, keys: Vec<TtlKey>) -> Result<u32, AdminError> {
+        if keys.len() > stellar_swipe_common::MAX_BUMP_KEYS {
+            return Err(AdminError::InvalidParameter);
+        }
+        Ok(storage::bump_ttl(&env, &keys))
     }
 
     /// Get count of expired signals
     pub fn get_expired_count(env: Env) -> u32 {
-        let signals = Self::get_signals_map(&env);
-        expiry::count_expired_signals(&signals)
+        expiry::count_expired_signals(&env)
     }
 
     /// Get count of signals pending expiry (past expiry time but not marked yet)
     pub fn get_pending_expiry_count(env: Env) -> u32 {
-        let signals = Self::get_signals_map(&env);
-        expiry::count_signals_pending_expiry(&env, &signals)
+        expiry::count_signals_pending_expiry(&env)
     }
 
     /* =========================
- feature/signal-categorization-tagging
- feature/signal-categorization-tagging
-=======
- feature/analytics-system
- main
        ANALYTICS FUNCTIONS
     ========================== */
 
@@ -750,27 +1589,29 @@ impl SignalRegistry {
         env: Env,
         provider: Address,
     ) -> Option<analytics::ProviderAnalytics> {
-        let signals = Self::get_signals_map(&env);
-        analytics::calculate_provider_analytics(&env, &signals, &provider)
+        analytics::calculate_provider_analytics(&env, &provider)
     }
 
-    /// Get trending asset pairs in last N hours
-    pub fn get_trending_assets(env: Env, window_hours: u64) -> Vec<(String, u32)> {
-        let signals = Self::get_signals_map(&env);
-        analytics::get_trending_assets(&env, &signals, window_hours)
+    /// Get asset pairs whose activity (signals, copies, volume and swipes)
+    /// in the last N hours grew most versus the N hours before, optionally
+    /// within one category
+    pub fn get_trending_assets(
+        env: Env,
+        window_hours: u64,
+        category: Option<SignalCategory>,
+    ) -> Vec<TrendEntry> {
+        analytics::get_trending_assets(&env, window_hours, category)
     }
 
     /// Get global analytics (24h metrics)
     pub fn get_global_analytics(env: Env) -> analytics::GlobalAnalytics {
-        let signals = Self::get_signals_map(&env);
-        analytics::calculate_global_analytics(&env, &signals)
- feature/signal-categorization-tagging
+        analytics::calculate_global_analytics(&env)
     }
-    
+
     /* =========================
        CATEGORIZATION & TAGGING FUNCTIONS
     ========================== */
-    
+
     /// Add tags to an existing signal
     pub fn add_tags_to_signal(
         env: Env,
@@ -779,23 +1620,23 @@ impl SignalRegistry {
         tags: Vec<String>,
     ) -> Result<(), AdminError> {
         provider.require_auth();
-        
-        let mut signals = Self::get_signals_map(&env);
-        let mut signal = signals.get(signal_id).ok_or(AdminError::InvalidParameter)?;
-        
+
+        let mut signal =
+            storage::get_signal(&env, signal_id).ok_or(AdminError::InvalidParameter)?;
+
         // Verify provider owns the signal
         if signal.provider != provider {
             return Err(AdminError::Unauthorized);
         }
-        
+
         // Validate new tags
         categories::validate_tags(&tags)?;
-        
+
         // Check total tag count
         if signal.tags.len() + tags.len() > 10 {
             return Err(AdminError::InvalidParameter);
         }
-        
+
         // Add tags (deduplicate)
         let mut combined = Vec::new(&env);
         for i in 0..signal.tags.len() {
@@ -804,22 +1645,22 @@ impl SignalRegistry {
         for i in 0..tags.len() {
             combined.push_back(tags.get(i).unwrap());
         }
-        
+
         signal.tags = categories::deduplicate_tags(&env, combined);
         let tag_count = signal.tags.len();
-        signals.set(signal_id, signal);
-        Self::save_signals_map(&env, &signals);
-        
-        // Update tag popularity
-        categories::increment_tag_popularity(&env, &tags);
-        
+        storage::save_signal(&env, &signal);
+
         // Emit event
         events::emit_tags_added(&env, signal_id, provider, tag_count);
-        
+
         Ok(())
     }
-    
-    /// Get signals filtered by categories, tags, and risk levels
+
+    /// Get signals filtered by categories, tags, and risk levels.
+    /// With `user`, that user's stored preferences are applied too.
+    /// `offset` and `limit` select positions in each category index, or in
+    /// the active index without a category filter, and only that window is
+    /// read; the filters then narrow the page.
     pub fn get_signals_filtered(
         env: Env,
         categories: Option<Vec<SignalCategory>>,
@@ -827,35 +1668,43 @@ impl SignalRegistry {
         risk_levels: Option<Vec<RiskLevel>>,
         offset: u32,
         limit: u32,
+        user: Option<Address>,
     ) -> Vec<Signal> {
-        let signals_map = Self::get_signals_map(&env);
         let mut filtered = Vec::new(&env);
         let now = env.ledger().timestamp();
-        
-        // Collect active signals
-        for key in signals_map.keys() {
-            if let Some(signal) = signals_map.get(key) {
-                if signal.status == SignalStatus::Active && signal.expiry > now {
-                    filtered.push_back(signal);
+
+        // Read one window of active signals, from the category indexes when
+        // filtering by category
+        let limit = query::clamp_limit(limit);
+        let candidates = match categories {
+            Some(cats) => {
+                let mut temp = Vec::new(&env);
+                for category in cats.iter() {
+                    temp.append(&storage::get_index_signals(
+                        &env,
+                        &storage::SignalIndex::Category(category),
+                        offset,
+                        limit,
+                    ));
                 }
+                temp
             }
-        }
-        
-        // Filter by categories
-        if let Some(cats) = categories {
-            let mut temp = Vec::new(&env);
-            for i in 0..filtered.len() {
-                let signal = filtered.get(i).unwrap();
-                for j in 0..cats.len() {
-                    if signal.category == cats.get(j).unwrap() {
-                        temp.push_back(signal);
-                        break;
-                    }
-                }
+            None => storage::get_index_signals(
+                &env,
+                &storage::SignalIndex::Status(SignalStatus::Active),
+                offset,
+                limit,
+            ),
+        };
+        for signal in candidates.iter() {
+            if signal.status == SignalStatus::Active
+                && signal.expiry > now
+                && subscriptions::is_public(&env, &signal)
+            {
+                filtered.push_back(signal);
             }
-            filtered = temp;
         }
-        
+
         // Filter by tags (any match)
         if let Some(tags_filter) = tags {
             let mut temp = Vec::new(&env);
@@ -880,7 +1729,7 @@ impl SignalRegistry {
             }
             filtered = temp;
         }
-        
+
         // Filter by risk levels
         if let Some(risks) = risk_levels {
             let mut temp = Vec::new(&env);
@@ -895,31 +1744,38 @@ impl SignalRegistry {
             }
             filtered = temp;
         }
-        
-        // Paginate
-        let total = filtered.len();
-        let start = offset.min(total);
-        let end = (offset + limit).min(total);
-        
+
+        if let Some(user) = user {
+            let stats = Self::get_provider_stats_map(&env);
+            filtered = preferences::filter_signals(&env, &user, filtered, &stats);
+        }
+
+        // Several category windows can together exceed the page size
         let mut result = Vec::new(&env);
-        for i in start..end {
-            result.push_back(filtered.get(i).unwrap());
+        for signal in filtered.iter().take(limit as usize) {
+            result.push_back(signal);
         }
-        
+
         result
     }
-    
-    /// Get popular tags
-    pub fn get_popular_tags(env: Env, limit: u32) -> Vec<(String, u32)> {
-        categories::get_popular_tags(&env, limit)
+
+    /// Get the most active tags of the last N hours, optionally within one
+    /// category, with their velocity against the N hours before
+    pub fn get_popular_tags(
+        env: Env,
+        window_hours: u64,
+        category: Option<SignalCategory>,
+        limit: u32,
+    ) -> Vec<TrendEntry> {
+        analytics::get_popular_tags(&env, window_hours, category, limit)
     }
-    
+
     /// Auto-suggest tags based on signal rationale
     pub fn suggest_tags(env: Env, rationale: String) -> Vec<String> {
         categories::auto_suggest_tags(&env, &rationale)
-=======
- main
-=======
+    }
+
+    /* =========================
        SIGNAL IMPORT FUNCTIONS
     ========================== */
 
@@ -966,18 +1822,18 @@ impl SignalRegistry {
         external_id: String,
     ) -> Option<u64> {
         import::get_signal_by_external_id(&env, &provider, &external_id)
- main
     }
 
     /* =========================
        COLLABORATION FUNCTIONS
     ========================== */
 
+    /// Create a signal shared between authors. `contributions` lists each
+    /// author's share in basis points (summing to 10000), primary author first.
     pub fn create_collaborative_signal(
         env: Env,
         primary_author: Address,
-        co_authors: Vec<Address>,
-        contribution_pcts: Vec<u32>,
+        contributions: Vec<(Address, u32)>,
         asset_pair: String,
         action: SignalAction,
         price: i128,
@@ -1002,21 +1858,22 @@ impl SignalRegistry {
             risk_level,
         )?;
 
-        let mut signals = Self::get_signals_map(&env);
-        let mut signal = signals.get(signal_id).unwrap();
+        let mut signal = storage::get_signal(&env, signal_id).unwrap();
         signal.is_collaborative = true;
         signal.status = SignalStatus::Pending;
-        signals.set(signal_id, signal);
-        Self::save_signals_map(&env, &signals);
+        storage::save_signal(&env, &signal);
 
-        collaboration::create_collaborative_signal(
+        let authors = collaboration::create_collaborative_signal(
             &env,
             signal_id,
             primary_author,
-            co_authors.clone(),
-            contribution_pcts,
+            contributions,
         )?;
 
+        let mut co_authors = Vec::new(&env);
+        for i in 1..authors.len() {
+            co_authors.push_back(authors.get(i).unwrap().address);
+        }
         events::emit_collaborative_signal_created(&env, signal_id, co_authors);
         Ok(signal_id)
     }
@@ -1032,11 +1889,10 @@ impl SignalRegistry {
         events::emit_collaborative_signal_approved(&env, signal_id, approver);
 
         if all_approved {
-            let mut signals = Self::get_signals_map(&env);
-            let mut signal = signals.get(signal_id).ok_or(AdminError::InvalidParameter)?;
+            let mut signal =
+                storage::get_signal(&env, signal_id).ok_or(AdminError::InvalidParameter)?;
             signal.status = SignalStatus::Active;
-            signals.set(signal_id, signal);
-            Self::save_signals_map(&env, &signals);
+            storage::save_signal(&env, &signal);
             events::emit_collaborative_signal_published(&env, signal_id);
         }
 
@@ -1056,16 +1912,28 @@ impl SignalRegistry {
 }
 
 mod test;
- feature/signal-categorization-tagging
- feature/signal-categorization-tagging
 mod test_analytics;
+mod test_breakdown;
 mod test_categories;
-=======
- feature/analytics-system
-mod test_analytics;
- main
-=======
+mod test_collaboration;
+mod test_commit_reveal;
+mod test_conditions;
+mod test_equity_curve;
+mod test_feed;
+mod test_follower_history;
+mod test_global_stats;
+mod test_hold;
 mod test_import;
- main
+mod test_leaderboard;
+mod test_lifecycle;
+mod test_multisig;
 mod test_performance;
-mod test_collaboration;
+mod test_preferences;
+mod test_profiles;
+mod test_resolution;
+mod test_strategy;
+mod test_subscriptions;
+mod test_swipes;
+mod test_targets;
+mod test_trending;
+mod test_upgrade;
@@ -0,0 +1,218 @@
+//! Provider-driven signal lifecycle: cancel, close early and amend.
+//!
+//! - `cancel_signal` withdraws a call nobody has copied yet. Cancelled signals
+//!   leave the open indexes and never count in performance stats.
+//! - `close_signal` settles a live signal before expiry. The close price is the
+//!   oracle price when one is configured, otherwise the provider's exit price.
+//!   The outcome is stored like a resolution with reason `Closed`, so early
+//!   closes count in provider stats.
+//! - `amend_signal` corrects rationale, price or expiry before any execution.
+//!   The replaced content is kept as a numbered revision (starting at 1).
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
+use crate::errors::AdminError;
+use crate::events::{emit_signal_amended, emit_signal_cancelled, emit_signal_closed};
+use crate::performance;
+use crate::resolution::{self, OutcomeReason, SignalOutcome};
+use crate::storage;
+use crate::targets;
+use crate::templates::MAX_SIGNAL_RATIONALE_BYTES;
+use crate::types::{Signal, SignalStatus};
+
+/// Maximum number of amendments per signal
+pub const MAX_SIGNAL_REVISIONS: u32 = 10;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum LifecycleStorageKey {
+    /// signal_id -> number of revisions recorded
+    RevisionCount(u64),
+    /// (signal_id, revision) -> SignalRevision
+    Revision(u64, u32),
+}
+
+/// Fields a provider can change before the signal is executed.
+/// `None` leaves the field unchanged.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignalAmendment {
+    pub rationale: Option<String>,
+    pub price: Option<i128>,
+    pub expiry: Option<u64>,
+}
+
+/// Signal content replaced by an amendment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignalRevision {
+    pub revision: u32,
+    pub rationale: String,
+    pub price: i128,
+    pub expiry: u64,
+    pub replaced_at: u64,
+}
+
+/// Load a signal the provider owns that is still live and unsettled
+fn load_open_signal(env: &Env, provider: &Address, signal_id: u64) -> Result<Signal, AdminError> {
+    let signal = storage::get_signal(env, signal_id).ok_or(AdminError::InvalidParameter)?;
+    if signal.provider != *provider {
+        return Err(AdminError::Unauthorized);
+    }
+
+    let open = matches!(signal.status, SignalStatus::Active | SignalStatus::Pending)
+        && signal.expiry > env.ledger().timestamp()
+        && resolution::get_outcome(env, signal_id).is_none();
+    if !open {
+        return Err(AdminError::SignalNotOpen);
+    }
+    Ok(signal)
+}
+
+/// Withdraw a signal that has not been executed
+pub fn cancel_signal(env: &Env, provider: &Address, signal_id: u64) -> Result<(), AdminError> {
+    let mut signal = load_open_signal(env, provider, signal_id)?;
+    if signal.executions > 0 {
+        return Err(AdminError::SignalHasExecutions);
+    }
+
+    signal.status = SignalStatus::Cancelled;
+    storage::save_signal(env, &signal);
+
+    emit_signal_cancelled(env, signal_id, provider.clone());
+    Ok(())
+}
+
+/// Settle a live signal now. The signal's expiry is moved to the close time
+/// so it drops out of active feeds.
+/// Returns the outcome together with the status the signal had before.
+pub fn close_signal(
+    env: &Env,
+    provider: &Address,
+    signal_id: u64,
+    exit_price: i128,
+) -> Result<(SignalOutcome, SignalStatus, Signal), AdminError> {
+    let mut signal = load_open_signal(env, provider, signal_id)?;
+    // Pending signals have not been published or activated yet
+    if signal.status != SignalStatus::Active {
+        return Err(AdminError::SignalNotOpen);
+    }
+
+    let close_price = match resolution::get_price_oracle(env) {
+        Some(oracle) => resolution::fetch_close_price(env, &oracle, &signal)
+            .map_err(|_| AdminError::OraclePriceUnavailable)?,
+        None if exit_price > 0 => exit_price,
+        None => return Err(AdminError::InvalidParameter),
+    };
+
+    let old_status = signal.status.clone();
+    let roi = performance::calculate_roi(signal.price, close_price, &signal.action);
+    let status = performance::evaluate_outcome_status(&signal.action, roi);
+
+    signal.expiry = env.ledger().timestamp();
+    let outcome = resolution::store_outcome(
+        env,
+        &mut signal,
+        close_price,
+        roi,
+        status,
+        OutcomeReason::Closed,
+    );
+
+    emit_signal_closed(env, signal_id, provider.clone(), close_price, roi);
+    Ok((outcome, old_status, signal))
+}
+
+/// Apply an amendment and record the replaced content.
+/// Returns the new revision number.
+pub fn amend_signal(
+    env: &Env,
+    provider: &Address,
+    signal_id: u64,
+    amendment: SignalAmendment,
+) -> Result<u32, AdminError> {
+    let mut signal = load_open_signal(env, provider, signal_id)?;
+    if signal.executions > 0 {
+        return Err(AdminError::SignalHasExecutions);
+    }
+    if amendment.rationale.is_none() && amendment.price.is_none() && amendment.expiry.is_none() {
+        return Err(AdminError::InvalidParameter);
+    }
+
+    let count = get_revision_count(env, signal_id);
+    if count >= MAX_SIGNAL_REVISIONS {
+        return Err(AdminError::InvalidParameter);
+    }
+
+    let now = env.ledger().timestamp();
+    let previous = SignalRevision {
+        revision: count + 1,
+        rationale: signal.rationale.clone(),
+        price: signal.price,
+        expiry: signal.expiry,
+        replaced_at: now,
+    };
+
+    if let Some(rationale) = amendment.rationale {
+        if rationale.is_empty() || rationale.len() > MAX_SIGNAL_RATIONALE_BYTES {
+            return Err(AdminError::InvalidRationale);
+        }
+        signal.rationale = rationale;
+    }
+
+    if let Some(price) = amendment.price {
+        if price <= 0 {
+            return Err(AdminError::InvalidParameter);
+        }
+        // Existing targets must stay on the right side of the new price
+        if let Some(targets) = targets::get_targets(env, signal_id) {
+            targets::validate_targets(&signal.action, price, &targets)?;
+        }
+        signal.price = price;
+    }
+
+    if let Some(expiry) = amendment.expiry {
+        if expiry <= now || expiry > now + crate::MAX_EXPIRY_SECONDS {
+            return Err(AdminError::InvalidExpiry);
+        }
+        signal.expiry = expiry;
+    }
+
+    storage::save_signal(env, &signal);
+
+    let key = LifecycleStorageKey::Revision(signal_id, previous.revision);
+    env.storage().persistent().set(&key, &previous);
+    bump_persistent(env, &key, TtlClass::History);
+
+    let count_key = LifecycleStorageKey::RevisionCount(signal_id);
+    env.storage()
+        .persistent()
+        .set(&count_key, &previous.revision);
+    bump_persistent(env, &count_key, TtlClass::History);
+
+    emit_signal_amended(env, signal_id, provider.clone(), previous.revision);
+    Ok(previous.revision)
+}
+
+pub fn get_revision_count(env: &Env, signal_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&LifecycleStorageKey::RevisionCount(signal_id))
+        .unwrap_or(0)
+}
+
+/// All revisions of a signal, oldest first
+pub fn get_revisions(env: &Env, signal_id: u64) -> Vec<SignalRevision> {
+    let mut revisions = Vec::new(env);
+    for revision in 1..=get_revision_count(env, signal_id) {
+        if let Some(entry) = env
+            .storage()
+            .persistent()
+            .get(&LifecycleStorageKey::Revision(signal_id, revision))
+        {
+            revisions.push_back(entry);
+        }
+    }
+    revisions
+}
@@ -0,0 +1,329 @@
+//! Multi-sig proposals for admin actions.
+//!
+//! When multi-sig is enabled, a signer proposes an action and the proposer's
+//! approval counts as the first signature. Other signers approve until the
+//! threshold is met, at which point the action executes in the same call.
+//! Only approvals from current signers count, so removing a signer also
+//! withdraws their approvals from open proposals.
+//!
+//! Pausing, upgrades and signer management go through proposals as well once
+//! the threshold is above one; see `admin::require_direct_admin`.
+//!
+//! Proposals expire after `PROPOSAL_DURATION` and can be cancelled by the
+//! proposer or the admin while pending.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
+use crate::admin::{
+    self, get_admin, get_multisig_threshold, is_multisig_enabled, is_multisig_signer, MAX_FEE_BPS,
+    MAX_RISK_PERCENTAGE,
+};
+use crate::errors::AdminError;
+use crate::events::{
+    emit_proposal_approved, emit_proposal_cancelled, emit_proposal_created, emit_proposal_executed,
+};
+use crate::fees;
+use crate::profiles;
+use crate::resolution;
+use crate::stake;
+use crate::upgrade;
+
+pub const PROPOSAL_DURATION: u64 = 72 * 60 * 60; // 72 hours in seconds
+
+/// Admin action that can be executed through a proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    SetMinStake(i128),
+    SetTradeFee(u32),
+    /// (stop_loss, position_limit)
+    SetRiskDefaults(u32, u32),
+    PauseTrading,
+    UnpauseTrading,
+    SetPlatformTreasury(Address),
+    TransferAdmin(Address),
+    SetPriceOracle(Address),
+    SetStakeToken(Address),
+    /// (provider, amount)
+    SlashProvider(Address, i128),
+    /// (moderator, enabled)
+    SetModerator(Address, bool),
+    AddMultisigSigner(Address),
+    RemoveMultisigSigner(Address),
+    DisableMultisig,
+    /// Replace the contract Wasm with the uploaded code of this hash
+    Upgrade(BytesN<32>),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Pending,
+    Executed,
+    Cancelled,
+    Expired,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: AdminAction,
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub status: ProposalStatus,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum MultiSigStorageKey {
+    /// Last proposal id issued (instance)
+    ProposalCounter,
+    /// proposal_id -> Proposal (persistent)
+    Proposal(u64),
+}
+
+fn next_proposal_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&MultiSigStorageKey::ProposalCounter)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&MultiSigStorageKey::ProposalCounter, &id);
+    id
+}
+
+fn load_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, AdminError> {
+    env.storage()
+        .persistent()
+        .get(&MultiSigStorageKey::Proposal(proposal_id))
+        .ok_or(AdminError::ProposalNotFound)
+}
+
+fn save_proposal(env: &Env, proposal: &Proposal) {
+    let key = MultiSigStorageKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    bump_persistent(env, &key, TtlClass::Hot);
+}
+
+/// Get a proposal. Pending proposals past their expiry are reported as Expired.
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
+    let mut proposal = load_proposal(env, proposal_id).ok()?;
+    if proposal.status == ProposalStatus::Pending && env.ledger().timestamp() >= proposal.expires_at
+    {
+        proposal.status = ProposalStatus::Expired;
+    }
+    Some(proposal)
+}
+
+/// Load a proposal that can still collect approvals
+fn load_pending(env: &Env, proposal_id: u64) -> Result<Proposal, AdminError> {
+    let proposal = get_proposal(env, proposal_id).ok_or(AdminError::ProposalNotFound)?;
+    match proposal.status {
+        ProposalStatus::Pending => Ok(proposal),
+        ProposalStatus::Expired => Err(AdminError::ProposalExpired),
+        _ => Err(AdminError::ProposalNotPending),
+    }
+}
+
+fn require_signer(env: &Env, caller: &Address) -> Result<(), AdminError> {
+    if !is_multisig_enabled(env) {
+        return Err(AdminError::MultiSigNotEnabled);
+    }
+    if !is_multisig_signer(env, caller) {
+        return Err(AdminError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Reject actions that would fail on execution
+fn validate_action(action: &AdminAction) -> Result<(), AdminError> {
+    match action {
+        AdminAction::SetMinStake(amount) if *amount <= 0 => Err(AdminError::InvalidParameter),
+        AdminAction::SlashProvider(_, amount) if *amount <= 0 => Err(AdminError::InvalidParameter),
+        AdminAction::SetTradeFee(fee_bps) if *fee_bps > MAX_FEE_BPS => {
+            Err(AdminError::InvalidFeeRate)
+        }
+        AdminAction::SetRiskDefaults(stop_loss, position_limit)
+            if *stop_loss > MAX_RISK_PERCENTAGE || *position_limit > MAX_RISK_PERCENTAGE =>
+        {
+            Err(AdminError::InvalidRiskParameter)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Number of approvals from addresses that are still signers
+fn count_valid_approvals(env: &Env, proposal: &Proposal) -> u32 {
+    let mut count = 0u32;
+    for signer in proposal.approvals.iter() {
+        if is_multisig_signer(env, &signer) {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn apply_action(env: &Env, action: &AdminAction, executor: &Address) -> Result<(), AdminError> {
+    match action {
+        AdminAction::SetMinStake(amount) => admin::apply_min_stake(env, *amount),
+        AdminAction::SetTradeFee(fee_bps) => admin::apply_trade_fee(env, *fee_bps),
+        AdminAction::SetRiskDefaults(stop_loss, position_limit) => {
+            admin::apply_risk_defaults(env, *stop_loss, *position_limit)
+        }
+        AdminAction::PauseTrading => {
+            admin::apply_pause(env, executor);
+            Ok(())
+        }
+        AdminAction::UnpauseTrading => {
+            admin::apply_unpause(env, executor);
+            Ok(())
+        }
+        AdminAction::SetPlatformTreasury(treasury) => {
+            fees::set_platform_treasury(env, treasury.clone());
+            Ok(())
+        }
+        AdminAction::TransferAdmin(new_admin) => {
+            admin::apply_transfer_admin(env, new_admin.clone())
+        }
+        AdminAction::SetPriceOracle(oracle) => {
+            resolution::store_price_oracle(env, oracle.clone());
+            Ok(())
+        }
+        AdminAction::SetStakeToken(token) => {
+            stake::set_stake_token(env, token.clone());
+            Ok(())
+        }
+        AdminAction::SlashProvider(provider, amount) => {
+            stake::apply_slash(env, provider, *amount).map(|_| ())
+        }
+        AdminAction::SetModerator(moderator, enabled) => {
+            profiles::set_moderator(env, moderator, *enabled);
+            Ok(())
+        }
+        AdminAction::AddMultisigSigner(signer) => {
+            admin::apply_add_multisig_signer(env, signer.clone(), executor)
+        }
+        AdminAction::RemoveMultisigSigner(signer) => {
+            admin::apply_remove_multisig_signer(env, signer.clone(), executor)
+        }
+        AdminAction::DisableMultisig => {
+            admin::apply_disable_multisig(env);
+            Ok(())
+        }
+        AdminAction::Upgrade(wasm_hash) => {
+            upgrade::apply_upgrade(env, executor, wasm_hash.clone());
+            Ok(())
+        }
+    }
+}
+
+/// Execute the proposal if it has enough valid approvals.
+/// Returns true if it was executed.
+fn execute_if_ready(
+    env: &Env,
+    proposal: &mut Proposal,
+    executor: &Address,
+) -> Result<bool, AdminError> {
+    if count_valid_approvals(env, proposal) < get_multisig_threshold(env) {
+        return Ok(false);
+    }
+
+    apply_action(env, &proposal.action, executor)?;
+    proposal.status = ProposalStatus::Executed;
+    emit_proposal_executed(env, proposal.id, executor.clone());
+    Ok(true)
+}
+
+/// Propose an admin action. The proposer's approval is recorded, and the
+/// action executes immediately if that already meets the threshold.
+pub fn propose(env: &Env, proposer: &Address, action: AdminAction) -> Result<u64, AdminError> {
+    require_signer(env, proposer)?;
+    proposer.require_auth();
+    validate_action(&action)?;
+
+    let now = env.ledger().timestamp();
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+
+    let mut proposal = Proposal {
+        id: next_proposal_id(env),
+        proposer: proposer.clone(),
+        action,
+        approvals,
+        created_at: now,
+        expires_at: now + PROPOSAL_DURATION,
+        status: ProposalStatus::Pending,
+    };
+
+    emit_proposal_created(env, proposal.id, proposer.clone(), proposal.expires_at);
+    emit_proposal_approved(env, proposal.id, proposer.clone(), 1);
+
+    execute_if_ready(env, &mut proposal, proposer)?;
+    save_proposal(env, &proposal);
+    Ok(proposal.id)
+}
+
+/// Approve a pending proposal, executing it once the threshold is met.
+/// Returns the proposal status after the approval.
+pub fn approve(
+    env: &Env,
+    signer: &Address,
+    proposal_id: u64,
+) -> Result<ProposalStatus, AdminError> {
+    require_signer(env, signer)?;
+    signer.require_auth();
+
+    let mut proposal = load_pending(env, proposal_id)?;
+    if proposal.approvals.contains(signer) {
+        return Err(AdminError::AlreadyApproved);
+    }
+
+    proposal.approvals.push_back(signer.clone());
+    emit_proposal_approved(
+        env,
+        proposal_id,
+        signer.clone(),
+        count_valid_approvals(env, &proposal),
+    );
+
+    execute_if_ready(env, &mut proposal, signer)?;
+    save_proposal(env, &proposal);
+    Ok(proposal.status)
+}
+
+/// Execute a pending proposal whose approvals meet the current threshold,
+/// e.g. after the threshold was lowered.
+pub fn execute(env: &Env, caller: &Address, proposal_id: u64) -> Result<(), AdminError> {
+    require_signer(env, caller)?;
+    caller.require_auth();
+
+    let mut proposal = load_pending(env, proposal_id)?;
+    if !execute_if_ready(env, &mut proposal, caller)? {
+        return Err(AdminError::InsufficientSignatures);
+    }
+
+    save_proposal(env, &proposal);
+    Ok(())
+}
+
+/// Cancel a pending proposal. Proposer or admin only.
+pub fn cancel(env: &Env, caller: &Address, proposal_id: u64) -> Result<(), AdminError> {
+    let mut proposal = load_pending(env, proposal_id)?;
+    if caller != &proposal.proposer && caller != &get_admin(env)? {
+        return Err(AdminError::Unauthorized);
+    }
+    caller.require_auth();
+
+    proposal.status = ProposalStatus::Cancelled;
+    save_proposal(env, &proposal);
+
+    emit_proposal_cancelled(env, proposal_id, caller.clone());
+    Ok(())
+}
@@ -1,3 +1,7 @@
+use soroban_sdk::{contracttype, Vec};
+
+use crate::errors::ResolutionError;
+use crate::strategy::StrategyLeg;
 use crate::types::{ProviderPerformance, Signal, SignalAction, SignalStatus, TradeExecution};
 
 /// ROI calculation constants
@@ -5,16 +9,89 @@ const BASIS_POINTS_100_PERCENT: i128 = 10000;
 const SUCCESS_THRESHOLD_BPS: i128 = 200; // 2% in basis points
 const FAILURE_THRESHOLD_BPS: i128 = -500; // -5% in basis points
 const MIN_ROI_BPS: i128 = -10000; // -100% cap
+/// Largest price move (either way) a Hold signal tolerates
+pub const HOLD_BAND_BPS: i128 = 200; // 2% in basis points
+/// Floor on the standard deviation and drawdown used for risk-adjusted
+/// returns, so a few identical results cannot produce an unbounded score
+pub const MIN_RISK_BPS: i128 = 100; // 1% in basis points
+
+/// Time for a resolved signal's weight in the reputation score to halve
+pub const REPUTATION_HALF_LIFE_SECONDS: u64 = 30 * 24 * 60 * 60;
+/// Weight of one resolved signal (or slash) before decay
+const REPUTATION_UNIT: i128 = 10_000;
+/// Share of the reputation score earned from the decayed success rate
+pub const REPUTATION_PERFORMANCE_WEIGHT_BPS: i128 = 9_000;
+/// Bonus for stake, reached at `REPUTATION_FULL_STAKE_MULTIPLE` times the minimum stake
+pub const REPUTATION_MAX_STAKE_BONUS: i128 = 1_000;
+pub const REPUTATION_FULL_STAKE_MULTIPLE: i128 = 5;
+/// Points a fresh slash takes off the score, decaying like signal weight
+pub const REPUTATION_SLASH_PENALTY: i128 = 2_500;
+
+/// Equity curve value before a provider's first resolved signal
+pub const EQUITY_START_VALUE: i128 = 10_000;
+
+/// One point of a provider's equity curve: the value of following every
+/// resolved signal so far, compounded from `EQUITY_START_VALUE`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EquityPoint {
+    pub resolved_at: u64,
+    /// ROI of the signal that moved the curve (basis points)
+    pub roi: i128,
+    pub equity: i128,
+}
+
+/// Time-decayed evidence behind a provider's reputation score
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProviderReputation {
+    /// Successful signals, each weighted 10000 and halved every half-life
+    pub decayed_successes: i128,
+    /// Resolved signals, weighted like `decayed_successes`
+    pub decayed_signals: i128,
+    /// Time both sums were last decayed to
+    pub updated_at: u64,
+}
+
+/// Return series statistics of a provider's resolved signals, in the order
+/// they resolved
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProviderRiskStats {
+    pub resolved_signals: u32,
+    /// Sum of signal ROIs in basis points
+    pub sum_roi: i128,
+    /// Sum of squared signal ROIs, for the running variance
+    pub sum_sq_roi: i128,
+    pub mean_roi: i128,
+    /// Population variance of signal ROIs (bps²)
+    pub variance: i128,
+    /// Standard deviation of signal ROIs in basis points
+    pub std_dev: i128,
+    /// Largest fall of the cumulative ROI from its previous peak (bps, >= 0)
+    pub max_drawdown: i128,
+    /// Highest cumulative ROI reached so far
+    pub peak_roi: i128,
+    pub total_holding_time: u64,
+    /// Average seconds from when a signal took effect to its resolution
+    pub avg_holding_time: u64,
+    /// Sharpe-like ratio: mean ROI over standard deviation, scaled by 10000
+    pub risk_adjusted_return: i128,
+    /// Calmar-like ratio: cumulative ROI over max drawdown, scaled by 10000
+    pub drawdown_adjusted_return: i128,
+}
 
 /// Calculate ROI in basis points from entry and exit prices
 ///
 /// # Arguments
 /// * `entry_price` - Entry price for the trade
 /// * `exit_price` - Exit price for the trade
-/// * `action` - Buy or Sell signal action
+/// * `action` - Buy, Sell or Hold signal action
 ///
 /// # Returns
 /// ROI in basis points (10000 = 100%). Capped at -100% minimum.
+/// Hold calls score the size of the move against them, so their ROI is
+/// never positive and 0 means the price did not move.
 ///
 /// # Panics
 /// Panics if entry_price is 0 (division by zero)
@@ -27,6 +104,7 @@ pub fn calculate_roi(entry_price: i128, exit_price: i128, action: &SignalAction)
     let price_diff = match action {
         SignalAction::Buy => exit_price - entry_price,
         SignalAction::Sell => entry_price - exit_price, // Inverted for sell signals
+        SignalAction::Hold => -(exit_price - entry_price).abs(),
     };
 
     // Calculate ROI: (price_diff / entry_price) * 10000
@@ -115,6 +193,58 @@ pub fn evaluate_signal_status(signal: &Signal, now: u64) -> SignalStatus {
     }
 }
 
+/// Evaluate a signal's outcome from its oracle-verified ROI at expiry
+///
+/// # Success/Failure Criteria:
+/// - Buy/Sell: Successful if ROI > 2%
+/// - Hold: Successful if the price stayed within `HOLD_BAND_BPS`
+/// - Failed: everything else (the call did not play out before expiry)
+pub fn evaluate_outcome_status(action: &SignalAction, outcome_roi: i128) -> SignalStatus {
+    let successful = match action {
+        SignalAction::Hold => outcome_roi >= -HOLD_BAND_BPS,
+        _ => outcome_roi > SUCCESS_THRESHOLD_BPS,
+    };
+
+    if successful {
+        SignalStatus::Successful
+    } else {
+        SignalStatus::Failed
+    }
+}
+
+/// Calculate the combined ROI of a multi-leg strategy in basis points
+///
+/// Each leg's ROI is weighted by its `weight_bps` (weights sum to 10000).
+/// `exit_prices` holds one price per leg, in leg order.
+/// Returns `RoiOverflow` if the weighted sum does not fit in an i128.
+pub fn calculate_strategy_roi(
+    legs: &Vec<StrategyLeg>,
+    exit_prices: &Vec<i128>,
+) -> Result<i128, ResolutionError> {
+    let mut weighted_total: i128 = 0;
+    for (leg, exit_price) in legs.iter().zip(exit_prices.iter()) {
+        let leg_roi = calculate_roi(leg.price, exit_price, &leg.action);
+        weighted_total = leg_roi
+            .checked_mul(leg.weight_bps as i128)
+            .and_then(|weighted| weighted_total.checked_add(weighted))
+            .ok_or(ResolutionError::RoiOverflow)?;
+    }
+    Ok(weighted_total / BASIS_POINTS_100_PERCENT)
+}
+
+/// Evaluate a strategy's outcome from its combined ROI at expiry
+///
+/// # Success/Failure Criteria:
+/// - Successful: combined ROI > 2%
+/// - Failed: everything else
+pub fn evaluate_strategy_status(strategy_roi: i128) -> SignalStatus {
+    if strategy_roi > SUCCESS_THRESHOLD_BPS {
+        SignalStatus::Successful
+    } else {
+        SignalStatus::Failed
+    }
+}
+
 /// Get the average ROI for a signal
 ///
 /// # Arguments
@@ -145,13 +275,16 @@ pub fn update_provider_performance(
     signal_roi: i128,
     signal_volume: i128,
 ) {
-    // Only update when transitioning to a terminal state
+    // Only update when transitioning to a terminal state.
+    // Expired signals reach one when resolved against the oracle.
     let is_terminal_transition = matches!(
         (old_status, new_status),
         (SignalStatus::Active, SignalStatus::Successful)
             | (SignalStatus::Active, SignalStatus::Failed)
             | (SignalStatus::Pending, SignalStatus::Successful)
             | (SignalStatus::Pending, SignalStatus::Failed)
+            | (SignalStatus::Expired, SignalStatus::Successful)
+            | (SignalStatus::Expired, SignalStatus::Failed)
     );
 
     if !is_terminal_transition {
@@ -204,6 +337,137 @@ pub fn update_provider_performance(
         .expect("total volume overflow");
 }
 
+/// Integer square root (floor) of a non-negative value
+fn isqrt(value: i128) -> i128 {
+    if value < 2 {
+        return value.max(0);
+    }
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+/// Add a resolved signal to the provider's return series statistics
+///
+/// # Arguments
+/// * `stats` - Mutable reference to the provider's risk stats
+/// * `signal_roi` - ROI of the resolved signal (in basis points)
+/// * `holding_time` - Seconds between signal creation and resolution
+pub fn update_risk_stats(stats: &mut ProviderRiskStats, signal_roi: i128, holding_time: u64) {
+    stats.resolved_signals = stats
+        .resolved_signals
+        .checked_add(1)
+        .expect("resolved signals overflow");
+    let n = stats.resolved_signals as i128;
+
+    let cumulative = stats.sum_roi.saturating_add(signal_roi);
+    stats.sum_roi = cumulative;
+    stats.sum_sq_roi = stats
+        .sum_sq_roi
+        .saturating_add(signal_roi.saturating_mul(signal_roi));
+    stats.mean_roi = stats.sum_roi / n;
+    // Var = E[x²] - E[x]²
+    stats.variance = (stats.sum_sq_roi / n - stats.mean_roi * stats.mean_roi).max(0);
+    stats.std_dev = isqrt(stats.variance);
+
+    // Drawdown over the cumulative ROI curve, which starts at 0
+    if cumulative > stats.peak_roi {
+        stats.peak_roi = cumulative;
+    }
+    stats.max_drawdown = stats.max_drawdown.max(stats.peak_roi - cumulative);
+
+    stats.total_holding_time = stats.total_holding_time.saturating_add(holding_time);
+    stats.avg_holding_time = stats.total_holding_time / stats.resolved_signals as u64;
+
+    stats.risk_adjusted_return =
+        stats.mean_roi * BASIS_POINTS_100_PERCENT / stats.std_dev.max(MIN_RISK_BPS);
+    stats.drawdown_adjusted_return =
+        cumulative * BASIS_POINTS_100_PERCENT / stats.max_drawdown.max(MIN_RISK_BPS);
+}
+
+/// Equity after following a signal with `signal_roi` (basis points) from
+/// `equity`. ROI is capped at -100%, so equity never turns negative.
+pub fn compound_equity(equity: i128, signal_roi: i128) -> i128 {
+    equity.saturating_mul(BASIS_POINTS_100_PERCENT + signal_roi.max(MIN_ROI_BPS))
+        / BASIS_POINTS_100_PERCENT
+}
+
+/// Decay `value` by half every `REPUTATION_HALF_LIFE_SECONDS`, interpolating
+/// linearly inside a half-life
+pub fn decay(value: i128, elapsed: u64) -> i128 {
+    let half_lives = elapsed / REPUTATION_HALF_LIFE_SECONDS;
+    if half_lives >= 64 {
+        return 0;
+    }
+    let halved = value >> half_lives;
+    let rest = (elapsed % REPUTATION_HALF_LIFE_SECONDS) as i128;
+    halved - halved * rest / (2 * REPUTATION_HALF_LIFE_SECONDS as i128)
+}
+
+/// Decay the reputation evidence to `now` and add a resolved signal
+///
+/// # Arguments
+/// * `reputation` - Mutable reference to the provider's reputation evidence
+/// * `successful` - Whether the signal resolved successfully
+/// * `now` - Ledger timestamp of the resolution
+pub fn update_reputation(reputation: &mut ProviderReputation, successful: bool, now: u64) {
+    let elapsed = now.saturating_sub(reputation.updated_at);
+    reputation.decayed_successes = decay(reputation.decayed_successes, elapsed);
+    reputation.decayed_signals = decay(reputation.decayed_signals, elapsed);
+    reputation.updated_at = now;
+
+    reputation.decayed_signals += REPUTATION_UNIT;
+    if successful {
+        reputation.decayed_successes += REPUTATION_UNIT;
+    }
+}
+
+/// Reputation score in basis points (0-10000)
+///
+/// 90% comes from the time-decayed success rate, with one neutral (50%)
+/// signal mixed in so a single result cannot set the score alone. Stake adds
+/// up to 10%, and every slash subtracts a penalty that decays with age.
+///
+/// # Arguments
+/// * `reputation` - Decayed signal evidence
+/// * `stake` - Provider's current stake
+/// * `min_stake` - Minimum stake required to submit signals
+/// * `slash_ages` - Seconds since each of the provider's slashes
+/// * `now` - Current ledger timestamp
+pub fn calculate_reputation_score(
+    reputation: &ProviderReputation,
+    stake: i128,
+    min_stake: i128,
+    slash_ages: &Vec<u64>,
+    now: u64,
+) -> u32 {
+    let elapsed = now.saturating_sub(reputation.updated_at);
+    let successes = decay(reputation.decayed_successes, elapsed);
+    let signals = decay(reputation.decayed_signals, elapsed);
+    let success_rate =
+        (successes + REPUTATION_UNIT / 2) * BASIS_POINTS_100_PERCENT / (signals + REPUTATION_UNIT);
+    let performance = success_rate * REPUTATION_PERFORMANCE_WEIGHT_BPS / BASIS_POINTS_100_PERCENT;
+
+    let full_stake = min_stake.saturating_mul(REPUTATION_FULL_STAKE_MULTIPLE);
+    let stake_bonus = if full_stake > 0 && stake > 0 {
+        (stake.saturating_mul(REPUTATION_MAX_STAKE_BONUS) / full_stake)
+            .min(REPUTATION_MAX_STAKE_BONUS)
+    } else {
+        0
+    };
+
+    let mut penalty: i128 = 0;
+    for age in slash_ages.iter() {
+        penalty += decay(REPUTATION_SLASH_PENALTY, age);
+    }
+
+    (performance + stake_bonus - penalty).clamp(0, BASIS_POINTS_100_PERCENT) as u32
+}
+
 /// Check if a status change should trigger provider stats update
 pub fn should_update_provider_stats(old_status: &SignalStatus, new_status: &SignalStatus) -> bool {
     old_status != new_status
@@ -241,19 +505,25 @@ mod tests {
 
     #[test]
     fn test_evaluate_status_expired_no_executions() {
+        let env = soroban_sdk::Env::default();
         let signal = Signal {
             id: 1,
-            provider: soroban_sdk::Address::generate(&soroban_sdk::Env::default()),
-            asset_pair: soroban_sdk::String::from_str(&soroban_sdk::Env::default(), "XLM/USDC"),
+            provider: soroban_sdk::Address::generate(&env),
+            asset_pair: soroban_sdk::String::from_str(&env, "XLM/USDC"),
             action: SignalAction::Buy,
             price: 100,
-            rationale: soroban_sdk::String::from_str(&soroban_sdk::Env::default(), "Test"),
+            rationale: soroban_sdk::String::from_str(&env, "Test"),
             timestamp: 1000,
             expiry: 2000,
             status: SignalStatus::Active,
             executions: 0,
+            successful_executions: 0,
             total_volume: 0,
             total_roi: 0,
+            category: crate::categories::SignalCategory::SwingTrade,
+            tags: soroban_sdk::Vec::new(&env),
+            risk_level: crate::categories::RiskLevel::Medium,
+            is_collaborative: false,
         };
 
         let status = evaluate_signal_status(&signal, 2001);
@@ -262,21 +532,94 @@ mod tests {
 
     #[test]
     fn test_get_signal_average_roi_zero_executions() {
+        let env = soroban_sdk::Env::default();
         let signal = Signal {
             id: 1,
-            provider: soroban_sdk::Address::generate(&soroban_sdk::Env::default()),
-            asset_pair: soroban_sdk::String::from_str(&soroban_sdk::Env::default(), "XLM/USDC"),
+            provider: soroban_sdk::Address::generate(&env),
+            asset_pair: soroban_sdk::String::from_str(&env, "XLM/USDC"),
             action: SignalAction::Buy,
             price: 100,
-            rationale: soroban_sdk::String::from_str(&soroban_sdk::Env::default(), "Test"),
+            rationale: soroban_sdk::String::from_str(&env, "Test"),
             timestamp: 1000,
             expiry: 2000,
             status: SignalStatus::Active,
             executions: 0,
+            successful_executions: 0,
             total_volume: 0,
             total_roi: 0,
+            category: crate::categories::SignalCategory::SwingTrade,
+            tags: soroban_sdk::Vec::new(&env),
+            risk_level: crate::categories::RiskLevel::Medium,
+            is_collaborative: false,
         };
 
         assert_eq!(get_signal_average_roi(&signal), 0);
     }
+
+    #[test]
+    fn test_reputation_decays_old_results() {
+        let env = soroban_sdk::Env::default();
+        let no_slashes = Vec::new(&env);
+        let day = 24 * 60 * 60;
+
+        assert_eq!(decay(10_000, REPUTATION_HALF_LIFE_SECONDS), 5_000);
+        assert_eq!(decay(10_000, REPUTATION_HALF_LIFE_SECONDS / 2), 7_500);
+
+        // Ten wins a year ago, five losses this month
+        let mut reputation = ProviderReputation::default();
+        for i in 0..10 {
+            update_reputation(&mut reputation, true, i * day);
+        }
+        let start = 365 * day;
+        for i in 0..5 {
+            update_reputation(&mut reputation, false, start + i * day);
+        }
+        let now = start + 5 * day;
+        let score = calculate_reputation_score(&reputation, 0, 100, &no_slashes, now);
+        assert!(score < 1_000, "stale wins still count: {score}");
+
+        // Without history a provider sits at the neutral 50% of the 90%
+        let fresh = ProviderReputation::default();
+        assert_eq!(
+            calculate_reputation_score(&fresh, 0, 100, &no_slashes, now),
+            4_500
+        );
+
+        // Stake adds up to 10%, a fresh slash costs 25%
+        assert_eq!(
+            calculate_reputation_score(&fresh, 500, 100, &no_slashes, now),
+            5_500
+        );
+        let mut slashes = Vec::new(&env);
+        slashes.push_back(0u64);
+        assert_eq!(
+            calculate_reputation_score(&fresh, 500, 100, &slashes, now),
+            3_000
+        );
+    }
+
+    #[test]
+    fn test_compound_equity() {
+        assert_eq!(compound_equity(EQUITY_START_VALUE, 500), 10_500);
+        assert_eq!(compound_equity(10_500, -1_000), 9_450);
+        assert_eq!(compound_equity(9_450, -15_000), 0);
+    }
+
+    #[test]
+    fn test_update_risk_stats_series() {
+        let mut stats = ProviderRiskStats::default();
+        for (roi, holding_time) in [(500, 60), (-300, 120), (500, 60), (-1000, 180), (800, 80)] {
+            update_risk_stats(&mut stats, roi, holding_time);
+        }
+
+        assert_eq!(stats.resolved_signals, 5);
+        assert_eq!(stats.mean_roi, 100);
+        assert_eq!(stats.variance, 436_000);
+        assert_eq!(stats.std_dev, 660);
+        // Peak 700 after the third signal, trough -300 after the fourth
+        assert_eq!(stats.max_drawdown, 1_000);
+        assert_eq!(stats.avg_holding_time, 100);
+        assert_eq!(stats.risk_adjusted_return, 1_515);
+        assert_eq!(stats.drawdown_adjusted_return, 5_000);
+    }
 }
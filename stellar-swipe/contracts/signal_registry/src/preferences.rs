@@ -0,0 +1,179 @@
+//! Per-user preference profiles.
+//!
+//! Preferences act in two ways:
+//! - Hard filters (`allows`): blocked providers, signals above the maximum
+//!   risk level and providers below the minimum success rate are left out of
+//!   the filtered list, the follow feed and the personalized feed.
+//! - Preferred categories and watched pairs (`is_preferred_category`,
+//!   `is_watched_pair`) rank signals higher in the personalized feed.
+//!
+//! Auto-copies must pass both: the auto_trade contract asks
+//! `signal_matches_preferences` / `strategy_matches_preferences` before it
+//! copies a signal or strategy for a user.
+
+use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
+use stellar_swipe_common::{bump_persistent, validate_asset_pair, TtlClass};
+
+use crate::categories::{RiskLevel, SignalCategory};
+use crate::errors::AdminError;
+use crate::strategy::StrategySignal;
+use crate::types::{ProviderPerformance, Signal};
+
+/// Cap on categories and watched pairs
+pub const MAX_PREFERENCE_ENTRIES: u32 = 10;
+pub const MAX_BLOCKED_PROVIDERS: u32 = 50;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserPreferences {
+    /// Preferred categories; empty means no preference
+    pub categories: Vec<SignalCategory>,
+    /// Signals riskier than this are hidden
+    pub max_risk_level: RiskLevel,
+    pub blocked_providers: Vec<Address>,
+    /// Watched asset pairs; empty means no preference
+    pub watched_pairs: Vec<String>,
+    /// Minimum provider success rate in basis points
+    pub min_success_rate: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum PreferenceStorageKey {
+    Preferences(Address),
+}
+
+pub fn get_preferences(env: &Env, user: &Address) -> Option<UserPreferences> {
+    env.storage()
+        .persistent()
+        .get(&PreferenceStorageKey::Preferences(user.clone()))
+}
+
+/// Store `user`'s preferences. Callers check auth.
+pub fn set_preferences(
+    env: &Env,
+    user: &Address,
+    preferences: &UserPreferences,
+) -> Result<(), AdminError> {
+    if preferences.categories.len() > MAX_PREFERENCE_ENTRIES
+        || preferences.watched_pairs.len() > MAX_PREFERENCE_ENTRIES
+        || preferences.blocked_providers.len() > MAX_BLOCKED_PROVIDERS
+        || preferences.min_success_rate > 10_000
+    {
+        return Err(AdminError::InvalidParameter);
+    }
+    for pair in preferences.watched_pairs.iter() {
+        validate_asset_pair(env, &pair).map_err(|_| AdminError::InvalidAssetPair)?;
+    }
+
+    let key = PreferenceStorageKey::Preferences(user.clone());
+    env.storage().persistent().set(&key, preferences);
+    bump_persistent(env, &key, TtlClass::Hot);
+    Ok(())
+}
+
+fn risk_rank(level: &RiskLevel) -> u32 {
+    match level {
+        RiskLevel::Low => 0,
+        RiskLevel::Medium => 1,
+        RiskLevel::High => 2,
+    }
+}
+
+/// Whether the hard filters let `signal` through
+pub fn allows(
+    prefs: &UserPreferences,
+    signal: &Signal,
+    stats: &Map<Address, ProviderPerformance>,
+) -> bool {
+    risk_rank(&signal.risk_level) <= risk_rank(&prefs.max_risk_level)
+        && allows_provider(prefs, &signal.provider, stats)
+}
+
+/// Blocked providers and the minimum success rate
+fn allows_provider(
+    prefs: &UserPreferences,
+    provider: &Address,
+    stats: &Map<Address, ProviderPerformance>,
+) -> bool {
+    if prefs.blocked_providers.contains(provider) {
+        return false;
+    }
+    if prefs.min_success_rate > 0 {
+        let success_rate = stats
+            .get(provider.clone())
+            .map(|s| s.success_rate)
+            .unwrap_or(0);
+        if success_rate < prefs.min_success_rate {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn is_preferred_category(prefs: &UserPreferences, signal: &Signal) -> bool {
+    prefs.categories.is_empty() || prefs.categories.contains(&signal.category)
+}
+
+pub fn is_watched_pair(prefs: &UserPreferences, signal: &Signal) -> bool {
+    prefs.watched_pairs.is_empty() || prefs.watched_pairs.contains(&signal.asset_pair)
+}
+
+/// Drop signals `user`'s hard filters reject. No-op without preferences.
+pub fn filter_signals(
+    env: &Env,
+    user: &Address,
+    signals: Vec<Signal>,
+    stats: &Map<Address, ProviderPerformance>,
+) -> Vec<Signal> {
+    let Some(prefs) = get_preferences(env, user) else {
+        return signals;
+    };
+    let mut allowed = Vec::new(env);
+    for signal in signals.iter() {
+        if allows(&prefs, &signal, stats) {
+            allowed.push_back(signal);
+        }
+    }
+    allowed
+}
+
+/// Whether an auto-copy for `user` should follow `signal`: it passes the
+/// hard filters and matches preferred categories and watched pairs.
+pub fn matches_for_auto_copy(
+    env: &Env,
+    user: &Address,
+    signal: &Signal,
+    stats: &Map<Address, ProviderPerformance>,
+) -> bool {
+    match get_preferences(env, user) {
+        Some(prefs) => {
+            allows(&prefs, signal, stats)
+                && is_preferred_category(&prefs, signal)
+                && is_watched_pair(&prefs, signal)
+        }
+        None => true,
+    }
+}
+
+/// Whether an auto-copy for `user` should follow `strategy`: the provider
+/// passes the filters and every leg trades a watched pair. Strategies carry
+/// no category or risk level, so those preferences do not apply.
+pub fn strategy_matches_for_auto_copy(
+    env: &Env,
+    user: &Address,
+    strategy: &StrategySignal,
+    stats: &Map<Address, ProviderPerformance>,
+) -> bool {
+    match get_preferences(env, user) {
+        Some(prefs) => {
+            allows_provider(&prefs, &strategy.provider, stats)
+                && (prefs.watched_pairs.is_empty()
+                    || strategy
+                        .legs
+                        .iter()
+                        .all(|leg| prefs.watched_pairs.contains(&leg.asset_pair)))
+        }
+        None => true,
+    }
+}
@@ -0,0 +1,205 @@
+//! Provider public profiles and verification badges.
+//!
+//! Providers manage their own profile (display name, bio, avatar hash,
+//! external links and declared strategy categories). Verification badges are
+//! stored separately and can only be granted or revoked by the admin or a
+//! moderator the admin appointed.
+//!
+//! Providers without a profile get an empty default so leaderboard entries
+//! and analytics always carry one.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
+use crate::admin;
+use crate::categories::SignalCategory;
+use crate::errors::ProfileError;
+use crate::events::{emit_provider_badge_changed, emit_provider_profile_updated};
+
+pub const MAX_DISPLAY_NAME_BYTES: u32 = 32;
+pub const MAX_BIO_BYTES: u32 = 280;
+pub const MAX_PROFILE_LINKS: u32 = 5;
+pub const MAX_LINK_BYTES: u32 = 128;
+pub const MAX_PROFILE_CATEGORIES: u32 = 7;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProviderBadge {
+    /// Identity of the provider checked by the platform
+    Verified,
+    /// Track record audited outside the contract
+    AuditedTrackRecord,
+    /// Trading on behalf of a registered firm
+    Institutional,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProviderProfile {
+    pub provider: Address,
+    pub display_name: String,
+    pub bio: String,
+    /// Hash of the avatar image stored off-chain
+    pub avatar_hash: Option<BytesN<32>>,
+    pub links: Vec<String>,
+    /// Strategy categories the provider declares to trade
+    pub categories: Vec<SignalCategory>,
+    /// Assigned by the admin or moderators, never by the provider
+    pub badges: Vec<ProviderBadge>,
+    /// 0 for providers that never set a profile
+    pub updated_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum ProfileStorageKey {
+    /// provider -> ProviderProfile (badges excluded)
+    Profile(Address),
+    /// provider -> Vec<ProviderBadge>
+    Badges(Address),
+    /// moderator -> ()
+    Moderator(Address),
+}
+
+fn get_badges(env: &Env, provider: &Address) -> Vec<ProviderBadge> {
+    env.storage()
+        .persistent()
+        .get(&ProfileStorageKey::Badges(provider.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn save_badges(env: &Env, provider: &Address, badges: &Vec<ProviderBadge>) {
+    let key = ProfileStorageKey::Badges(provider.clone());
+    env.storage().persistent().set(&key, badges);
+    bump_persistent(env, &key, TtlClass::Hot);
+}
+
+/// Profile with current badges, if the provider set one or holds badges
+pub fn get_profile(env: &Env, provider: &Address) -> Option<ProviderProfile> {
+    let stored: Option<ProviderProfile> = env
+        .storage()
+        .persistent()
+        .get(&ProfileStorageKey::Profile(provider.clone()));
+    let badges = get_badges(env, provider);
+    if stored.is_none() && badges.is_empty() {
+        return None;
+    }
+
+    let mut profile = stored.unwrap_or_else(|| empty_profile(env, provider));
+    profile.badges = badges;
+    Some(profile)
+}
+
+pub fn get_profile_or_default(env: &Env, provider: &Address) -> ProviderProfile {
+    get_profile(env, provider).unwrap_or_else(|| empty_profile(env, provider))
+}
+
+fn empty_profile(env: &Env, provider: &Address) -> ProviderProfile {
+    ProviderProfile {
+        provider: provider.clone(),
+        display_name: String::from_str(env, ""),
+        bio: String::from_str(env, ""),
+        avatar_hash: None,
+        links: Vec::new(env),
+        categories: Vec::new(env),
+        badges: Vec::new(env),
+        updated_at: 0,
+    }
+}
+
+/// Create or replace `provider`'s profile. Callers check auth.
+pub fn set_profile(
+    env: &Env,
+    provider: &Address,
+    display_name: String,
+    bio: String,
+    avatar_hash: Option<BytesN<32>>,
+    links: Vec<String>,
+    categories: Vec<SignalCategory>,
+) -> Result<(), ProfileError> {
+    if display_name.is_empty() || display_name.len() > MAX_DISPLAY_NAME_BYTES {
+        return Err(ProfileError::InvalidDisplayName);
+    }
+    if bio.len() > MAX_BIO_BYTES {
+        return Err(ProfileError::BioTooLong);
+    }
+    if links.len() > MAX_PROFILE_LINKS {
+        return Err(ProfileError::InvalidLinks);
+    }
+    for link in links.iter() {
+        if link.is_empty() || link.len() > MAX_LINK_BYTES {
+            return Err(ProfileError::InvalidLinks);
+        }
+    }
+    if categories.len() > MAX_PROFILE_CATEGORIES {
+        return Err(ProfileError::TooManyCategories);
+    }
+
+    let profile = ProviderProfile {
+        provider: provider.clone(),
+        display_name,
+        bio,
+        avatar_hash,
+        links,
+        categories,
+        badges: Vec::new(env),
+        updated_at: env.ledger().timestamp(),
+    };
+    let key = ProfileStorageKey::Profile(provider.clone());
+    env.storage().persistent().set(&key, &profile);
+    bump_persistent(env, &key, TtlClass::Hot);
+
+    emit_provider_profile_updated(env, provider.clone());
+    Ok(())
+}
+
+pub fn is_moderator(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&ProfileStorageKey::Moderator(account.clone()))
+}
+
+/// Appoint or remove a moderator. Callers check admin auth.
+pub fn set_moderator(env: &Env, moderator: &Address, enabled: bool) {
+    let key = ProfileStorageKey::Moderator(moderator.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &());
+        bump_persistent(env, &key, TtlClass::Hot);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+fn require_moderator(env: &Env, caller: &Address) -> Result<(), ProfileError> {
+    if is_moderator(env, caller) || admin::require_admin(env, caller).is_ok() {
+        Ok(())
+    } else {
+        Err(ProfileError::NotModerator)
+    }
+}
+
+/// Grant (`granted`) or revoke a badge. Admin or moderator only; callers
+/// check auth.
+pub fn set_badge(
+    env: &Env,
+    caller: &Address,
+    provider: &Address,
+    badge: ProviderBadge,
+    granted: bool,
+) -> Result<(), ProfileError> {
+    require_moderator(env, caller)?;
+
+    let mut badges = get_badges(env, provider);
+    match (badges.first_index_of(&badge), granted) {
+        (None, true) => badges.push_back(badge.clone()),
+        (Some(index), false) => {
+            badges.remove(index);
+        }
+        (Some(_), true) => return Err(ProfileError::BadgeAlreadyGranted),
+        (None, false) => return Err(ProfileError::BadgeNotFound),
+    }
+    save_badges(env, provider, &badges);
+
+    emit_provider_badge_changed(env, provider.clone(), badge, granted, caller.clone());
+    Ok(())
+}
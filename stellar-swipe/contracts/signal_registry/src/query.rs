@@ -1,59 +1,112 @@
+use crate::storage::{self, SignalIndex};
+use crate::subscriptions;
+use crate::swipes;
+use crate::targets;
 use crate::types::{Signal, SignalStatus, SignalSummary, SortOption};
 use soroban_sdk::{Address, Env, Map, Vec};
 
-const MAX_LIMIT: u32 = 50;
-const DEFAULT_LIMIT: u32 = 20;
+pub(crate) const MAX_LIMIT: u32 = 50;
+pub(crate) const DEFAULT_LIMIT: u32 = 20;
 
-/// Implement Batch Signal Querying & Feed Pagination
+/// Implement Batch Signal Querying & Feed Pagination.
+/// `offset` and `limit` select positions in the provider index when
+/// filtering, otherwise in the open status indexes, and only that window is
+/// read. The page is sorted by `sort_by`; signals in the window that are past
+/// their expiry are dropped, so a page can hold fewer than `limit` entries.
 pub fn get_active_signals(
     env: &Env,
-    signals_map: &Map<u64, Signal>,
     provider_filter: Option<Address>,
     offset: u32,
     limit: u32,
     sort_by: SortOption,
 ) -> Vec<SignalSummary> {
+    let active_signals = collect_active_signals(env, provider_filter, offset, clamp_limit(limit));
+    sort_summaries(env, active_signals, sort_by)
+}
+
+/// Active signals `user` has not swiped yet among the active index
+/// positions `[offset, offset + limit)`, newest first
+pub fn get_unswiped_signals(
+    env: &Env,
+    user: &Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<SignalSummary> {
+    let mut unswiped = Vec::new(env);
+    for signal in collect_swipeable_signals(env, offset, clamp_limit(limit)).iter() {
+        if swipes::get_swipe(env, user, signal.id).is_none() {
+            unswiped.push_back(signal);
+        }
+    }
+    sort_summaries(env, unswiped, SortOption::RecencyDesc)
+}
+
+/// Unexpired open signals at positions `[offset, offset + limit)` of the
+/// provider index when filtering, otherwise of the open status indexes
+pub(crate) fn collect_active_signals(
+    env: &Env,
+    provider_filter: Option<Address>,
+    offset: u32,
+    limit: u32,
+) -> Vec<Signal> {
     let mut active_signals = Vec::new(env);
     let current_time = env.ledger().timestamp();
 
-    // 1. Filter out expired signals and optionally filter by provider
-    for i in 0..signals_map.keys().len() {
-        if let Some(key) = signals_map.keys().get(i) {
-            if let Some(signal) = signals_map.get(key) {
-                if signal.expiry > current_time
-                    && signal.status != SignalStatus::Expired
-                    && signal.status != SignalStatus::Executed
-                {
-                    let mut include = true;
-                    if let Some(ref p) = provider_filter {
-                        if signal.provider != *p {
-                            include = false;
-                        }
-                    }
-                    if include {
-                        active_signals.push_back(signal);
-                    }
-                }
-            }
+    let candidates = match provider_filter {
+        Some(ref p) => {
+            storage::get_index_signals(env, &SignalIndex::Provider(p.clone()), offset, limit)
+        }
+        None => storage::get_open_signals(env, offset, limit),
+    };
+
+    for signal in candidates.iter() {
+        if signal.expiry > current_time && storage::is_open(&signal.status) {
+            active_signals.push_back(signal);
         }
     }
+    active_signals
+}
 
-    let total_active = active_signals.len();
+/// Unexpired signals at positions `[offset, offset + limit)` of the active
+/// index. Pending signals (unrevealed, or waiting on their trigger) stay out
+/// of the swipe queue and feed until they activate.
+pub(crate) fn collect_swipeable_signals(env: &Env, offset: u32, limit: u32) -> Vec<Signal> {
+    let mut signals = Vec::new(env);
+    let current_time = env.ledger().timestamp();
+    let index = SignalIndex::Status(SignalStatus::Active);
+    for signal in storage::get_index_signals(env, &index, offset, limit).iter() {
+        if signal.expiry > current_time && signal.status == SignalStatus::Active {
+            signals.push_back(signal);
+        }
+    }
+    signals
+}
 
-    // If offset is beyond count or no signals, return empty
-    if offset >= total_active || total_active == 0 {
-        return Vec::new(env);
+/// Page size to read: `DEFAULT_LIMIT` for 0, at most `MAX_LIMIT`
+pub(crate) fn clamp_limit(limit: u32) -> u32 {
+    if limit == 0 {
+        DEFAULT_LIMIT
+    } else {
+        limit.min(MAX_LIMIT)
     }
+}
+
+fn sort_summaries(
+    env: &Env,
+    mut active_signals: Vec<Signal>,
+    sort_by: SortOption,
+) -> Vec<SignalSummary> {
+    let total_active = active_signals.len();
 
-    // Clamp limit
-    let mut actual_limit = limit;
-    if actual_limit == 0 {
-        actual_limit = DEFAULT_LIMIT;
-    } else if actual_limit > MAX_LIMIT {
-        actual_limit = MAX_LIMIT;
+    // Right swipe counts are read once up front for interest ranking
+    let mut right_swipes: Map<u64, u32> = Map::new(env);
+    if sort_by == SortOption::SwipesDesc {
+        for signal in active_signals.iter() {
+            right_swipes.set(signal.id, swipes::get_counts(env, signal.id).right);
+        }
     }
 
-    // 2. Sort the elements
+    // Sort the page
     // We implement a simple bubble sort matching Soroban constraints
     for i in 0..total_active {
         for j in 0..(total_active - i - 1) {
@@ -62,16 +115,12 @@ pub fn get_active_signals(
 
             let should_swap = match sort_by {
                 SortOption::PerformanceDesc => {
-                    let curr_success = if curr.executions > 0 {
-                        (curr.successful_executions * 10_000) / curr.executions
-                    } else {
-                        0
-                    };
-                    let next_success = if next.executions > 0 {
-                        (next.successful_executions * 10_000) / next.executions
-                    } else {
-                        0
-                    };
+                    let curr_success = (curr.successful_executions * 10_000)
+                        .checked_div(curr.executions)
+                        .unwrap_or(0);
+                    let next_success = (next.successful_executions * 10_000)
+                        .checked_div(next.executions)
+                        .unwrap_or(0);
                     // Tie breaker: timestamp
                     if curr_success < next_success {
                         true
@@ -82,6 +131,18 @@ pub fn get_active_signals(
                     }
                 }
                 SortOption::RecencyDesc => curr.timestamp < next.timestamp,
+                SortOption::SwipesDesc => {
+                    let curr_swipes = right_swipes.get(curr.id).unwrap_or(0);
+                    let next_swipes = right_swipes.get(next.id).unwrap_or(0);
+                    // Tie breaker: timestamp
+                    if curr_swipes < next_swipes {
+                        true
+                    } else if curr_swipes == next_swipes {
+                        curr.timestamp < next.timestamp
+                    } else {
+                        false
+                    }
+                }
                 SortOption::VolumeDesc => {
                     // Tie breaker: timestamp
                     if curr.total_volume < next.total_volume {
@@ -101,29 +162,41 @@ pub fn get_active_signals(
         }
     }
 
-    // 3. Paginate
     let mut results = Vec::new(env);
-    let end = (offset + actual_limit).min(total_active);
-
-    for i in offset..end {
-        let signal = active_signals.get(i).unwrap();
-        let success_rate = if signal.executions > 0 {
-            (signal.successful_executions * 10000) / signal.executions
-        } else {
-            0
-        };
-
-        results.push_back(SignalSummary {
-            id: signal.id,
-            provider: signal.provider,
-            asset_pair: signal.asset_pair,
-            action: signal.action,
-            price: signal.price,
-            success_rate,
-            total_copies: signal.executions,
-            timestamp: signal.timestamp,
-        });
+    for signal in active_signals.iter() {
+        results.push_back(to_summary(env, signal));
     }
 
     results
 }
+
+/// Feed view of a signal
+pub(crate) fn to_summary(env: &Env, signal: Signal) -> SignalSummary {
+    let success_rate = (signal.successful_executions * 10000)
+        .checked_div(signal.executions)
+        .unwrap_or(0);
+    // Subscriber-only signals are listed without their levels
+    let subscriber_only = subscriptions::is_subscriber_only(env, signal.id);
+    let (price, targets) = if subscriber_only {
+        (0, None)
+    } else {
+        (signal.price, targets::get_targets(env, signal.id))
+    };
+    let swipe_counts = swipes::get_counts(env, signal.id);
+
+    SignalSummary {
+        id: signal.id,
+        provider: signal.provider,
+        asset_pair: signal.asset_pair,
+        action: signal.action,
+        price,
+        success_rate,
+        total_copies: signal.executions,
+        timestamp: signal.timestamp,
+        take_profit: targets.as_ref().and_then(|t| t.take_profit),
+        stop_loss: targets.and_then(|t| t.stop_loss),
+        subscriber_only,
+        right_swipes: swipe_counts.right,
+        left_swipes: swipe_counts.left,
+    }
+}
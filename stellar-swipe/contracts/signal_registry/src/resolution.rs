@@ -0,0 +1,204 @@
+//! Oracle-verified signal outcomes.
+//!
+//! Once a price oracle is configured, each signal is settled at expiry against
+//! the oracle price for its asset pair. The outcome ROI compares the signal's
+//! stated `price` with the oracle close, so it does not depend on how many
+//! users copied the signal or what prices they reported. In this mode
+//! `record_trade_execution` only records copy stats and never changes status.
+//!
+//! Without an oracle, the legacy execution-driven evaluation is used.
+//!
+//! Signals with take-profit or stop-loss targets can also be resolved before
+//! expiry, as soon as the oracle price reaches a target. Keepers calling
+//! `resolve_signal` while a signal is live settle it on the first target hit.
+//!
+//! Providers closing a signal early also get an outcome record, with reason
+//! `Closed`, so early exits count in performance stats like any other call.
+//!
+//! Oracle prices must use the same precision as signal prices.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, String};
+use stellar_swipe_common::{bump_persistent, parse_asset_pair, AssetPair, TtlClass};
+
+use crate::conditions;
+use crate::errors::ResolutionError;
+use crate::events::{emit_price_oracle_updated, emit_signal_resolved};
+use crate::performance;
+use crate::storage;
+use crate::targets::{self, TargetHit};
+use crate::types::{Signal, SignalStatus};
+
+/// Price feed interface the registry settles signals against
+#[allow(dead_code)]
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn get_price(env: Env, pair: AssetPair) -> i128;
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum ResolutionStorageKey {
+    /// Price oracle contract (instance)
+    PriceOracle,
+    /// signal_id -> SignalOutcome (persistent)
+    Outcome(u64),
+}
+
+/// What settled a signal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OutcomeReason {
+    Expiry,
+    TakeProfit,
+    StopLoss,
+    /// Closed early by the provider
+    Closed,
+}
+
+/// Oracle-verified result of a signal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignalOutcome {
+    pub signal_id: u64,
+    /// Price stated by the provider
+    pub entry_price: i128,
+    /// Oracle price when the signal was resolved, or the provider's exit
+    /// price for early closes without an oracle
+    pub close_price: i128,
+    /// ROI in basis points implied by entry and close
+    pub roi: i128,
+    pub status: SignalStatus,
+    pub reason: OutcomeReason,
+    pub resolved_at: u64,
+}
+
+pub fn get_price_oracle(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&ResolutionStorageKey::PriceOracle)
+}
+
+/// Set the price oracle. Callers must check admin rights.
+pub fn store_price_oracle(env: &Env, oracle: Address) {
+    env.storage()
+        .instance()
+        .set(&ResolutionStorageKey::PriceOracle, &oracle);
+    emit_price_oracle_updated(env, oracle);
+}
+
+/// True when signal outcomes are settled by the oracle
+pub fn is_oracle_mode(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .has(&ResolutionStorageKey::PriceOracle)
+}
+
+pub fn get_outcome(env: &Env, signal_id: u64) -> Option<SignalOutcome> {
+    env.storage()
+        .persistent()
+        .get(&ResolutionStorageKey::Outcome(signal_id))
+}
+
+/// Current oracle price for the signal's asset pair
+pub fn fetch_close_price(
+    env: &Env,
+    oracle: &Address,
+    signal: &Signal,
+) -> Result<i128, ResolutionError> {
+    fetch_pair_price(env, oracle, &signal.asset_pair)
+}
+
+/// Current oracle price for an asset pair such as "XLM/USDC"
+pub fn fetch_pair_price(
+    env: &Env,
+    oracle: &Address,
+    asset_pair: &String,
+) -> Result<i128, ResolutionError> {
+    let pair = parse_asset_pair(env, asset_pair).map_err(|_| ResolutionError::InvalidAssetPair)?;
+
+    match PriceOracleClient::new(env, oracle).try_get_price(&pair) {
+        Ok(Ok(price)) if price > 0 => Ok(price),
+        _ => Err(ResolutionError::PriceUnavailable),
+    }
+}
+
+/// Settle a signal against the oracle price: at expiry, or earlier if the
+/// price has reached one of its targets.
+/// Saves the outcome and the new status, and returns the outcome together
+/// with the status the signal had before.
+pub fn resolve_signal(
+    env: &Env,
+    signal_id: u64,
+) -> Result<(SignalOutcome, SignalStatus, Signal), ResolutionError> {
+    let oracle = get_price_oracle(env).ok_or(ResolutionError::OracleNotConfigured)?;
+    let mut signal = storage::get_signal(env, signal_id).ok_or(ResolutionError::SignalNotFound)?;
+    // Conditional signals that never activated have no outcome
+    if conditions::is_waiting(env, signal_id) {
+        return Err(ResolutionError::NotActivated);
+    }
+
+    let now = env.ledger().timestamp();
+    let live = now < signal.expiry;
+    let targets = targets::get_targets(env, signal_id);
+    if live && targets.is_none() {
+        return Err(ResolutionError::NotExpired);
+    }
+
+    // Signals settled before the oracle was configured keep their status
+    let old_status = signal.status.clone();
+    if get_outcome(env, signal_id).is_some()
+        || matches!(
+            old_status,
+            SignalStatus::Successful
+                | SignalStatus::Failed
+                | SignalStatus::Executed
+                | SignalStatus::Cancelled
+        )
+    {
+        return Err(ResolutionError::AlreadyResolved);
+    }
+
+    let close_price = fetch_close_price(env, &oracle, &signal)?;
+    let roi = performance::calculate_roi(signal.price, close_price, &signal.action);
+    let (status, reason) = match targets.and_then(|t| t.hit(&signal.action, close_price)) {
+        Some(TargetHit::TakeProfit) => (SignalStatus::Successful, OutcomeReason::TakeProfit),
+        Some(TargetHit::StopLoss) => (SignalStatus::Failed, OutcomeReason::StopLoss),
+        None if live => return Err(ResolutionError::NotExpired),
+        None => (
+            performance::evaluate_outcome_status(&signal.action, roi),
+            OutcomeReason::Expiry,
+        ),
+    };
+
+    let outcome = store_outcome(env, &mut signal, close_price, roi, status, reason);
+    emit_signal_resolved(env, signal_id, signal.provider.clone(), close_price, roi);
+    Ok((outcome, old_status, signal))
+}
+
+/// Save the outcome of a signal and set its status to the outcome status
+pub fn store_outcome(
+    env: &Env,
+    signal: &mut Signal,
+    close_price: i128,
+    roi: i128,
+    status: SignalStatus,
+    reason: OutcomeReason,
+) -> SignalOutcome {
+    let outcome = SignalOutcome {
+        signal_id: signal.id,
+        entry_price: signal.price,
+        close_price,
+        roi,
+        status: status.clone(),
+        reason,
+        resolved_at: env.ledger().timestamp(),
+    };
+
+    let key = ResolutionStorageKey::Outcome(signal.id);
+    env.storage().persistent().set(&key, &outcome);
+    bump_persistent(env, &key, TtlClass::History);
+
+    signal.status = status;
+    storage::save_signal(env, signal);
+    outcome
+}
@@ -2,9 +2,12 @@
 //!
 //! Store follows: (user, provider) -> bool
 //! Store follower count per provider for leaderboard/stats.
+//! Follow and unfollow also record the provider's follower count for the
+//! current day, so growth can be measured against past days.
 //! Gas: O(1) follow/unfollow, O(n) get_followed_providers where n = followed count.
 
-use soroban_sdk::{contracttype, Address, Env, Vec};
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
 
 use crate::errors::SocialError;
 use crate::events;
@@ -18,6 +21,22 @@ pub enum SocialDataKey {
     UserFollowedList(Address),
     /// provider -> u32 follower count
     FollowerCount(Address),
+    /// provider -> Map<day, u32> follower count at the end of each day it changed
+    FollowerHistory(Address),
+}
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Days of follower history kept and returned per provider
+pub const MAX_FOLLOWER_HISTORY_DAYS: u32 = 365;
+
+/// Follower count of a provider at the end of a day
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FollowerSnapshot {
+    /// Days since the Unix epoch
+    pub day: u64,
+    pub followers: u32,
 }
 
 /// Check if user follows provider
@@ -70,10 +89,12 @@ pub fn follow_provider(env: &Env, user: Address, provider: Address) -> Result<()
     );
 
     // Increment follower count
-    let count = get_follower_count(env, &provider) + 1;
+    let previous = get_follower_count(env, &provider);
+    let count = previous + 1;
     env.storage()
         .instance()
         .set(&SocialDataKey::FollowerCount(provider.clone()), &count);
+    record_follower_snapshot(env, &provider, previous, count);
 
     events::emit_follow_gained(env, user, provider.clone(), count);
 
@@ -107,7 +128,8 @@ pub fn unfollow_provider(env: &Env, user: Address, provider: Address) -> Result<
         .remove(&SocialDataKey::Follow(user.clone(), provider.clone()));
 
     // Decrement follower count
-    let count = get_follower_count(env, &provider).saturating_sub(1);
+    let previous = get_follower_count(env, &provider);
+    let count = previous.saturating_sub(1);
     if count == 0 {
         env.storage()
             .instance()
@@ -117,8 +139,102 @@ pub fn unfollow_provider(env: &Env, user: Address, provider: Address) -> Result<
             .instance()
             .set(&SocialDataKey::FollowerCount(provider.clone()), &count);
     }
+    record_follower_snapshot(env, &provider, previous, count);
 
     events::emit_follow_lost(env, user, provider.clone(), count);
 
     Ok(())
 }
+
+fn get_follower_snapshots(env: &Env, provider: &Address) -> Map<u64, u32> {
+    env.storage()
+        .persistent()
+        .get(&SocialDataKey::FollowerHistory(provider.clone()))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Store today's follower count. The first snapshot of a provider followed
+/// before history was kept records the earlier count at the start of the
+/// retention window. Snapshots older than the window are dropped, except the
+/// newest of them, which moves to the window start so its count still carries.
+fn record_follower_snapshot(env: &Env, provider: &Address, previous: u32, count: u32) {
+    let today = env.ledger().timestamp() / SECONDS_PER_DAY;
+    let oldest_kept = today.saturating_sub(MAX_FOLLOWER_HISTORY_DAYS as u64);
+    let mut snapshots = get_follower_snapshots(env, provider);
+    if snapshots.is_empty() && previous > 0 && oldest_kept < today {
+        snapshots.set(oldest_kept, previous);
+    }
+    snapshots.set(today, count);
+
+    let mut carried = None;
+    for (day, followers) in snapshots.iter() {
+        if day >= oldest_kept {
+            break;
+        }
+        snapshots.remove(day);
+        carried = Some(followers);
+    }
+    if let Some(followers) = carried {
+        if !snapshots.contains_key(oldest_kept) {
+            snapshots.set(oldest_kept, followers);
+        }
+    }
+
+    let key = SocialDataKey::FollowerHistory(provider.clone());
+    env.storage().persistent().set(&key, &snapshots);
+    bump_persistent(env, &key, TtlClass::History);
+}
+
+/// Follower count at the end of each of the last `days` days, oldest first,
+/// ending today. Days without a follow or unfollow carry the previous count.
+pub fn get_follower_history(env: &Env, provider: &Address, days: u32) -> Vec<FollowerSnapshot> {
+    let days = days.min(MAX_FOLLOWER_HISTORY_DAYS) as u64;
+    let mut history = Vec::new(env);
+    if days == 0 {
+        return history;
+    }
+
+    let today = env.ledger().timestamp() / SECONDS_PER_DAY;
+    let first_day = today.saturating_sub(days - 1);
+    let snapshots = get_follower_snapshots(env, provider);
+    if snapshots.is_empty() {
+        // Never changed since history was kept
+        let followers = get_follower_count(env, provider);
+        for day in first_day..=today {
+            history.push_back(FollowerSnapshot { day, followers });
+        }
+        return history;
+    }
+
+    // Count carried into the window from before it
+    let mut followers = 0;
+    for (day, count) in snapshots.iter() {
+        if day > first_day {
+            break;
+        }
+        followers = count;
+    }
+    for day in first_day..=today {
+        if let Some(count) = snapshots.get(day) {
+            followers = count;
+        }
+        history.push_back(FollowerSnapshot { day, followers });
+    }
+    history
+}
+
+/// Follower growth over the last `window_days` days in basis points
+/// (10000 = doubled). Growth from zero followers counts every new follower
+/// as 100%.
+pub fn calculate_follower_growth(env: &Env, provider: &Address, window_days: u32) -> i128 {
+    let window_days = window_days.clamp(1, MAX_FOLLOWER_HISTORY_DAYS - 1);
+    let history = get_follower_history(env, provider, window_days + 1);
+    let start = history.first().map(|s| s.followers).unwrap_or(0) as i128;
+    let end = history.last().map(|s| s.followers).unwrap_or(0) as i128;
+
+    if start == 0 {
+        end * 10_000
+    } else {
+        (end - start) * 10_000 / start
+    }
+}
@@ -1,6 +1,11 @@
 #![allow(dead_code)]
 
-use soroban_sdk::{contracttype, Address, Env, Map};
+use soroban_sdk::{contracttype, token, Address, Env, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
+use crate::errors::AdminError;
+use crate::events::{emit_provider_slashed, emit_stake_withdrawn};
+use crate::fees;
 
 pub const DEFAULT_MINIMUM_STAKE: i128 = 100_000_000; // 100 XLM
 pub const UNSTAKE_LOCK_PERIOD: u64 = 7 * 24 * 60 * 60; // 7 days in seconds
@@ -8,11 +13,44 @@ pub const UNSTAKE_LOCK_PERIOD: u64 = 7 * 24 * 60 * 60; // 7 days in seconds
 #[contracttype]
 #[derive(Clone)]
 pub struct StakeInfo {
+    /// Token the stake is held in, fixed while any stake remains
+    pub token: Address,
     pub amount: i128,
     pub last_signal_time: u64,
     pub locked_until: u64,
 }
 
+impl StakeInfo {
+    /// Empty stake in `token`
+    pub fn new(token: Address) -> Self {
+        StakeInfo {
+            token,
+            amount: 0,
+            last_signal_time: 0,
+            locked_until: 0,
+        }
+    }
+}
+
+/// A stake reduction applied to a provider for misbehaviour
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlashRecord {
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum StakeStorageKey {
+    /// provider -> StakeInfo (persistent)
+    Stake(Address),
+    /// provider -> Vec<SlashRecord>, oldest first
+    Slashes(Address),
+    /// Token contract new stakes are posted in
+    StakeToken,
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ContractError {
     InvalidStakeAmount,
@@ -22,42 +60,23 @@ pub enum ContractError {
     BelowMinimumStake,
 }
 
-/// Stake XLM for a provider
-pub fn stake(
-    _env: &Env,
-    storage: &mut Map<Address, StakeInfo>,
-    provider: &Address,
-    amount: i128,
-) -> Result<(), ContractError> {
+/// Add `amount` to a stake. The total must reach the minimum stake.
+pub fn stake(info: &mut StakeInfo, amount: i128) -> Result<(), ContractError> {
     if amount <= 0 {
         return Err(ContractError::InvalidStakeAmount);
     }
 
-    let mut info = storage.get(provider.clone()).unwrap_or(StakeInfo {
-        amount: 0,
-        last_signal_time: 0,
-        locked_until: 0,
-    });
-
-    info.amount += amount;
-
-    if info.amount < DEFAULT_MINIMUM_STAKE {
+    let total = info.amount + amount;
+    if total < DEFAULT_MINIMUM_STAKE {
         return Err(ContractError::BelowMinimumStake);
     }
 
-    storage.set(provider.clone(), info);
+    info.amount = total;
     Ok(())
 }
 
-/// Unstake XLM (only after lock period)
-pub fn unstake(
-    env: &Env,
-    storage: &mut Map<Address, StakeInfo>,
-    provider: &Address,
-) -> Result<i128, ContractError> {
-    let mut info = storage
-        .get(provider.clone())
-        .ok_or(ContractError::NoStakeFound)?;
+/// Take the whole stake out (only after lock period)
+pub fn unstake(env: &Env, info: &mut StakeInfo) -> Result<i128, ContractError> {
     let now = env.ledger().timestamp();
 
     if now < info.locked_until {
@@ -70,38 +89,20 @@ pub fn unstake(
 
     let amount = info.amount;
     info.amount = 0;
-    storage.set(provider.clone(), info);
-
     Ok(amount)
 }
 
 /// Record that a signal was submitted
 /// Updates last_signal_time and locks stake for UNSTAKE_LOCK_PERIOD
-pub fn record_signal(
-    env: &Env,
-    storage: &mut Map<Address, StakeInfo>,
-    provider: &Address,
-) -> Result<(), ContractError> {
-    let mut info = storage
-        .get(provider.clone())
-        .ok_or(ContractError::NoStakeFound)?;
+pub fn record_signal(env: &Env, info: &mut StakeInfo) {
     let now = env.ledger().timestamp();
-
     info.last_signal_time = now;
     info.locked_until = now + UNSTAKE_LOCK_PERIOD;
-
-    storage.set(provider.clone(), info);
-    Ok(())
 }
 
-/// Check if a provider can submit a signal
-pub fn can_submit_signal(
-    storage: &Map<Address, StakeInfo>,
-    provider: &Address,
-) -> Result<(), ContractError> {
-    let info = storage
-        .get(provider.clone())
-        .ok_or(ContractError::NoStakeFound)?;
+/// Check if a stake allows submitting signals
+pub fn can_submit_signal(info: Option<&StakeInfo>) -> Result<(), ContractError> {
+    let info = info.ok_or(ContractError::NoStakeFound)?;
 
     if info.amount < DEFAULT_MINIMUM_STAKE {
         return Err(ContractError::BelowMinimumStake);
@@ -110,85 +111,216 @@ pub fn can_submit_signal(
     Ok(())
 }
 
+pub fn get_stake(env: &Env, provider: &Address) -> Option<StakeInfo> {
+    let key = StakeStorageKey::Stake(provider.clone());
+    let info = env.storage().persistent().get(&key)?;
+    bump_persistent(env, &key, TtlClass::Hot);
+    Some(info)
+}
+
+fn save_stake(env: &Env, provider: &Address, info: &StakeInfo) {
+    let key = StakeStorageKey::Stake(provider.clone());
+    env.storage().persistent().set(&key, info);
+    bump_persistent(env, &key, TtlClass::Hot);
+}
+
+pub fn set_stake_token(env: &Env, token: Address) {
+    env.storage()
+        .instance()
+        .set(&StakeStorageKey::StakeToken, &token);
+}
+
+pub fn get_stake_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&StakeStorageKey::StakeToken)
+}
+
+/// Current stake of a provider, 0 if none
+pub fn get_stake_amount(env: &Env, provider: &Address) -> i128 {
+    get_stake(env, provider)
+        .map(|info| info.amount)
+        .unwrap_or(0)
+}
+
+/// Pull `amount` from the provider into their stake. An empty stake takes the
+/// current stake token; otherwise the token the stake is held in is used.
+pub fn deposit(env: &Env, provider: &Address, amount: i128) -> Result<(), AdminError> {
+    let mut info = match get_stake(env, provider) {
+        Some(info) if info.amount > 0 => info,
+        existing => {
+            let token = get_stake_token(env).ok_or(AdminError::NotInitialized)?;
+            let mut info = existing.unwrap_or_else(|| StakeInfo::new(token.clone()));
+            info.token = token;
+            info
+        }
+    };
+    stake(&mut info, amount).map_err(|_| AdminError::InvalidParameter)?;
+
+    token::Client::new(env, &info.token).transfer(
+        provider,
+        env.current_contract_address(),
+        &amount,
+    );
+    save_stake(env, provider, &info);
+    Ok(())
+}
+
+/// Lock the provider's stake for `UNSTAKE_LOCK_PERIOD` after a signal.
+/// No-op for providers without a stake.
+pub fn lock_for_signal(env: &Env, provider: &Address) {
+    if let Some(mut info) = get_stake(env, provider) {
+        record_signal(env, &mut info);
+        save_stake(env, provider, &info);
+    }
+}
+
+/// Return the provider's whole stake once `UNSTAKE_LOCK_PERIOD` has passed
+/// since their last signal. Returns the amount paid out.
+pub fn withdraw(env: &Env, provider: &Address) -> Result<i128, AdminError> {
+    let mut info = get_stake(env, provider).ok_or(AdminError::NoStakeFound)?;
+    let amount = unstake(env, &mut info).map_err(|e| match e {
+        ContractError::StakeLocked => AdminError::StakeLocked,
+        _ => AdminError::NoStakeFound,
+    })?;
+    save_stake(env, provider, &info);
+
+    token::Client::new(env, &info.token).transfer(
+        &env.current_contract_address(),
+        provider,
+        &amount,
+    );
+    emit_stake_withdrawn(env, provider.clone(), amount);
+    Ok(amount)
+}
+
+pub fn get_slash_history(env: &Env, provider: &Address) -> Vec<SlashRecord> {
+    env.storage()
+        .persistent()
+        .get(&StakeStorageKey::Slashes(provider.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Take up to `amount` from the provider's stake and record the slash.
+/// Returns the amount taken and the token it is held in. Callers check
+/// admin auth.
+pub fn slash(
+    env: &Env,
+    provider: &Address,
+    amount: i128,
+) -> Result<(i128, Address), ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidStakeAmount);
+    }
+
+    let mut info = get_stake(env, provider).ok_or(ContractError::NoStakeFound)?;
+    let slashed = amount.min(info.amount);
+    info.amount -= slashed;
+    save_stake(env, provider, &info);
+
+    let mut history = get_slash_history(env, provider);
+    history.push_back(SlashRecord {
+        amount: slashed,
+        timestamp: env.ledger().timestamp(),
+    });
+    let key = StakeStorageKey::Slashes(provider.clone());
+    env.storage().persistent().set(&key, &history);
+    bump_persistent(env, &key, TtlClass::History);
+
+    emit_provider_slashed(env, provider.clone(), slashed);
+    Ok((slashed, info.token))
+}
+
+/// Slash the provider and move the slashed tokens from the contract to the
+/// platform treasury, in the token the stake is held in. Callers check
+/// admin auth.
+pub(crate) fn apply_slash(env: &Env, provider: &Address, amount: i128) -> Result<i128, AdminError> {
+    let treasury = fees::get_platform_treasury(env).ok_or(AdminError::NotInitialized)?;
+
+    let (slashed, stake_token) =
+        slash(env, provider, amount).map_err(|_| AdminError::InvalidParameter)?;
+    if slashed > 0 {
+        token::Client::new(env, &stake_token).transfer(
+            &env.current_contract_address(),
+            &treasury,
+            &slashed,
+        );
+    }
+    Ok(slashed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use soroban_sdk::testutils::Ledger;
-    use soroban_sdk::{testutils::Address as TestAddress, Address, Env, Map};
+    use soroban_sdk::{testutils::Address as TestAddress, Address, Env};
 
     fn setup_env() -> Env {
         Env::default()
     }
 
-    fn sample_provider(env: &Env) -> Address {
-        <Address as TestAddress>::generate(env)
+    fn empty_stake(env: &Env) -> StakeInfo {
+        StakeInfo::new(<Address as TestAddress>::generate(env))
     }
 
     #[test]
     fn test_stake_accumulation_and_minimum() {
         let env = setup_env();
-        let mut storage: Map<Address, StakeInfo> = Map::new(&env);
-        let provider = sample_provider(&env);
+        let mut info = empty_stake(&env);
 
         // First stake below minimum fails
         assert_eq!(
-            stake(&env, &mut storage, &provider, 50_000_000),
+            stake(&mut info, 50_000_000),
             Err(ContractError::BelowMinimumStake)
         );
+        assert_eq!(info.amount, 0);
 
         // Stake meeting minimum succeeds
-        assert!(stake(&env, &mut storage, &provider, 100_000_000).is_ok());
-        let info = storage.get(provider.clone()).unwrap();
+        assert!(stake(&mut info, 100_000_000).is_ok());
         assert_eq!(info.amount, 100_000_000);
 
         // Additional stake accumulates
-        assert!(stake(&env, &mut storage, &provider, 50_000_000).is_ok());
-        let info = storage.get(provider.clone()).unwrap();
+        assert!(stake(&mut info, 50_000_000).is_ok());
         assert_eq!(info.amount, 150_000_000);
     }
 
     #[test]
     fn test_unstake_before_and_after_lock() {
         let env = setup_env();
-        let mut storage: Map<Address, StakeInfo> = Map::new(&env);
-        let provider = sample_provider(&env);
+        let mut info = empty_stake(&env);
 
-        stake(&env, &mut storage, &provider, 100_000_000).unwrap();
+        stake(&mut info, 100_000_000).unwrap();
 
-        // Locked because no signal yet (locked_until = 0, so actually allowed)
-        let unstake_result = unstake(&env, &mut storage, &provider);
-        assert_eq!(unstake_result.unwrap(), 100_000_000);
+        // No signal yet (locked_until = 0), so unstaking is allowed
+        assert_eq!(unstake(&env, &mut info).unwrap(), 100_000_000);
+        assert_eq!(
+            unstake(&env, &mut info),
+            Err(ContractError::InsufficientStake)
+        );
 
         // Re-stake and simulate signal submission
-        stake(&env, &mut storage, &provider, 100_000_000).unwrap();
-        record_signal(&env, &mut storage, &provider).unwrap();
+        stake(&mut info, 100_000_000).unwrap();
+        record_signal(&env, &mut info);
 
         // Attempt unstake immediately should fail
-        assert_eq!(
-            unstake(&env, &mut storage, &provider),
-            Err(ContractError::StakeLocked)
-        );
+        assert_eq!(unstake(&env, &mut info), Err(ContractError::StakeLocked));
 
         // Move timestamp beyond lock period
         env.ledger()
             .set_timestamp(env.ledger().timestamp() + UNSTAKE_LOCK_PERIOD + 1);
 
         // Now unstake should succeed
-        let amount = unstake(&env, &mut storage, &provider).unwrap();
-        assert_eq!(amount, 100_000_000);
+        assert_eq!(unstake(&env, &mut info).unwrap(), 100_000_000);
+        assert_eq!(info.amount, 0);
     }
 
     #[test]
     fn test_record_signal_updates_lock() {
         let env = setup_env();
-        let mut storage: Map<Address, StakeInfo> = Map::new(&env);
-        let provider = sample_provider(&env);
+        let mut info = empty_stake(&env);
 
-        stake(&env, &mut storage, &provider, 100_000_000).unwrap();
+        stake(&mut info, 100_000_000).unwrap();
         let before = env.ledger().timestamp();
 
-        record_signal(&env, &mut storage, &provider).unwrap();
-        let info = storage.get(provider.clone()).unwrap();
+        record_signal(&env, &mut info);
 
         assert_eq!(info.last_signal_time, before);
         assert_eq!(info.locked_until, before + UNSTAKE_LOCK_PERIOD);
@@ -197,16 +329,16 @@ mod tests {
     #[test]
     fn test_can_submit_signal() {
         let env = setup_env();
-        let mut storage: Map<Address, StakeInfo> = Map::new(&env);
-        let provider = sample_provider(&env);
+        let mut info = empty_stake(&env);
 
         // No stake yet
+        assert_eq!(can_submit_signal(None), Err(ContractError::NoStakeFound));
         assert_eq!(
-            can_submit_signal(&storage, &provider),
-            Err(ContractError::NoStakeFound)
+            can_submit_signal(Some(&info)),
+            Err(ContractError::BelowMinimumStake)
         );
 
-        stake(&env, &mut storage, &provider, 100_000_000).unwrap();
-        assert!(can_submit_signal(&storage, &provider).is_ok());
+        stake(&mut info, 100_000_000).unwrap();
+        assert!(can_submit_signal(Some(&info)).is_ok());
     }
 }
@@ -0,0 +1,398 @@
+//! Per-signal persistent storage with paged secondary indexes.
+//!
+//! Each signal is stored under its own `Signal(id)` key, so reads and writes
+//! only touch the entries they need instead of one ever-growing map.
+//!
+//! Secondary indexes (provider, asset pair, status, category) are lists of
+//! signal ids split into pages of `INDEX_PAGE_SIZE`. Ids are appended to the
+//! last page; removal swaps the last id into the freed slot, so index order is
+//! insertion order until an entry is removed. Positions are not stored, which
+//! keeps each index update to two ledger entries (length + page).
+//!
+//! Only open signals are listed under the provider, asset pair and category
+//! indexes. A signal leaves them when it reaches a terminal status, and its
+//! provider analytics are folded into running totals at that point, so an
+//! index walk never reaches an entry that is left to expire.
+//!
+//! # TTL
+//! Open signals and index entries are hot and bumped on every read and write.
+//! Signals in a terminal status get a single history-class extension when
+//! they are written and are not bumped on read. They stay listed only under
+//! their status index until they are archived and left to expire.
+//!
+//! # Gas Costs
+//! - save_signal: 1 write per index on create, 2 index updates on status
+//!   change, 3 more when the signal closes, and 1 to the analytics day
+//!   bucket when the signal takes effect
+//! - index_remove: scans pages until the id is found
+//! - get_index_ids: reads only the pages covering `[offset, offset + limit)`
+//! - get_index_signals / get_open_signals: the pages above plus one read per
+//!   signal in the window
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use stellar_swipe_common::{bump_instance, bump_persistent, TtlClass};
+
+use crate::analytics;
+use crate::breakdown;
+use crate::categories::SignalCategory;
+use crate::types::{Signal, SignalStatus};
+
+/// Number of signal ids stored per index page
+pub const INDEX_PAGE_SIZE: u32 = 50;
+
+/// Statuses a signal can hold before it reaches a terminal status.
+/// Resolved, expired, executed and cancelled signals are read from their own
+/// status indexes.
+pub const OPEN_STATUSES: [SignalStatus; 2] = [SignalStatus::Pending, SignalStatus::Active];
+
+/// Secondary index a signal id can be listed under
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignalIndex {
+    Provider(Address),
+    AssetPair(String),
+    Status(SignalStatus),
+    Category(SignalCategory),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum SignalStorageKey {
+    /// signal_id -> Signal
+    Signal(u64),
+    /// index -> number of ids in the index
+    IndexLen(SignalIndex),
+    /// (index, page) -> Vec<u64> of up to INDEX_PAGE_SIZE ids
+    IndexPage(SignalIndex, u32),
+}
+
+/// Entry that can be passed to the `bump_ttl` maintenance entrypoint
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TtlKey {
+    /// Contract instance storage
+    Instance,
+    /// A single signal (expired signals are skipped)
+    Signal(u64),
+    /// An index's length entry and all of its pages
+    Index(SignalIndex),
+    /// A provider's running analytics and breakdown totals
+    Provider(Address),
+}
+
+/// Whether a signal with this status can still be copied, resolved or expire
+pub fn is_open(status: &SignalStatus) -> bool {
+    OPEN_STATUSES.contains(status)
+}
+
+/// TTL class for a signal entry, by status
+fn signal_ttl_class(signal: &Signal) -> TtlClass {
+    if is_open(&signal.status) {
+        TtlClass::Hot
+    } else {
+        TtlClass::History
+    }
+}
+
+/// Get a signal by id. Open signals have their TTL bumped.
+pub fn get_signal(env: &Env, signal_id: u64) -> Option<Signal> {
+    let key = SignalStorageKey::Signal(signal_id);
+    let signal: Signal = env.storage().persistent().get(&key)?;
+    if signal_ttl_class(&signal) == TtlClass::Hot {
+        bump_persistent(env, &key, TtlClass::Hot);
+    }
+    Some(signal)
+}
+
+/// Insert or update a signal, keeping the secondary indexes in sync
+pub fn save_signal(env: &Env, signal: &Signal) {
+    let key = SignalStorageKey::Signal(signal.id);
+    let previous: Option<Signal> = env.storage().persistent().get(&key);
+
+    match previous {
+        Some(old) => {
+            if old.status != signal.status {
+                index_remove(env, &SignalIndex::Status(old.status.clone()), signal.id);
+                index_add(env, &SignalIndex::Status(signal.status.clone()), signal.id);
+
+                if signal.status == SignalStatus::Pending {
+                    analytics::remove_activity(env, &old);
+                } else if old.status == SignalStatus::Pending
+                    && signal.status == SignalStatus::Active
+                {
+                    analytics::record_activity(env, signal);
+                }
+            }
+            if is_open(&old.status) && !is_open(&signal.status) {
+                for index in listing_indexes(signal).iter() {
+                    index_remove(env, &index, signal.id);
+                }
+                analytics::record_closed_signal(env, signal);
+            }
+        }
+        None => {
+            index_add(env, &SignalIndex::Status(signal.status.clone()), signal.id);
+            if signal.status == SignalStatus::Active {
+                analytics::record_activity(env, signal);
+            }
+            if is_open(&signal.status) {
+                for index in listing_indexes(signal).iter() {
+                    index_add(env, &index, signal.id);
+                }
+            }
+        }
+    }
+
+    env.storage().persistent().set(&key, signal);
+    bump_persistent(env, &key, signal_ttl_class(signal));
+}
+
+/// Drop a signal from every index it is still listed under. The entry itself
+/// is kept but no longer bumped, so it expires once its remaining TTL runs
+/// out. Returns false if the signal did not exist.
+pub fn archive_signal(env: &Env, signal_id: u64) -> bool {
+    let key = SignalStorageKey::Signal(signal_id);
+    let signal: Signal = match env.storage().persistent().get(&key) {
+        Some(signal) => signal,
+        None => return false,
+    };
+
+    index_remove(env, &SignalIndex::Status(signal.status.clone()), signal_id);
+    if is_open(&signal.status) {
+        for index in listing_indexes(&signal).iter() {
+            index_remove(env, &index, signal_id);
+        }
+    }
+    true
+}
+
+/// Extend the TTL of the given entries. Missing entries and expired signals
+/// are skipped. Returns the number of ledger entries bumped.
+pub fn bump_ttl(env: &Env, keys: &Vec<TtlKey>) -> u32 {
+    let mut bumped = 0u32;
+    for key in keys.iter() {
+        match key {
+            TtlKey::Instance => {
+                bump_instance(env);
+                bumped += 1;
+            }
+            TtlKey::Signal(signal_id) => {
+                let entry = SignalStorageKey::Signal(signal_id);
+                let signal: Option<Signal> = env.storage().persistent().get(&entry);
+                if let Some(signal) = signal {
+                    if signal.status != SignalStatus::Expired
+                        && bump_persistent(env, &entry, signal_ttl_class(&signal))
+                    {
+                        bumped += 1;
+                    }
+                }
+            }
+            TtlKey::Index(index) => {
+                let len_key = SignalStorageKey::IndexLen(index.clone());
+                let len: u32 = env.storage().persistent().get(&len_key).unwrap_or(0);
+                if bump_persistent(env, &len_key, TtlClass::Hot) {
+                    bumped += 1;
+                }
+                for page in 0..len.div_ceil(INDEX_PAGE_SIZE) {
+                    let page_key = SignalStorageKey::IndexPage(index.clone(), page);
+                    if bump_persistent(env, &page_key, TtlClass::Hot) {
+                        bumped += 1;
+                    }
+                }
+            }
+            TtlKey::Provider(provider) => {
+                if analytics::bump_provider_totals(env, &provider) {
+                    bumped += 1;
+                }
+                if breakdown::bump_provider_segments(env, &provider) {
+                    bumped += 1;
+                }
+            }
+        }
+    }
+    bumped
+}
+
+/// Indexes a signal is listed under only while it is open
+fn listing_indexes(signal: &Signal) -> Vec<SignalIndex> {
+    let env = signal.provider.env();
+    let mut indexes = Vec::new(env);
+    indexes.push_back(SignalIndex::Provider(signal.provider.clone()));
+    indexes.push_back(SignalIndex::AssetPair(signal.asset_pair.clone()));
+    indexes.push_back(SignalIndex::Category(signal.category.clone()));
+    indexes
+}
+
+/* =========================
+   INDEX PRIMITIVES
+========================== */
+
+/// Number of ids listed under an index
+pub fn index_len(env: &Env, index: &SignalIndex) -> u32 {
+    let key = SignalStorageKey::IndexLen(index.clone());
+    match env.storage().persistent().get(&key) {
+        Some(len) => {
+            bump_persistent(env, &key, TtlClass::Hot);
+            len
+        }
+        None => 0,
+    }
+}
+
+/// Get a single page of ids from an index
+pub fn get_index_page(env: &Env, index: &SignalIndex, page: u32) -> Vec<u64> {
+    let key = SignalStorageKey::IndexPage(index.clone(), page);
+    match env.storage().persistent().get(&key) {
+        Some(ids) => {
+            bump_persistent(env, &key, TtlClass::Hot);
+            ids
+        }
+        None => Vec::new(env),
+    }
+}
+
+fn set_index_page(env: &Env, index: &SignalIndex, page: u32, ids: &Vec<u64>) {
+    let key = SignalStorageKey::IndexPage(index.clone(), page);
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, ids);
+        bump_persistent(env, &key, TtlClass::Hot);
+    }
+}
+
+fn set_index_len(env: &Env, index: &SignalIndex, len: u32) {
+    let key = SignalStorageKey::IndexLen(index.clone());
+    if len == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &len);
+        bump_persistent(env, &key, TtlClass::Hot);
+    }
+}
+
+/// Get up to `limit` ids from an index starting at `offset`
+pub fn get_index_ids(env: &Env, index: &SignalIndex, offset: u32, limit: u32) -> Vec<u64> {
+    let mut result = Vec::new(env);
+    let len = index_len(env, index);
+    let end = offset.saturating_add(limit).min(len);
+    if offset >= end {
+        return result;
+    }
+
+    let mut page_no = offset / INDEX_PAGE_SIZE;
+    let mut page = get_index_page(env, index, page_no);
+    for pos in offset..end {
+        if pos / INDEX_PAGE_SIZE != page_no {
+            page_no = pos / INDEX_PAGE_SIZE;
+            page = get_index_page(env, index, page_no);
+        }
+        result.push_back(page.get(pos % INDEX_PAGE_SIZE).unwrap());
+    }
+    result
+}
+
+/// Get every id listed under an index
+pub fn get_all_index_ids(env: &Env, index: &SignalIndex) -> Vec<u64> {
+    get_index_ids(env, index, 0, index_len(env, index))
+}
+
+/// Load the signals listed at positions `[offset, offset + limit)` of an index
+pub fn get_index_signals(env: &Env, index: &SignalIndex, offset: u32, limit: u32) -> Vec<Signal> {
+    load_signals(env, &get_index_ids(env, index, offset, limit))
+}
+
+/// Load every signal listed under an index. Reads one entry per id, so only
+/// use it for indexes that stay small, such as a single provider's signals.
+pub fn get_all_index_signals(env: &Env, index: &SignalIndex) -> Vec<Signal> {
+    load_signals(env, &get_all_index_ids(env, index))
+}
+
+fn load_signals(env: &Env, ids: &Vec<u64>) -> Vec<Signal> {
+    let mut signals = Vec::new(env);
+    for signal_id in ids.iter() {
+        if let Some(signal) = get_signal(env, signal_id) {
+            signals.push_back(signal);
+        }
+    }
+    signals
+}
+
+/// Load up to `limit` pending or active signals starting at `offset`.
+/// Positions run through the pending index and then the active index.
+pub fn get_open_signals(env: &Env, offset: u32, limit: u32) -> Vec<Signal> {
+    let mut signals = Vec::new(env);
+    let mut offset = offset;
+    let mut remaining = limit;
+    for status in OPEN_STATUSES.iter() {
+        if remaining == 0 {
+            break;
+        }
+        let index = SignalIndex::Status(status.clone());
+        let len = index_len(env, &index);
+        if offset >= len {
+            offset -= len;
+            continue;
+        }
+        let ids = get_index_ids(env, &index, offset, remaining);
+        remaining -= ids.len();
+        offset = 0;
+        signals.append(&load_signals(env, &ids));
+    }
+    signals
+}
+
+/// Append an id to an index
+pub fn index_add(env: &Env, index: &SignalIndex, signal_id: u64) {
+    let len = index_len(env, index);
+    let page_no = len / INDEX_PAGE_SIZE;
+    let mut page = get_index_page(env, index, page_no);
+    page.push_back(signal_id);
+
+    set_index_page(env, index, page_no, &page);
+    set_index_len(env, index, len + 1);
+}
+
+/// Remove an id from an index by moving the last id into its slot.
+/// No-op if the id is not listed.
+pub fn index_remove(env: &Env, index: &SignalIndex, signal_id: u64) {
+    let len = index_len(env, index);
+    if len == 0 {
+        return;
+    }
+
+    let last_pos = len - 1;
+    let last_page_no = last_pos / INDEX_PAGE_SIZE;
+    let mut last_page = get_index_page(env, index, last_page_no);
+
+    // Locate the page holding the id
+    let mut found: Option<(u32, Vec<u64>, u32)> = None;
+    for page_no in 0..=last_page_no {
+        let page = if page_no == last_page_no {
+            last_page.clone()
+        } else {
+            get_index_page(env, index, page_no)
+        };
+        if let Some(slot) = page.first_index_of(signal_id) {
+            found = Some((page_no, page, slot));
+            break;
+        }
+    }
+    let (page_no, mut page, slot) = match found {
+        Some(found) => found,
+        None => return,
+    };
+
+    let last_id = last_page.pop_back().unwrap();
+    if page_no == last_page_no {
+        if slot < last_page.len() {
+            last_page.set(slot, last_id);
+        }
+    } else {
+        page.set(slot, last_id);
+        set_index_page(env, index, page_no, &page);
+    }
+
+    set_index_page(env, index, last_page_no, &last_page);
+    set_index_len(env, index, last_pos);
+}
@@ -0,0 +1,175 @@
+//! Multi-leg strategy signals.
+//!
+//! A strategy groups 2 to `MAX_STRATEGY_LEGS` legs (a pair trade such as long
+//! A / short B, or a weighted basket) under a single id copiers can follow.
+//! Leg weights are in basis points and must sum to 100%.
+//!
+//! Strategies are settled at expiry against the price oracle: every leg is
+//! priced, the weighted ROI is computed by
+//! `performance::calculate_strategy_roi` and the outcome counts in provider
+//! stats like a single signal.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use stellar_swipe_common::{bump_persistent, validate_asset_pair, TtlClass};
+
+use crate::errors::{AdminError, ResolutionError};
+use crate::events::{emit_strategy_created, emit_strategy_resolved};
+use crate::performance;
+use crate::resolution;
+use crate::templates::MAX_SIGNAL_RATIONALE_BYTES;
+use crate::types::{SignalAction, SignalStatus};
+
+pub const MIN_STRATEGY_LEGS: u32 = 2;
+pub const MAX_STRATEGY_LEGS: u32 = 5;
+/// Leg weights must add up to this (100%)
+pub const TOTAL_WEIGHT_BPS: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StrategyLeg {
+    pub asset_pair: String,
+    /// Buy (long) or Sell (short)
+    pub action: SignalAction,
+    /// Entry price for the leg
+    pub price: i128,
+    /// Share of the strategy in basis points
+    pub weight_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StrategySignal {
+    pub id: u64,
+    pub provider: Address,
+    pub rationale: String,
+    pub legs: Vec<StrategyLeg>,
+    pub timestamp: u64,
+    pub expiry: u64,
+    pub status: SignalStatus,
+    /// Combined ROI in basis points, set once resolved
+    pub roi: Option<i128>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum StrategyStorageKey {
+    /// Last strategy id issued (instance)
+    StrategyCounter,
+    /// strategy_id -> StrategySignal (persistent)
+    Strategy(u64),
+}
+
+pub fn get_strategy(env: &Env, strategy_id: u64) -> Option<StrategySignal> {
+    env.storage()
+        .persistent()
+        .get(&StrategyStorageKey::Strategy(strategy_id))
+}
+
+fn save_strategy(env: &Env, strategy: &StrategySignal) {
+    let key = StrategyStorageKey::Strategy(strategy.id);
+    env.storage().persistent().set(&key, strategy);
+    let class = if strategy.status == SignalStatus::Active {
+        TtlClass::Hot
+    } else {
+        TtlClass::History
+    };
+    bump_persistent(env, &key, class);
+}
+
+fn validate_legs(env: &Env, legs: &Vec<StrategyLeg>) -> Result<(), AdminError> {
+    if legs.len() < MIN_STRATEGY_LEGS || legs.len() > MAX_STRATEGY_LEGS {
+        return Err(AdminError::InvalidStrategy);
+    }
+
+    let mut total_weight = 0u32;
+    for leg in legs.iter() {
+        validate_asset_pair(env, &leg.asset_pair).map_err(|_| AdminError::InvalidAssetPair)?;
+        // Neutral legs have no direction to weight
+        if leg.action == SignalAction::Hold || leg.price <= 0 || leg.weight_bps == 0 {
+            return Err(AdminError::InvalidStrategy);
+        }
+        total_weight = total_weight.saturating_add(leg.weight_bps);
+    }
+
+    if total_weight != TOTAL_WEIGHT_BPS {
+        return Err(AdminError::InvalidStrategy);
+    }
+    Ok(())
+}
+
+/// Validate and store a new strategy. Callers check auth and pause state.
+pub fn create_strategy(
+    env: &Env,
+    provider: &Address,
+    rationale: String,
+    legs: Vec<StrategyLeg>,
+    expiry: u64,
+) -> Result<u64, AdminError> {
+    validate_legs(env, &legs)?;
+
+    if rationale.is_empty() || rationale.len() > MAX_SIGNAL_RATIONALE_BYTES {
+        return Err(AdminError::InvalidRationale);
+    }
+
+    let now = env.ledger().timestamp();
+    if expiry <= now || expiry > now + crate::MAX_EXPIRY_SECONDS {
+        return Err(AdminError::InvalidExpiry);
+    }
+
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&StrategyStorageKey::StrategyCounter)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&StrategyStorageKey::StrategyCounter, &id);
+
+    let leg_count = legs.len();
+    let strategy = StrategySignal {
+        id,
+        provider: provider.clone(),
+        rationale,
+        legs,
+        timestamp: now,
+        expiry,
+        status: SignalStatus::Active,
+        roi: None,
+    };
+    save_strategy(env, &strategy);
+
+    emit_strategy_created(env, id, provider.clone(), leg_count);
+    Ok(id)
+}
+
+/// Settle an expired strategy against oracle prices for every leg.
+/// Returns the updated strategy together with the status it had before.
+pub fn resolve_strategy(
+    env: &Env,
+    strategy_id: u64,
+) -> Result<(StrategySignal, SignalStatus), ResolutionError> {
+    let oracle = resolution::get_price_oracle(env).ok_or(ResolutionError::OracleNotConfigured)?;
+    let mut strategy = get_strategy(env, strategy_id).ok_or(ResolutionError::SignalNotFound)?;
+
+    if env.ledger().timestamp() < strategy.expiry {
+        return Err(ResolutionError::NotExpired);
+    }
+    if strategy.status != SignalStatus::Active {
+        return Err(ResolutionError::AlreadyResolved);
+    }
+
+    let mut exit_prices = Vec::new(env);
+    for leg in strategy.legs.iter() {
+        exit_prices.push_back(resolution::fetch_pair_price(env, &oracle, &leg.asset_pair)?);
+    }
+
+    let roi = performance::calculate_strategy_roi(&strategy.legs, &exit_prices)?;
+    let old_status = strategy.status.clone();
+    strategy.status = performance::evaluate_strategy_status(roi);
+    strategy.roi = Some(roi);
+    save_strategy(env, &strategy);
+
+    emit_strategy_resolved(env, strategy_id, strategy.provider.clone(), roi);
+    Ok((strategy, old_status))
+}
@@ -44,8 +44,9 @@ pub fn submit_signal(
     rationale: String,
 ) -> Result<u64, Error> {
     // Verify provider stake
-    can_submit_signal(provider_stakes, provider).map_err(|_| Error::NoStake)?;
-    let stake_info = provider_stakes.get(provider.clone()).unwrap();
+    let stake_info = provider_stakes.get(provider.clone());
+    can_submit_signal(stake_info.as_ref()).map_err(|_| Error::NoStake)?;
+    let stake_info = stake_info.unwrap();
     if stake_info.amount < DEFAULT_MINIMUM_STAKE {
         return Err(Error::BelowMinimumStake);
     }
@@ -122,6 +123,12 @@ mod tests {
         <Address as TestAddress>::generate(env)
     }
 
+    fn staked(env: &Env, amount: i128) -> StakeInfo {
+        let mut info = StakeInfo::new(<Address as TestAddress>::generate(env));
+        stake(&mut info, amount).unwrap();
+        info
+    }
+
     #[test]
     fn test_submit_signal_success() {
         let env = setup_env();
@@ -129,7 +136,7 @@ mod tests {
         let mut signals: Map<u64, Signal> = Map::new(&env);
         let provider = sample_provider(&env);
 
-        stake(&env, &mut stakes, &provider, DEFAULT_MINIMUM_STAKE).unwrap();
+        stakes.set(provider.clone(), staked(&env, DEFAULT_MINIMUM_STAKE));
 
         let signal_id = submit_signal(
             &env,
@@ -186,7 +193,7 @@ mod tests {
         let mut signals: Map<u64, Signal> = Map::new(&env);
         let provider = sample_provider(&env);
 
-        stake(&env, &mut stakes, &provider, DEFAULT_MINIMUM_STAKE).unwrap();
+        stakes.set(provider.clone(), staked(&env, DEFAULT_MINIMUM_STAKE));
 
         let res = submit_signal(
             &env,
@@ -209,7 +216,7 @@ mod tests {
         let mut signals: Map<u64, Signal> = Map::new(&env);
         let provider = sample_provider(&env);
 
-        stake(&env, &mut stakes, &provider, DEFAULT_MINIMUM_STAKE).unwrap();
+        stakes.set(provider.clone(), staked(&env, DEFAULT_MINIMUM_STAKE));
 
         let res = submit_signal(
             &env,
@@ -232,7 +239,7 @@ mod tests {
         let mut signals: Map<u64, Signal> = Map::new(&env);
         let provider = sample_provider(&env);
 
-        stake(&env, &mut stakes, &provider, DEFAULT_MINIMUM_STAKE).unwrap();
+        stakes.set(provider.clone(), staked(&env, DEFAULT_MINIMUM_STAKE));
 
         let _ = submit_signal(
             &env,
@@ -270,6 +277,7 @@ mod tests {
         let below_min = DEFAULT_MINIMUM_STAKE / 2;
 
         let low_stake = StakeInfo {
+            token: sample_provider(&env),
             amount: below_min,
             locked_until: 0,
             last_signal_time: 0,
@@ -297,7 +305,7 @@ mod tests {
         let mut signals: Map<u64, Signal> = Map::new(&env);
         let provider = sample_provider(&env);
 
-        stake(&env, &mut stakes, &provider, DEFAULT_MINIMUM_STAKE).unwrap();
+        stakes.set(provider.clone(), staked(&env, DEFAULT_MINIMUM_STAKE));
 
         // Missing slash
         let res = submit_signal(
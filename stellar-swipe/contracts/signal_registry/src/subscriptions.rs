@@ -0,0 +1,251 @@
+//! Provider subscriptions and subscriber-only signals.
+//!
+//! Providers define subscription plans (a price per period in a token) and
+//! can publish signals only their active subscribers may see in full.
+//! Subscribing and renewing pay through `fees::collect_subscription_payment`;
+//! a renewal extends the current period instead of restarting it.
+//!
+//! Subscriber-only signals are left out of public `Signal` queries and shown
+//! redacted in the public feed (no entry price or targets).
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
+use crate::errors::SubscriptionError;
+use crate::events::{emit_subscription_plan_created, emit_subscription_renewed};
+use crate::fees;
+use crate::storage;
+use crate::types::{Asset, Signal};
+
+pub const MAX_PLANS_PER_PROVIDER: u32 = 5;
+/// Shortest billing period a plan may use (1 day)
+pub const MIN_PLAN_PERIOD_SECONDS: u64 = 86_400;
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SubscriptionPlan {
+    pub id: u32,
+    pub provider: Address,
+    /// Token the plan is paid in
+    pub asset: Asset,
+    /// Price per period, in `asset` units
+    pub price: i128,
+    pub period_seconds: u64,
+    /// Inactive plans accept no new subscriptions or renewals
+    pub active: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Subscription {
+    pub subscriber: Address,
+    pub provider: Address,
+    /// Plan of the last payment
+    pub plan_id: u32,
+    pub started_at: u64,
+    pub expires_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum SubscriptionStorageKey {
+    /// provider -> number of plans created
+    PlanCount(Address),
+    /// (provider, plan_id) -> SubscriptionPlan
+    Plan(Address, u32),
+    /// (subscriber, provider) -> Subscription
+    Subscription(Address, Address),
+    /// signal_id -> () for subscriber-only signals
+    SubscriberOnly(u64),
+}
+
+fn get_plan_count(env: &Env, provider: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&SubscriptionStorageKey::PlanCount(provider.clone()))
+        .unwrap_or(0)
+}
+
+fn save_plan(env: &Env, plan: &SubscriptionPlan) {
+    let key = SubscriptionStorageKey::Plan(plan.provider.clone(), plan.id);
+    env.storage().persistent().set(&key, plan);
+    bump_persistent(env, &key, TtlClass::Hot);
+}
+
+pub fn get_plan(env: &Env, provider: &Address, plan_id: u32) -> Option<SubscriptionPlan> {
+    env.storage()
+        .persistent()
+        .get(&SubscriptionStorageKey::Plan(provider.clone(), plan_id))
+}
+
+pub fn get_plans(env: &Env, provider: &Address) -> Vec<SubscriptionPlan> {
+    let mut plans = Vec::new(env);
+    for plan_id in 1..=get_plan_count(env, provider) {
+        if let Some(plan) = get_plan(env, provider, plan_id) {
+            plans.push_back(plan);
+        }
+    }
+    plans
+}
+
+/// Add a plan for `provider`. Callers check auth.
+pub fn create_plan(
+    env: &Env,
+    provider: &Address,
+    asset: Asset,
+    price: i128,
+    period_seconds: u64,
+) -> Result<u32, SubscriptionError> {
+    if price <= 0 || period_seconds < MIN_PLAN_PERIOD_SECONDS {
+        return Err(SubscriptionError::InvalidPlan);
+    }
+
+    let count = get_plan_count(env, provider);
+    if count >= MAX_PLANS_PER_PROVIDER {
+        return Err(SubscriptionError::TooManyPlans);
+    }
+
+    let plan = SubscriptionPlan {
+        id: count + 1,
+        provider: provider.clone(),
+        asset,
+        price,
+        period_seconds,
+        active: true,
+    };
+    save_plan(env, &plan);
+
+    let count_key = SubscriptionStorageKey::PlanCount(provider.clone());
+    env.storage().persistent().set(&count_key, &plan.id);
+    bump_persistent(env, &count_key, TtlClass::Hot);
+
+    emit_subscription_plan_created(env, provider.clone(), plan.id, plan.price, period_seconds);
+    Ok(plan.id)
+}
+
+/// Open or close a plan to new subscriptions and renewals. Callers check auth.
+pub fn set_plan_active(
+    env: &Env,
+    provider: &Address,
+    plan_id: u32,
+    active: bool,
+) -> Result<(), SubscriptionError> {
+    let mut plan = get_plan(env, provider, plan_id).ok_or(SubscriptionError::PlanNotFound)?;
+    plan.active = active;
+    save_plan(env, &plan);
+    Ok(())
+}
+
+pub fn get_subscription(
+    env: &Env,
+    subscriber: &Address,
+    provider: &Address,
+) -> Option<Subscription> {
+    env.storage()
+        .persistent()
+        .get(&SubscriptionStorageKey::Subscription(
+            subscriber.clone(),
+            provider.clone(),
+        ))
+}
+
+/// Pay one period of `plan_id`. Extends an active subscription from its
+/// current expiry, otherwise starts a new one now. Callers check auth.
+pub fn subscribe(
+    env: &Env,
+    subscriber: &Address,
+    provider: &Address,
+    plan_id: u32,
+) -> Result<Subscription, SubscriptionError> {
+    if subscriber == provider {
+        return Err(SubscriptionError::CannotSubscribeSelf);
+    }
+
+    let plan = get_plan(env, provider, plan_id).ok_or(SubscriptionError::PlanNotFound)?;
+    if !plan.active {
+        return Err(SubscriptionError::PlanInactive);
+    }
+
+    fees::collect_subscription_payment(env, plan.asset.clone(), subscriber, provider, plan.price)
+        .map_err(|_| SubscriptionError::PaymentFailed)?;
+
+    let now = env.ledger().timestamp();
+    let subscription = match get_subscription(env, subscriber, provider) {
+        Some(current) if current.expires_at > now => Subscription {
+            plan_id,
+            expires_at: current.expires_at + plan.period_seconds,
+            ..current
+        },
+        _ => Subscription {
+            subscriber: subscriber.clone(),
+            provider: provider.clone(),
+            plan_id,
+            started_at: now,
+            expires_at: now + plan.period_seconds,
+        },
+    };
+
+    let key = SubscriptionStorageKey::Subscription(subscriber.clone(), provider.clone());
+    env.storage().persistent().set(&key, &subscription);
+    bump_persistent(env, &key, TtlClass::Hot);
+
+    emit_subscription_renewed(
+        env,
+        subscriber.clone(),
+        provider.clone(),
+        plan_id,
+        subscription.expires_at,
+    );
+    Ok(subscription)
+}
+
+pub fn is_active_subscriber(env: &Env, subscriber: &Address, provider: &Address) -> bool {
+    get_subscription(env, subscriber, provider)
+        .is_some_and(|s| s.expires_at > env.ledger().timestamp())
+}
+
+pub fn set_subscriber_only(env: &Env, signal_id: u64) {
+    let key = SubscriptionStorageKey::SubscriberOnly(signal_id);
+    env.storage().persistent().set(&key, &());
+    bump_persistent(env, &key, TtlClass::Hot);
+}
+
+pub fn is_subscriber_only(env: &Env, signal_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&SubscriptionStorageKey::SubscriberOnly(signal_id))
+}
+
+/// Whether `signal` may be shown in full publicly
+pub fn is_public(env: &Env, signal: &Signal) -> bool {
+    !is_subscriber_only(env, signal.id)
+}
+
+/// Drop subscriber-only signals from a public listing
+pub fn public_only(env: &Env, signals: Vec<Signal>) -> Vec<Signal> {
+    let mut visible = Vec::new(env);
+    for signal in signals.iter() {
+        if is_public(env, &signal) {
+            visible.push_back(signal);
+        }
+    }
+    visible
+}
+
+/// Full signal details for `viewer`: the provider or, for subscriber-only
+/// signals, an active subscriber. Callers check auth.
+pub fn get_signal_for(
+    env: &Env,
+    viewer: &Address,
+    signal_id: u64,
+) -> Result<Signal, SubscriptionError> {
+    let signal = storage::get_signal(env, signal_id).ok_or(SubscriptionError::SignalNotFound)?;
+
+    if is_subscriber_only(env, signal_id)
+        && *viewer != signal.provider
+        && !is_active_subscriber(env, viewer, &signal.provider)
+    {
+        return Err(SubscriptionError::SubscriptionRequired);
+    }
+    Ok(signal)
+}
@@ -0,0 +1,89 @@
+//! Swipe interactions.
+//!
+//! Users swipe right (interested in copying) or left (pass) on open signals.
+//! Each user decides once per signal; the decision is stored per user and
+//! tallied per signal so feeds can skip swiped signals and rank by interest.
+
+use soroban_sdk::{contracttype, Address, Env};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
+use crate::errors::SwipeError;
+use crate::events::emit_signal_swiped;
+use crate::storage;
+use crate::types::SignalStatus;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SwipeCounts {
+    pub right: u32,
+    pub left: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum SwipeStorageKey {
+    /// (user, signal_id) -> SwipeDirection
+    Swipe(Address, u64),
+    /// signal_id -> SwipeCounts
+    Counts(u64),
+}
+
+pub fn get_swipe(env: &Env, user: &Address, signal_id: u64) -> Option<SwipeDirection> {
+    env.storage()
+        .persistent()
+        .get(&SwipeStorageKey::Swipe(user.clone(), signal_id))
+}
+
+pub fn get_counts(env: &Env, signal_id: u64) -> SwipeCounts {
+    env.storage()
+        .persistent()
+        .get(&SwipeStorageKey::Counts(signal_id))
+        .unwrap_or_default()
+}
+
+/// Record `user`'s decision on an open signal. Callers check auth.
+pub fn swipe(
+    env: &Env,
+    user: &Address,
+    signal_id: u64,
+    direction: SwipeDirection,
+) -> Result<SwipeCounts, SwipeError> {
+    let signal = storage::get_signal(env, signal_id).ok_or(SwipeError::SignalNotFound)?;
+    if signal.status != SignalStatus::Active || signal.expiry <= env.ledger().timestamp() {
+        return Err(SwipeError::SignalNotOpen);
+    }
+    if signal.provider == *user {
+        return Err(SwipeError::CannotSwipeOwnSignal);
+    }
+    if get_swipe(env, user, signal_id).is_some() {
+        return Err(SwipeError::AlreadySwiped);
+    }
+
+    let swipe_key = SwipeStorageKey::Swipe(user.clone(), signal_id);
+    env.storage().persistent().set(&swipe_key, &direction);
+    bump_persistent(env, &swipe_key, TtlClass::Hot);
+
+    let mut counts = get_counts(env, signal_id);
+    match direction {
+        SwipeDirection::Right => counts.right += 1,
+        SwipeDirection::Left => counts.left += 1,
+    }
+    let counts_key = SwipeStorageKey::Counts(signal_id);
+    env.storage().persistent().set(&counts_key, &counts);
+    bump_persistent(env, &counts_key, TtlClass::Hot);
+
+    emit_signal_swiped(
+        env,
+        signal_id,
+        user.clone(),
+        direction == SwipeDirection::Right,
+    );
+    Ok(counts)
+}
@@ -0,0 +1,116 @@
+//! Take-profit and stop-loss targets for signals.
+//!
+//! Targets are optional and stored next to the signal under their own key,
+//! so signals without targets cost nothing extra. They are validated against
+//! the action direction at creation: a Buy needs `stop_loss < price <
+//! take_profit`, a Sell the reverse. Hold signals cannot have targets.
+
+use soroban_sdk::{contracttype, Env};
+use stellar_swipe_common::{bump_persistent, TtlClass};
+
+use crate::errors::AdminError;
+use crate::types::SignalAction;
+
+/// Exit levels published with a signal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignalTargets {
+    pub take_profit: Option<i128>,
+    pub stop_loss: Option<i128>,
+}
+
+/// Which target a price has reached
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TargetHit {
+    TakeProfit,
+    StopLoss,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum TargetStorageKey {
+    /// signal_id -> SignalTargets
+    Targets(u64),
+}
+
+impl SignalTargets {
+    pub fn is_empty(&self) -> bool {
+        self.take_profit.is_none() && self.stop_loss.is_none()
+    }
+
+    /// Target reached at `price`, if any
+    pub fn hit(&self, action: &SignalAction, price: i128) -> Option<TargetHit> {
+        let (profit_hit, loss_hit) = match action {
+            SignalAction::Buy => (
+                self.take_profit.is_some_and(|tp| price >= tp),
+                self.stop_loss.is_some_and(|sl| price <= sl),
+            ),
+            SignalAction::Sell => (
+                self.take_profit.is_some_and(|tp| price <= tp),
+                self.stop_loss.is_some_and(|sl| price >= sl),
+            ),
+            SignalAction::Hold => (false, false),
+        };
+
+        if profit_hit {
+            Some(TargetHit::TakeProfit)
+        } else if loss_hit {
+            Some(TargetHit::StopLoss)
+        } else {
+            None
+        }
+    }
+}
+
+/// Check targets are positive and on the right side of the entry price
+pub fn validate_targets(
+    action: &SignalAction,
+    price: i128,
+    targets: &SignalTargets,
+) -> Result<(), AdminError> {
+    if price <= 0 {
+        return Err(AdminError::InvalidTargets);
+    }
+
+    for target in [targets.take_profit, targets.stop_loss].iter().flatten() {
+        if *target <= 0 {
+            return Err(AdminError::InvalidTargets);
+        }
+    }
+
+    let valid = match action {
+        SignalAction::Buy => {
+            targets.take_profit.is_none_or(|tp| tp > price)
+                && targets.stop_loss.is_none_or(|sl| sl < price)
+        }
+        SignalAction::Sell => {
+            targets.take_profit.is_none_or(|tp| tp < price)
+                && targets.stop_loss.is_none_or(|sl| sl > price)
+        }
+        // Neutral calls have no exit levels
+        SignalAction::Hold => targets.is_empty(),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(AdminError::InvalidTargets)
+    }
+}
+
+/// Store targets for a signal. Empty targets are not stored.
+pub fn set_targets(env: &Env, signal_id: u64, targets: &SignalTargets) {
+    if targets.is_empty() {
+        return;
+    }
+    let key = TargetStorageKey::Targets(signal_id);
+    env.storage().persistent().set(&key, targets);
+    bump_persistent(env, &key, TtlClass::Hot);
+}
+
+pub fn get_targets(env: &Env, signal_id: u64) -> Option<SignalTargets> {
+    let key = TargetStorageKey::Targets(signal_id);
+    let targets = env.storage().persistent().get(&key)?;
+    bump_persistent(env, &key, TtlClass::Hot);
+    Some(targets)
+}
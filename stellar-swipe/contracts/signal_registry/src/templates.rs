@@ -168,6 +168,7 @@ pub fn parse_action(action_text: &String) -> Result<crate::types::SignalAction,
     match lower.as_str() {
         "buy" => Ok(crate::types::SignalAction::Buy),
         "sell" => Ok(crate::types::SignalAction::Sell),
+        "hold" | "neutral" => Ok(crate::types::SignalAction::Hold),
         _ => Err(TemplateError::InvalidAction),
     }
 }
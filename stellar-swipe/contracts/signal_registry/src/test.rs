@@ -60,6 +60,9 @@ fn create_and_read_signal() {
         &100_000,
         &String::from_str(&env, "Breakout confirmed"),
         &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
     );
 
     let signal = client.get_signal(&signal_id).unwrap();
@@ -89,6 +92,9 @@ fn test_invalid_asset_pair_rejected() {
         &100_000,
         &String::from_str(&env, "Test"),
         &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
     );
     assert!(result.is_err());
 
@@ -99,6 +105,9 @@ fn test_invalid_asset_pair_rejected() {
         &100_000,
         &String::from_str(&env, "Test"),
         &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
     );
     assert!(result.is_err());
 
@@ -109,6 +118,9 @@ fn test_invalid_asset_pair_rejected() {
         &100_000,
         &String::from_str(&env, "Test"),
         &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
     );
     assert!(result.is_err());
 }
@@ -138,11 +150,204 @@ fn test_custom_asset_pair_with_issuer() {
         &100_000,
         &String::from_str(&env, "Full format"),
         &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
     );
 
     assert!(signal_id > 0);
 }
 
+#[test]
+fn test_duplicate_signal_rejected_within_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    use soroban_sdk::testutils::Ledger;
+    env.ledger().set_timestamp(10_000);
+
+    let provider = Address::generate(&env);
+    let pair = String::from_str(&env, "XLM/USDC");
+    let rationale = String::from_str(&env, "Breakout confirmed");
+    let expiry = env.ledger().timestamp() + 86_400;
+
+    client.create_signal(
+        &provider,
+        &pair,
+        &SignalAction::Buy,
+        &100_000,
+        &rationale,
+        &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+
+    // Same provider, pair, action and price within the hour
+    let result = client.try_create_signal(
+        &provider,
+        &pair,
+        &SignalAction::Buy,
+        &100_000,
+        &rationale,
+        &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+    assert_eq!(result, Err(Ok(AdminError::DuplicateSignal)));
+
+    // A different price or action is not a duplicate
+    client.create_signal(
+        &provider,
+        &pair,
+        &SignalAction::Buy,
+        &101_000,
+        &rationale,
+        &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+    client.create_signal(
+        &provider,
+        &pair,
+        &SignalAction::Sell,
+        &100_000,
+        &rationale,
+        &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+
+    // Another provider can publish the same call
+    let other_provider = Address::generate(&env);
+    client.create_signal(
+        &other_provider,
+        &pair,
+        &SignalAction::Buy,
+        &100_000,
+        &rationale,
+        &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+
+    // Once the window has passed the original call can be repeated
+    env.ledger().set_timestamp(10_000 + 60 * 60);
+    let signal_id = client.create_signal(
+        &provider,
+        &pair,
+        &SignalAction::Buy,
+        &100_000,
+        &rationale,
+        &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+    assert_eq!(signal_id, 5);
+}
+
+#[test]
+fn test_invalid_expiry_returns_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    use soroban_sdk::testutils::Ledger;
+    env.ledger().set_timestamp(10_000);
+
+    let provider = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    // Expiry in the past
+    let result = client.try_create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Test"),
+        &now,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+    assert_eq!(result, Err(Ok(AdminError::InvalidExpiry)));
+
+    // Expiry beyond 30 days
+    let result = client.try_create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Test"),
+        &(now + MAX_EXPIRY_SECONDS + 1),
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+    assert_eq!(result, Err(Ok(AdminError::InvalidExpiry)));
+}
+
+#[test]
+fn test_invalid_rationale_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 60;
+
+    let result = client.try_create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, ""),
+        &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+    assert_eq!(result, Err(Ok(AdminError::InvalidRationale)));
+
+    let long_rationale = std::string::String::from("x").repeat(501);
+    let result = client.try_create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, &long_rationale),
+        &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+    assert_eq!(result, Err(Ok(AdminError::InvalidRationale)));
+}
+
 #[test]
 fn test_pause_blocks_signals() {
     let env = Env::default();
@@ -170,6 +375,9 @@ fn test_pause_blocks_signals() {
         &100_000,
         &String::from_str(&env, "Test"),
         &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
     );
 
     assert!(result.is_err());
@@ -186,6 +394,9 @@ fn test_pause_blocks_signals() {
         &100_000,
         &String::from_str(&env, "Test"),
         &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
     );
 
     assert!(signal_id > 0);
@@ -329,8 +540,14 @@ fn test_multisig_enable_and_use() {
     let returned_signers = client.get_multisig_signers();
     assert_eq!(returned_signers.len(), 3);
 
-    // Any signer should be able to pause
-    client.pause_trading(&signer1);
+    // A single signer cannot pause once the threshold is above one
+    assert_eq!(
+        client.try_pause_trading(&signer1),
+        Err(Ok(AdminError::InsufficientSignatures))
+    );
+
+    let id = client.propose_admin_action(&signer1, &AdminAction::PauseTrading);
+    client.approve_proposal(&signer2, &id);
     assert!(client.is_paused());
 }
 
@@ -360,9 +577,15 @@ fn test_multisig_add_remove_signers() {
     assert_eq!(client.get_multisig_signers().len(), 3);
 
     // Add one more, then we can remove
-    client.add_multisig_signer(&admin, &signer4);
-    client.remove_multisig_signer(&admin, &signer1);
+    let add = client.propose_admin_action(&admin, &AdminAction::AddMultisigSigner(signer4.clone()));
+    client.approve_proposal(&signer2, &add);
+    assert_eq!(client.get_multisig_signers().len(), 4);
+
+    let remove =
+        client.propose_admin_action(&admin, &AdminAction::RemoveMultisigSigner(signer1.clone()));
+    client.approve_proposal(&signer4, &remove);
     assert_eq!(client.get_multisig_signers().len(), 3);
+    assert!(!client.get_multisig_signers().contains(&signer1));
 }
 
 #[test]
@@ -412,6 +635,9 @@ fn provider_stats_initialized() {
         &200_000,
         &String::from_str(&env, "Resistance hit"),
         &expiry,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
     );
 
     let stats = client.get_provider_stats(&provider).unwrap();
@@ -525,26 +751,32 @@ fn test_get_active_signals_excludes_expired() {
     let current_time = env.ledger().timestamp();
 
     // Create 3 active signals
-    for _i in 0..3 {
+    for i in 0..3 {
         client.create_signal(
             &provider,
             &String::from_str(&env, "XLM/USDC"),
             &SignalAction::Buy,
-            &100_000,
+            &(100_000 + i as i128),
             &String::from_str(&env, "Active"),
             &(current_time + 10000),
+            &SignalCategory::SwingTrade,
+            &Vec::new(&env),
+            &RiskLevel::Medium,
         );
     }
 
     // Create 2 expired signals
-    for _i in 0..2 {
+    for i in 0..2 {
         client.create_signal(
             &provider,
             &String::from_str(&env, "XLM/BTC"),
             &SignalAction::Sell,
-            &200_000,
+            &(200_000 + i as i128),
             &String::from_str(&env, "Expired"),
             &(current_time + 10),
+            &SignalCategory::SwingTrade,
+            &Vec::new(&env),
+            &RiskLevel::Medium,
         );
     }
 
@@ -553,7 +785,7 @@ fn test_get_active_signals_excludes_expired() {
 
     // Get active signals - should only return 3 (followed_only = false)
     let any_user = Address::generate(&env);
-    let active = client.get_active_signals(&any_user, &false);
+    let active = client.get_active_signals_archived(&any_user, &false);
     assert_eq!(active.len(), 3);
 
     // All returned signals should be active
@@ -563,6 +795,45 @@ fn test_get_active_signals_excludes_expired() {
     }
 }
 
+#[test]
+fn test_get_active_signals_reads_only_requested_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    let provider = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 10_000;
+    for i in 0..150 {
+        client.create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &(100_000 + i as i128),
+            &String::from_str(&env, "Windowed"),
+            &expiry,
+            &SignalCategory::SwingTrade,
+            &Vec::new(&env),
+            &RiskLevel::Medium,
+        );
+    }
+
+    // Positions 120..140 of the active index hold signals 121..=140
+    let page = client.get_active_signals(&120, &20, &SortOption::RecencyDesc, &None);
+    assert_eq!(page.len(), 20);
+    for summary in page.iter() {
+        assert!(summary.id > 120 && summary.id <= 140);
+    }
+    // Far below one read per open signal
+    assert!(env.cost_estimate().resources().memory_read_entries < 100);
+
+    let page = client.get_active_signals(&140, &20, &SortOption::RecencyDesc, &Some(provider));
+    assert_eq!(page.len(), 10);
+    assert!(env.cost_estimate().resources().memory_read_entries < 100);
+}
+
 #[test]
 fn test_cleanup_batch_limit() {
     let env = Env::default();
@@ -583,30 +854,75 @@ fn test_cleanup_batch_limit() {
     let current_time = env.ledger().timestamp();
 
     // Create 150 expired signals
-    for _ in 0..150 {
+    for i in 0..150 {
         client.create_signal(
             &provider,
             &String::from_str(&env, "XLM/USDC"),
             &SignalAction::Buy,
-            &100_000,
+            &(100_000 + i as i128),
             &String::from_str(&env, "Test"),
             &(current_time + 10),
+            &SignalCategory::SwingTrade,
+            &Vec::new(&env),
+            &RiskLevel::Medium,
         );
     }
 
     // Move time to expire all
     env.ledger().set_timestamp(current_time + 100);
 
-    // Cleanup with limit of 50
-    let (processed, expired) = client.cleanup_expired_signals(&50);
+    // Cleanup with limit of 25
+    let (processed, expired) = client.cleanup_expired_signals(&25);
 
-    assert_eq!(processed, 50);
-    assert_eq!(expired, 50);
+    assert_eq!(processed, 25);
+    assert_eq!(expired, 25);
 
     // Run again to process more
-    let (processed2, expired2) = client.cleanup_expired_signals(&50);
-    assert_eq!(processed2, 50);
-    assert_eq!(expired2, 50);
+    let (processed2, expired2) = client.cleanup_expired_signals(&25);
+    assert_eq!(processed2, 25);
+    assert_eq!(expired2, 25);
+
+    // Larger limits are capped
+    let (processed3, _) = client.cleanup_expired_signals(&50);
+    assert_eq!(processed3, 25);
+}
+
+#[test]
+fn test_cleanup_resumes_past_live_signals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    use soroban_sdk::testutils::Ledger;
+    env.ledger().set_timestamp(10000);
+    let provider = Address::generate(&env);
+
+    // Five long-lived signals ahead of one that expires soon
+    for i in 0..6 {
+        let expiry = if i < 5 { 100_000 } else { 10_010 };
+        client.create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &(100_000 + i as i128),
+            &String::from_str(&env, "Test"),
+            &expiry,
+            &SignalCategory::SwingTrade,
+            &Vec::new(&env),
+            &RiskLevel::Medium,
+        );
+    }
+    env.ledger().set_timestamp(10_100);
+
+    assert_eq!(client.cleanup_expired_signals(&5), (5, 0));
+    assert_eq!(client.cleanup_expired_signals(&5), (1, 1));
+    assert_eq!(client.get_pending_expiry_count(), 0);
+
+    // The cursor wrapped back to the start
+    assert_eq!(client.cleanup_expired_signals(&5), (5, 0));
 }
 
 #[test]
@@ -629,14 +945,17 @@ fn test_pending_expiry_count() {
     let current_time = env.ledger().timestamp();
 
     // Create signals that will be past expiry
-    for _i in 0..4 {
+    for i in 0..4 {
         client.create_signal(
             &provider,
             &String::from_str(&env, "XLM/USDC"),
             &SignalAction::Buy,
-            &100_000,
+            &(100_000 + i as i128),
             &String::from_str(&env, "Test"),
             &(current_time + 10),
+            &SignalCategory::SwingTrade,
+            &Vec::new(&env),
+            &RiskLevel::Medium,
         );
     }
 
@@ -787,6 +1106,9 @@ fn test_feed_filtered_by_followed() {
         &100_000,
         &String::from_str(&env, "A1"),
         &(current_time + 10000),
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
     );
     client.create_signal(
         &provider_b,
@@ -795,17 +1117,20 @@ fn test_feed_filtered_by_followed() {
         &100_000,
         &String::from_str(&env, "B1"),
         &(current_time + 10000),
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
     );
 
     // User follows only provider_a
     client.follow_provider(&user, &provider_a);
 
     // All signals (followed_only = false)
-    let all_active = client.get_active_signals(&user, &false);
+    let all_active = client.get_active_signals_archived(&user, &false);
     assert_eq!(all_active.len(), 2);
 
     // Filtered feed (followed_only = true) - only provider_a
-    let followed_active = client.get_active_signals(&user, &true);
+    let followed_active = client.get_active_signals_archived(&user, &true);
     assert_eq!(followed_active.len(), 1);
     assert_eq!(followed_active.get(0).unwrap().provider, provider_a);
 }
@@ -1000,3 +1325,168 @@ fn test_share_template_and_submit_from_another_provider() {
     assert_eq!(signal.asset_pair, String::from_str(&env, "BTC/USDC"));
     assert_eq!(signal.action, SignalAction::Sell);
 }
+
+#[test]
+fn test_bump_ttl_extends_signal_entries() {
+    use soroban_sdk::testutils::{storage::Persistent as _, Ledger};
+    use stellar_swipe_common::TtlClass;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    let signal_id = client.create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Long-lived signal"),
+        &(env.ledger().timestamp() + 30 * 24 * 60 * 60),
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+
+    let key = storage::SignalStorageKey::Signal(signal_id);
+    let ttl = env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl, TtlClass::Hot.extend_to());
+
+    // Let most of the TTL run out, then bump it back up
+    let elapsed = 25 * stellar_swipe_common::DAY_IN_LEDGERS;
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + elapsed);
+
+    let mut keys = Vec::new(&env);
+    keys.push_back(TtlKey::Signal(signal_id));
+    keys.push_back(TtlKey::Signal(999)); // missing, skipped
+    assert_eq!(client.bump_ttl(&keys), 1);
+
+    let ttl = env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl, TtlClass::Hot.extend_to());
+}
+
+#[test]
+fn test_provider_queries_after_closed_signals_expire() {
+    use soroban_sdk::testutils::Ledger;
+    use stellar_swipe_common::{TtlClass, DAY_IN_LEDGERS};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    let create = |price: i128| {
+        client.create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &price,
+            &String::from_str(&env, "Test"),
+            &(env.ledger().timestamp() + 24 * 60 * 60),
+            &SignalCategory::SwingTrade,
+            &Vec::new(&env),
+            &RiskLevel::Medium,
+        )
+    };
+
+    // +10% trade resolves the signal as successful
+    let closed_id = create(100_000);
+    client.record_trade_execution(
+        &Address::generate(&env),
+        &closed_id,
+        &100_000,
+        &110_000,
+        &1_000,
+    );
+    assert_eq!(
+        client.get_signal(&closed_id).unwrap().status,
+        SignalStatus::Successful
+    );
+
+    // Outlive the closed signal's history TTL, keeping the instance and the
+    // provider's totals alive the way a keeper would
+    let mut keys = Vec::new(&env);
+    keys.push_back(TtlKey::Instance);
+    keys.push_back(TtlKey::Provider(provider.clone()));
+    let step = 20 * DAY_IN_LEDGERS;
+    for _ in 0..=TtlClass::History.extend_to() / step {
+        env.ledger()
+            .set_sequence_number(env.ledger().sequence() + step);
+        client.bump_ttl(&keys);
+    }
+
+    // Provider queries only touch live entries: reading an archived one
+    // restores it, which shows up as a write
+    let open_id = create(101_000);
+    let page =
+        client.get_active_signals(&0, &20, &SortOption::RecencyDesc, &Some(provider.clone()));
+    assert_eq!(env.cost_estimate().resources().write_entries, 0);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, open_id);
+
+    let breakdown = client.get_performance_breakdown(&provider);
+    assert_eq!(env.cost_estimate().resources().write_entries, 0);
+    let category = breakdown.by_category.get(0).unwrap();
+    assert_eq!(category.performance.resolved_signals, 1);
+    assert_eq!(category.performance.successful_signals, 1);
+
+    // Fewer than the minimum signals for analytics
+    assert!(client.get_provider_analytics(&provider).is_none());
+    assert_eq!(env.cost_estimate().resources().write_entries, 0);
+}
+
+#[test]
+fn test_bump_ttl_skips_expired_signals_and_limits_keys() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    env.ledger().set_timestamp(10_000);
+    let provider = Address::generate(&env);
+    let signal_id = client.create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Short-lived signal"),
+        &10_100,
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+
+    env.ledger().set_timestamp(10_200);
+    client.cleanup_expired_signals(&10);
+
+    let mut keys = Vec::new(&env);
+    keys.push_back(TtlKey::Signal(signal_id));
+    assert_eq!(client.bump_ttl(&keys), 0);
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..(stellar_swipe_common::MAX_BUMP_KEYS + 1) {
+        too_many.push_back(TtlKey::Instance);
+    }
+    let result = client.try_bump_ttl(&too_many);
+    assert_eq!(result, Err(Ok(AdminError::InvalidParameter)));
+}
@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> SignalRegistryClient<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    client
+}
+
+/// Create a signal and settle it through a +5% or -10% trade of `volume`
+fn settle(
+    env: &Env,
+    client: &SignalRegistryClient,
+    provider: &Address,
+    category: SignalCategory,
+    risk_level: RiskLevel,
+    tags: &[&str],
+    success: bool,
+) {
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    let mut tag_vec = Vec::new(env);
+    for tag in tags {
+        tag_vec.push_back(String::from_str(env, tag));
+    }
+    let id = client.create_signal(
+        provider,
+        &String::from_str(env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(env, "Setup"),
+        &(env.ledger().timestamp() + 3_600),
+        &category,
+        &tag_vec,
+        &risk_level,
+    );
+    let exit = if success { 105_000 } else { 90_000 };
+    client.record_trade_execution(&Address::generate(env), &id, &100_000, &exit, &1_000);
+}
+
+#[test]
+fn test_provider_breakdown() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+
+    settle(
+        &env,
+        &client,
+        &provider,
+        SignalCategory::Scalping,
+        RiskLevel::High,
+        &["btc", "news"],
+        true,
+    );
+    settle(
+        &env,
+        &client,
+        &provider,
+        SignalCategory::Scalping,
+        RiskLevel::High,
+        &["btc"],
+        false,
+    );
+    settle(
+        &env,
+        &client,
+        &provider,
+        SignalCategory::LongTerm,
+        RiskLevel::Low,
+        &["btc", "macro"],
+        true,
+    );
+    // Unresolved signals are ignored
+    client.create_signal(
+        &provider,
+        &String::from_str(&env, "ETH/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Open"),
+        &(env.ledger().timestamp() + 3_600),
+        &SignalCategory::Momentum,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+
+    let breakdown = client.get_performance_breakdown(&provider);
+
+    assert_eq!(breakdown.by_category.len(), 2);
+    let scalping = breakdown
+        .by_category
+        .iter()
+        .find(|c| c.category == SignalCategory::Scalping)
+        .unwrap()
+        .performance;
+    assert_eq!(
+        scalping,
+        SegmentPerformance {
+            resolved_signals: 2,
+            successful_signals: 1,
+            success_rate: 5_000,
+            avg_roi: -250,
+            total_volume: 2_000,
+        }
+    );
+
+    assert_eq!(breakdown.by_risk_level.len(), 2);
+    let low = breakdown
+        .by_risk_level
+        .iter()
+        .find(|r| r.risk_level == RiskLevel::Low)
+        .unwrap();
+    assert_eq!(low.performance.success_rate, 10_000);
+
+    let top = breakdown.top_tags.get(0).unwrap();
+    assert_eq!(top.tag, String::from_str(&env, "btc"));
+    assert_eq!(top.performance.resolved_signals, 3);
+    assert_eq!(breakdown.top_tags.len(), 3);
+}
+
+#[test]
+fn test_platform_category_ranking() {
+    let env = Env::default();
+    let client = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    for (provider, category, success) in [
+        (&alice, SignalCategory::DayTrade, false),
+        (&bob, SignalCategory::DayTrade, true),
+        (&alice, SignalCategory::Breakout, true),
+        (&bob, SignalCategory::Breakout, true),
+    ] {
+        settle(
+            &env,
+            &client,
+            provider,
+            category,
+            RiskLevel::Medium,
+            &[],
+            success,
+        );
+    }
+
+    let ranking = client.get_category_performance();
+    assert_eq!(ranking.len(), 2);
+    let best = ranking.get(0).unwrap();
+    assert_eq!(best.category, SignalCategory::Breakout);
+    assert_eq!(best.performance.success_rate, 10_000);
+    assert_eq!(best.performance.resolved_signals, 2);
+    let worst = ranking.get(1).unwrap();
+    assert_eq!(worst.category, SignalCategory::DayTrade);
+    assert_eq!(worst.performance.success_rate, 5_000);
+
+    // Totals are kept as signals resolve, and later ones move the ranking
+    for _ in 0..3 {
+        settle(
+            &env,
+            &client,
+            &alice,
+            SignalCategory::Breakout,
+            RiskLevel::Medium,
+            &[],
+            false,
+        );
+    }
+    let ranking = client.get_category_performance();
+    assert_eq!(ranking.get(0).unwrap().category, SignalCategory::DayTrade);
+    let breakout = ranking.get(1).unwrap().performance;
+    assert_eq!(breakout.resolved_signals, 5);
+    assert_eq!(breakout.success_rate, 4_000);
+    assert_eq!(breakout.total_volume, 5_000);
+}
@@ -1,4 +1,6 @@
 #![cfg(test)]
+extern crate std;
+use std::format;
 
 use crate::categories::{RiskLevel, SignalCategory};
 use crate::{SignalRegistry, SignalRegistryClient};
@@ -7,13 +9,13 @@ use soroban_sdk::{testutils::Address as TestAddress, Address, Env, String, Vec};
 fn setup_env() -> (Env, Address, SignalRegistryClient<'static>) {
     let env = Env::default();
     env.mock_all_auths();
-    
-    let contract_id = env.register_contract(None, SignalRegistry);
+
+    let contract_id = env.register(SignalRegistry, ());
     let client = SignalRegistryClient::new(&env, &contract_id);
-    
+
     let admin = Address::generate(&env);
     client.initialize(&admin);
-    
+
     (env, admin, client)
 }
 
@@ -25,11 +27,11 @@ fn create_string(env: &Env, s: &str) -> String {
 fn test_create_signal_with_category_and_tags() {
     let (env, _admin, client) = setup_env();
     let provider = Address::generate(&env);
-    
+
     let mut tags = Vec::new(&env);
     tags.push_back(create_string(&env, "bullish"));
     tags.push_back(create_string(&env, "breakout"));
-    
+
     let signal_id = client.create_signal(
         &provider,
         &create_string(&env, "XLM/USDC"),
@@ -41,7 +43,7 @@ fn test_create_signal_with_category_and_tags() {
         &tags,
         &RiskLevel::Medium,
     );
-    
+
     let signal = client.get_signal(&signal_id).unwrap();
     assert_eq!(signal.category, SignalCategory::SwingTrade);
     assert_eq!(signal.tags.len(), 2);
@@ -52,9 +54,9 @@ fn test_create_signal_with_category_and_tags() {
 fn test_add_tags_to_signal() {
     let (env, _admin, client) = setup_env();
     let provider = Address::generate(&env);
-    
+
     let tags = Vec::new(&env);
-    
+
     let signal_id = client.create_signal(
         &provider,
         &create_string(&env, "XLM/USDC"),
@@ -66,13 +68,13 @@ fn test_add_tags_to_signal() {
         &tags,
         &RiskLevel::High,
     );
-    
+
     let mut new_tags = Vec::new(&env);
     new_tags.push_back(create_string(&env, "momentum"));
     new_tags.push_back(create_string(&env, "high-risk"));
-    
+
     client.add_tags_to_signal(&provider, &signal_id, &new_tags);
-    
+
     let signal = client.get_signal(&signal_id).unwrap();
     assert_eq!(signal.tags.len(), 2);
 }
@@ -82,12 +84,12 @@ fn test_add_tags_to_signal() {
 fn test_add_tags_exceeds_max() {
     let (env, _admin, client) = setup_env();
     let provider = Address::generate(&env);
-    
+
     let mut initial_tags = Vec::new(&env);
     for i in 0..8 {
         initial_tags.push_back(create_string(&env, &format!("tag{}", i)));
     }
-    
+
     let signal_id = client.create_signal(
         &provider,
         &create_string(&env, "XLM/USDC"),
@@ -99,12 +101,12 @@ fn test_add_tags_exceeds_max() {
         &initial_tags,
         &RiskLevel::Low,
     );
-    
+
     let mut new_tags = Vec::new(&env);
     new_tags.push_back(create_string(&env, "tag8"));
     new_tags.push_back(create_string(&env, "tag9"));
     new_tags.push_back(create_string(&env, "tag10"));
-    
+
     // Should panic - exceeds max 10 tags
     client.add_tags_to_signal(&provider, &signal_id, &new_tags);
 }
@@ -113,12 +115,12 @@ fn test_add_tags_exceeds_max() {
 fn test_deduplicate_tags() {
     let (env, _admin, client) = setup_env();
     let provider = Address::generate(&env);
-    
+
     let mut tags = Vec::new(&env);
     tags.push_back(create_string(&env, "bullish"));
     tags.push_back(create_string(&env, "breakout"));
     tags.push_back(create_string(&env, "bullish")); // Duplicate
-    
+
     let signal_id = client.create_signal(
         &provider,
         &create_string(&env, "XLM/USDC"),
@@ -130,7 +132,7 @@ fn test_deduplicate_tags() {
         &tags,
         &RiskLevel::Medium,
     );
-    
+
     let signal = client.get_signal(&signal_id).unwrap();
     assert_eq!(signal.tags.len(), 2); // Deduplicated
 }
@@ -139,10 +141,10 @@ fn test_deduplicate_tags() {
 fn test_filter_by_category() {
     let (env, _admin, client) = setup_env();
     let provider = Address::generate(&env);
-    
+
     // Create signals with different categories
     let tags = Vec::new(&env);
-    
+
     client.create_signal(
         &provider,
         &create_string(&env, "XLM/USDC"),
@@ -154,7 +156,7 @@ fn test_filter_by_category() {
         &tags,
         &RiskLevel::Low,
     );
-    
+
     client.create_signal(
         &provider,
         &create_string(&env, "BTC/USDC"),
@@ -166,33 +168,30 @@ fn test_filter_by_category() {
         &tags,
         &RiskLevel::High,
     );
-    
+
     let mut categories = Vec::new(&env);
     categories.push_back(SignalCategory::SwingTrade);
-    
-    let filtered = client.get_signals_filtered(
-        &Some(categories),
-        &None,
-        &None,
-        &0,
-        &10,
-    );
-    
+
+    let filtered = client.get_signals_filtered(&Some(categories), &None, &None, &0, &10, &None);
+
     assert_eq!(filtered.len(), 1);
-    assert_eq!(filtered.get(0).unwrap().category, SignalCategory::SwingTrade);
+    assert_eq!(
+        filtered.get(0).unwrap().category,
+        SignalCategory::SwingTrade
+    );
 }
 
 #[test]
 fn test_filter_by_tags() {
     let (env, _admin, client) = setup_env();
     let provider = Address::generate(&env);
-    
+
     let mut tags1 = Vec::new(&env);
     tags1.push_back(create_string(&env, "bullish"));
-    
+
     let mut tags2 = Vec::new(&env);
     tags2.push_back(create_string(&env, "bearish"));
-    
+
     client.create_signal(
         &provider,
         &create_string(&env, "XLM/USDC"),
@@ -204,7 +203,7 @@ fn test_filter_by_tags() {
         &tags1,
         &RiskLevel::Low,
     );
-    
+
     client.create_signal(
         &provider,
         &create_string(&env, "BTC/USDC"),
@@ -216,29 +215,26 @@ fn test_filter_by_tags() {
         &tags2,
         &RiskLevel::High,
     );
-    
+
     let mut filter_tags = Vec::new(&env);
     filter_tags.push_back(create_string(&env, "bullish"));
-    
-    let filtered = client.get_signals_filtered(
-        &None,
-        &Some(filter_tags),
-        &None,
-        &0,
-        &10,
-    );
-    
+
+    let filtered = client.get_signals_filtered(&None, &Some(filter_tags), &None, &0, &10, &None);
+
     assert_eq!(filtered.len(), 1);
-    assert!(filtered.get(0).unwrap().tags.get(0).unwrap().to_bytes() == create_string(&env, "bullish").to_bytes());
+    assert!(
+        filtered.get(0).unwrap().tags.get(0).unwrap().to_bytes()
+            == create_string(&env, "bullish").to_bytes()
+    );
 }
 
 #[test]
 fn test_filter_by_risk_level() {
     let (env, _admin, client) = setup_env();
     let provider = Address::generate(&env);
-    
+
     let tags = Vec::new(&env);
-    
+
     client.create_signal(
         &provider,
         &create_string(&env, "XLM/USDC"),
@@ -250,7 +246,7 @@ fn test_filter_by_risk_level() {
         &tags,
         &RiskLevel::Low,
     );
-    
+
     client.create_signal(
         &provider,
         &create_string(&env, "BTC/USDC"),
@@ -262,18 +258,12 @@ fn test_filter_by_risk_level() {
         &tags,
         &RiskLevel::High,
     );
-    
+
     let mut risk_levels = Vec::new(&env);
     risk_levels.push_back(RiskLevel::High);
-    
-    let filtered = client.get_signals_filtered(
-        &None,
-        &None,
-        &Some(risk_levels),
-        &0,
-        &10,
-    );
-    
+
+    let filtered = client.get_signals_filtered(&None, &None, &Some(risk_levels), &0, &10, &None);
+
     assert_eq!(filtered.len(), 1);
     assert_eq!(filtered.get(0).unwrap().risk_level, RiskLevel::High);
 }
@@ -282,13 +272,13 @@ fn test_filter_by_risk_level() {
 fn test_combined_filters() {
     let (env, _admin, client) = setup_env();
     let provider = Address::generate(&env);
-    
+
     let mut tags1 = Vec::new(&env);
     tags1.push_back(create_string(&env, "momentum"));
-    
+
     let mut tags2 = Vec::new(&env);
     tags2.push_back(create_string(&env, "reversal"));
-    
+
     // Signal 1: SwingTrade, momentum, Medium
     client.create_signal(
         &provider,
@@ -301,7 +291,7 @@ fn test_combined_filters() {
         &tags1,
         &RiskLevel::Medium,
     );
-    
+
     // Signal 2: DayTrade, reversal, High
     client.create_signal(
         &provider,
@@ -314,42 +304,46 @@ fn test_combined_filters() {
         &tags2,
         &RiskLevel::High,
     );
-    
+
     let mut categories = Vec::new(&env);
     categories.push_back(SignalCategory::SwingTrade);
-    
+
     let mut filter_tags = Vec::new(&env);
     filter_tags.push_back(create_string(&env, "momentum"));
-    
+
     let mut risk_levels = Vec::new(&env);
     risk_levels.push_back(RiskLevel::Medium);
-    
+
     let filtered = client.get_signals_filtered(
         &Some(categories),
         &Some(filter_tags),
         &Some(risk_levels),
         &0,
         &10,
+        &None,
     );
-    
+
     assert_eq!(filtered.len(), 1);
-    assert_eq!(filtered.get(0).unwrap().category, SignalCategory::SwingTrade);
+    assert_eq!(
+        filtered.get(0).unwrap().category,
+        SignalCategory::SwingTrade
+    );
 }
 
 #[test]
 fn test_popular_tags() {
     let (env, _admin, client) = setup_env();
     let provider = Address::generate(&env);
-    
+
     // Create multiple signals with overlapping tags
     let mut tags1 = Vec::new(&env);
     tags1.push_back(create_string(&env, "bullish"));
     tags1.push_back(create_string(&env, "breakout"));
-    
+
     let mut tags2 = Vec::new(&env);
     tags2.push_back(create_string(&env, "bullish"));
     tags2.push_back(create_string(&env, "momentum"));
-    
+
     client.create_signal(
         &provider,
         &create_string(&env, "XLM/USDC"),
@@ -361,7 +355,7 @@ fn test_popular_tags() {
         &tags1,
         &RiskLevel::Low,
     );
-    
+
     client.create_signal(
         &provider,
         &create_string(&env, "BTC/USDC"),
@@ -373,23 +367,27 @@ fn test_popular_tags() {
         &tags2,
         &RiskLevel::Medium,
     );
-    
-    let popular = client.get_popular_tags(&10);
-    
-    assert!(popular.len() > 0);
+
+    let popular = client.get_popular_tags(&24, &None, &10);
+
+    assert!(!popular.is_empty());
     // "bullish" should be most popular (used twice)
     let top_tag = popular.get(0).unwrap();
-    assert_eq!(top_tag.1, 2); // Count should be 2
+    assert_eq!(top_tag.name, create_string(&env, "bullish"));
+    assert_eq!(top_tag.signals, 2); // Count should be 2
 }
 
 #[test]
 fn test_suggest_tags() {
     let (env, _admin, _client) = setup_env();
-    
-    let rationale = create_string(&env, "Strong breakout above resistance with bullish momentum");
+
+    let rationale = create_string(
+        &env,
+        "Strong breakout above resistance with bullish momentum",
+    );
     let suggestions = crate::categories::auto_suggest_tags(&env, &rationale);
-    
-    assert!(suggestions.len() > 0);
+
+    assert!(!suggestions.is_empty());
     // Should suggest "breakout", "bullish", "momentum"
 }
 
@@ -397,9 +395,9 @@ fn test_suggest_tags() {
 fn test_pagination() {
     let (env, _admin, client) = setup_env();
     let provider = Address::generate(&env);
-    
+
     let tags = Vec::new(&env);
-    
+
     // Create 5 signals
     for i in 0..5 {
         client.create_signal(
@@ -414,16 +412,16 @@ fn test_pagination() {
             &RiskLevel::Low,
         );
     }
-    
+
     // Get first 2
-    let page1 = client.get_signals_filtered(&None, &None, &None, &0, &2);
+    let page1 = client.get_signals_filtered(&None, &None, &None, &0, &2, &None);
     assert_eq!(page1.len(), 2);
-    
+
     // Get next 2
-    let page2 = client.get_signals_filtered(&None, &None, &None, &2, &2);
+    let page2 = client.get_signals_filtered(&None, &None, &None, &2, &2, &None);
     assert_eq!(page2.len(), 2);
-    
+
     // Get last 1
-    let page3 = client.get_signals_filtered(&None, &None, &None, &4, &2);
+    let page3 = client.get_signals_filtered(&None, &None, &None, &4, &2, &None);
     assert_eq!(page3.len(), 1);
 }
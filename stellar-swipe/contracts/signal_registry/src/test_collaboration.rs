@@ -1,15 +1,16 @@
 #![cfg(test)]
-use crate::{SignalRegistry, SignalRegistryClient};
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
 use crate::categories::{RiskLevel, SignalCategory};
+use crate::errors::AdminError;
 use crate::types::SignalAction;
+use crate::{SignalRegistry, SignalRegistryClient};
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
 
 #[test]
 fn test_create_collaborative_signal() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register_contract(None, SignalRegistry);
+    let contract_id = env.register(SignalRegistry, ());
     let client = SignalRegistryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
@@ -19,19 +20,14 @@ fn test_create_collaborative_signal() {
 
     client.initialize(&admin);
 
-    let mut co_authors = Vec::new(&env);
-    co_authors.push_back(co_author1.clone());
-    co_authors.push_back(co_author2.clone());
-
-    let mut contribution_pcts = Vec::new(&env);
-    contribution_pcts.push_back(6000); // Primary: 60%
-    contribution_pcts.push_back(2500); // Co-author1: 25%
-    contribution_pcts.push_back(1500); // Co-author2: 15%
+    let mut contributions = Vec::new(&env);
+    contributions.push_back((primary.clone(), 6000)); // Primary: 60%
+    contributions.push_back((co_author1.clone(), 2500)); // Co-author1: 25%
+    contributions.push_back((co_author2.clone(), 1500)); // Co-author2: 15%
 
     let signal_id = client.create_collaborative_signal(
         &primary,
-        &co_authors,
-        &contribution_pcts,
+        &contributions,
         &String::from_str(&env, "XLM/USDC"),
         &SignalAction::Buy,
         &1000000,
@@ -51,7 +47,7 @@ fn test_approve_collaborative_signal() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register_contract(None, SignalRegistry);
+    let contract_id = env.register(SignalRegistry, ());
     let client = SignalRegistryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
@@ -60,17 +56,13 @@ fn test_approve_collaborative_signal() {
 
     client.initialize(&admin);
 
-    let mut co_authors = Vec::new(&env);
-    co_authors.push_back(co_author.clone());
-
-    let mut contribution_pcts = Vec::new(&env);
-    contribution_pcts.push_back(6000);
-    contribution_pcts.push_back(4000);
+    let mut contributions = Vec::new(&env);
+    contributions.push_back((primary.clone(), 6000));
+    contributions.push_back((co_author.clone(), 4000));
 
     let signal_id = client.create_collaborative_signal(
         &primary,
-        &co_authors,
-        &contribution_pcts,
+        &contributions,
         &String::from_str(&env, "XLM/USDC"),
         &SignalAction::Buy,
         &1000000,
@@ -88,12 +80,11 @@ fn test_approve_collaborative_signal() {
 }
 
 #[test]
-#[should_panic(expected = "InvalidParameter")]
 fn test_invalid_contribution_percentages() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register_contract(None, SignalRegistry);
+    let contract_id = env.register(SignalRegistry, ());
     let client = SignalRegistryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
@@ -102,17 +93,13 @@ fn test_invalid_contribution_percentages() {
 
     client.initialize(&admin);
 
-    let mut co_authors = Vec::new(&env);
-    co_authors.push_back(co_author);
-
-    let mut contribution_pcts = Vec::new(&env);
-    contribution_pcts.push_back(6000);
-    contribution_pcts.push_back(3000); // Total = 9000, not 10000
+    let mut contributions = Vec::new(&env);
+    contributions.push_back((primary.clone(), 6000));
+    contributions.push_back((co_author, 3000)); // Total = 9000, not 10000
 
-    client.create_collaborative_signal(
+    let result = client.try_create_collaborative_signal(
         &primary,
-        &co_authors,
-        &contribution_pcts,
+        &contributions,
         &String::from_str(&env, "XLM/USDC"),
         &SignalAction::Buy,
         &1000000,
@@ -122,4 +109,5 @@ fn test_invalid_contribution_percentages() {
         &Vec::new(&env),
         &RiskLevel::Medium,
     );
+    assert_eq!(result, Err(Ok(AdminError::InvalidParameter)));
 }
@@ -0,0 +1,236 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> SignalRegistryClient<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    client
+}
+
+fn reveal(env: &Env, price: i128) -> SignalReveal {
+    SignalReveal {
+        asset_pair: String::from_str(env, "XLM/USDC"),
+        action: SignalAction::Buy,
+        price,
+        rationale: String::from_str(env, "Hidden until reveal"),
+        expiry: env.ledger().timestamp() + 7200,
+        category: SignalCategory::SwingTrade,
+        tags: Vec::new(env),
+        risk_level: RiskLevel::Medium,
+        salt: BytesN::from_array(env, &[7; 32]),
+    }
+}
+
+#[test]
+fn test_reveal_links_signal_to_commit_time() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+
+    let content = reveal(&env, 100_000);
+    let commitment = client.compute_signal_commitment(&content);
+    let committed_at = env.ledger().timestamp();
+    let commit_id = client.commit_signal(&provider, &commitment);
+
+    // Nothing is published before the reveal
+    assert_eq!(
+        client
+            .get_active_signals(&0, &10, &SortOption::RecencyDesc, &None)
+            .len(),
+        0
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 600);
+    let signal_id = client
+        .reveal_signal(&provider, &commit_id, &content)
+        .unwrap();
+
+    // The signal keeps its creation time; the commit records when it was made
+    let signal = client.get_signal(&signal_id).unwrap();
+    assert_eq!(signal.timestamp, committed_at + 600);
+    assert_eq!(signal.price, 100_000);
+
+    let commit = client.get_signal_commit(&commit_id).unwrap();
+    assert_eq!(commit.status, CommitStatus::Revealed);
+    assert_eq!(commit.signal_id, Some(signal_id));
+    assert_eq!(commit.committed_at, committed_at);
+    assert_eq!(client.get_commit_for_signal(&signal_id), Some(commit));
+    assert_eq!(client.get_commit_for_signal(&999), None);
+    assert_eq!(
+        client.try_reveal_signal(&provider, &commit_id, &content),
+        Err(Ok(AdminError::CommitNotPending))
+    );
+}
+
+#[test]
+fn test_revealed_signal_performance_runs_from_commit() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+
+    // Commit at 23:30 on day 9, reveal early on day 10
+    let committed_at = 10 * 86_400 - 1_800;
+    env.ledger().with_mut(|li| li.timestamp = committed_at);
+    let mut content = reveal(&env, 100_000);
+    content.expiry = committed_at + 30 * 86_400;
+    let commit_id = client.commit_signal(&provider, &client.compute_signal_commitment(&content));
+    env.ledger().with_mut(|li| li.timestamp += 2_400);
+    let signal_id = client
+        .reveal_signal(&provider, &commit_id, &content)
+        .unwrap();
+
+    // Settle on day 16, when a 7 day window covers days 10 to 16
+    let resolved_at = 16 * 86_400 + 1_200;
+    env.ledger().with_mut(|li| li.timestamp = resolved_at);
+    client.record_trade_execution(
+        &Address::generate(&env),
+        &signal_id,
+        &100_000,
+        &110_000,
+        &1_000,
+    );
+    assert_eq!(
+        client.get_signal(&signal_id).unwrap().status,
+        SignalStatus::Successful
+    );
+
+    let risk = client.get_provider_risk_stats(&provider).unwrap();
+    assert_eq!(risk.avg_holding_time, resolved_at - committed_at);
+
+    // Bucketed on the commit day, which only the 30 day window covers
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            leaderboard::get_windowed_stats(&env, &provider, 7).total_signals,
+            0
+        );
+        assert_eq!(
+            leaderboard::get_windowed_stats(&env, &provider, 30).total_signals,
+            1
+        );
+    });
+}
+
+#[test]
+fn test_mismatched_reveal_counts_against_provider() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let commitment = client.compute_signal_commitment(&reveal(&env, 100_000));
+    let commit_id = client.commit_signal(&provider, &commitment);
+
+    assert_eq!(
+        client.try_reveal_signal(&other, &commit_id, &reveal(&env, 100_000)),
+        Err(Ok(AdminError::Unauthorized))
+    );
+
+    // Revealing a different price after the market moved is rejected
+    assert_eq!(
+        client.reveal_signal(&provider, &commit_id, &reveal(&env, 90_000)),
+        None
+    );
+    assert_eq!(
+        client.get_signal_commit(&commit_id).unwrap().status,
+        CommitStatus::Mismatched
+    );
+
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.total_signals, 1);
+    assert_eq!(stats.failed_signals, 1);
+
+    // Only the success rate and reputation take the forfeit
+    let view = client.get_provider_performance(&provider).unwrap();
+    assert_eq!(client.get_provider_risk_stats(&provider), None);
+    assert_eq!(client.get_provider_equity_curve(&provider, &0).len(), 0);
+    let today = env.ledger().timestamp() / 86_400;
+    for day in client.get_global_history(&today, &today).iter() {
+        assert_eq!(day.resolved_signals, 0);
+    }
+    env.as_contract(&client.address, || {
+        assert!(view.reputation_score < leaderboard::get_reputation_score(&env, &other));
+        assert_eq!(
+            leaderboard::get_windowed_stats(&env, &provider, 30).total_signals,
+            0
+        );
+    });
+}
+
+#[test]
+fn test_unrevealed_commit_lapses() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+
+    let content = reveal(&env, 100_000);
+    let commitment = client.compute_signal_commitment(&content);
+    let commit_id = client.commit_signal(&provider, &commitment);
+
+    assert_eq!(
+        client.try_expire_commit(&commit_id),
+        Err(Ok(AdminError::RevealWindowOpen))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += commit_reveal::REVEAL_WINDOW_SECONDS + 1);
+    assert_eq!(
+        client.try_reveal_signal(&provider, &commit_id, &content),
+        Err(Ok(AdminError::RevealWindowClosed))
+    );
+
+    client.expire_commit(&commit_id);
+    assert_eq!(
+        client.get_signal_commit(&commit_id).unwrap().status,
+        CommitStatus::Lapsed
+    );
+    assert_eq!(
+        client.get_provider_stats(&provider).unwrap().failed_signals,
+        1
+    );
+    assert_eq!(
+        client.try_expire_commit(&commit_id),
+        Err(Ok(AdminError::CommitNotPending))
+    );
+}
+
+#[test]
+fn test_one_pending_commit_per_provider() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+
+    // Committing to both sides and revealing only the winner is not possible
+    let up = reveal(&env, 100_000);
+    let mut down = reveal(&env, 100_000);
+    down.action = SignalAction::Sell;
+    let commit_id = client.commit_signal(&provider, &client.compute_signal_commitment(&up));
+    assert_eq!(
+        client.try_commit_signal(&provider, &client.compute_signal_commitment(&down)),
+        Err(Ok(AdminError::CommitAlreadyPending))
+    );
+
+    // Other providers are unaffected
+    client.commit_signal(
+        &Address::generate(&env),
+        &client.compute_signal_commitment(&down),
+    );
+
+    // A lapsed commit blocks new ones until it is expired and counted
+    env.ledger()
+        .with_mut(|li| li.timestamp += commit_reveal::REVEAL_WINDOW_SECONDS + 1);
+    assert_eq!(
+        client.try_commit_signal(&provider, &client.compute_signal_commitment(&down)),
+        Err(Ok(AdminError::CommitAlreadyPending))
+    );
+    client.expire_commit(&commit_id);
+    let next = reveal(&env, 100_000);
+    let next_id = client.commit_signal(&provider, &client.compute_signal_commitment(&next));
+    client.reveal_signal(&provider, &next_id, &next).unwrap();
+    client.commit_signal(&provider, &client.compute_signal_commitment(&up));
+}
@@ -0,0 +1,250 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_resolution::{MockOracle, MockOracleClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> (SignalRegistryClient<'_>, MockOracleClient<'_>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let oracle_id = env.register(MockOracle, ());
+    client.set_price_oracle(&admin, &oracle_id);
+
+    (client, MockOracleClient::new(env, &oracle_id))
+}
+
+fn create(
+    env: &Env,
+    client: &SignalRegistryClient,
+    provider: &Address,
+    price: i128,
+    condition: &SignalCondition,
+) -> Result<u64, AdminError> {
+    client
+        .try_create_conditional_signal(
+            provider,
+            &String::from_str(env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &price,
+            &String::from_str(env, "Breakout entry"),
+            &(env.ledger().timestamp() + 3600),
+            &SignalCategory::SwingTrade,
+            &Vec::new(env),
+            &RiskLevel::Medium,
+            condition,
+        )
+        .map(|id| id.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+fn breakout(price: i128) -> SignalCondition {
+    SignalCondition {
+        trigger_price: Some(price),
+        trigger_direction: TriggerDirection::Above,
+        valid_from: None,
+    }
+}
+
+#[test]
+fn test_trigger_activates_signal() {
+    let env = Env::default();
+    let (client, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+    let executor = Address::generate(&env);
+
+    oracle.set_price(&140_000);
+    let signal_id = create(&env, &client, &provider, 150_000, &breakout(150_000)).unwrap();
+    assert_eq!(
+        client.get_signal(&signal_id).unwrap().status,
+        SignalStatus::Pending
+    );
+    assert_eq!(
+        client.try_record_trade_execution(&executor, &signal_id, &150_000, &151_000, &1_000),
+        Err(Ok(errors::PerformanceError::SignalNotActive))
+    );
+
+    // Below the trigger nothing happens
+    oracle.set_price(&149_000);
+    assert_eq!(client.activate_conditional_signals(&0, &10), 0);
+
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    oracle.set_price(&150_500);
+    assert_eq!(client.activate_conditional_signals(&0, &10), 1);
+
+    let signal = client.get_signal(&signal_id).unwrap();
+    assert_eq!(signal.status, SignalStatus::Active);
+    assert_eq!(signal.timestamp, env.ledger().timestamp());
+    assert_eq!(client.get_signal_condition(&signal_id), None);
+}
+
+#[test]
+fn test_valid_from_activation() {
+    let env = Env::default();
+    let (client, _oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let condition = SignalCondition {
+        trigger_price: None,
+        trigger_direction: TriggerDirection::Above,
+        valid_from: Some(env.ledger().timestamp() + 600),
+    };
+    let signal_id = create(&env, &client, &provider, 100_000, &condition).unwrap();
+
+    assert_eq!(client.activate_conditional_signals(&0, &10), 0);
+    env.ledger().with_mut(|li| li.timestamp += 600);
+    assert_eq!(client.activate_conditional_signals(&0, &10), 1);
+    assert_eq!(
+        client.get_signal(&signal_id).unwrap().status,
+        SignalStatus::Active
+    );
+}
+
+#[test]
+fn test_pending_signal_stays_out_of_queue_and_feed() {
+    let env = Env::default();
+    let (client, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    oracle.set_price(&140_000);
+    let signal_id = create(&env, &client, &provider, 150_000, &breakout(150_000)).unwrap();
+
+    assert!(client.get_swipe_queue(&user, &0, &10).is_empty());
+    assert!(client
+        .get_feed(&user, &FeedCursor::Start, &10)
+        .signals
+        .is_empty());
+
+    oracle.set_price(&150_500);
+    assert_eq!(client.activate_conditional_signals(&0, &10), 1);
+
+    let queue = client.get_swipe_queue(&user, &0, &10);
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.get(0).unwrap().id, signal_id);
+    let feed = client.get_feed(&user, &FeedCursor::Start, &10);
+    assert_eq!(feed.signals.len(), 1);
+    assert_eq!(feed.signals.get(0).unwrap().id, signal_id);
+}
+
+#[test]
+fn test_invalid_conditions_rejected() {
+    let env = Env::default();
+    let (client, _oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let empty = SignalCondition {
+        trigger_price: None,
+        trigger_direction: TriggerDirection::Above,
+        valid_from: None,
+    };
+    assert_eq!(
+        create(&env, &client, &provider, 100_000, &empty),
+        Err(AdminError::InvalidCondition)
+    );
+
+    let after_expiry = SignalCondition {
+        trigger_price: None,
+        trigger_direction: TriggerDirection::Above,
+        valid_from: Some(env.ledger().timestamp() + 3600),
+    };
+    assert_eq!(
+        create(&env, &client, &provider, 100_000, &after_expiry),
+        Err(AdminError::InvalidCondition)
+    );
+    assert_eq!(
+        create(&env, &client, &provider, 100_000, &breakout(0)),
+        Err(AdminError::InvalidCondition)
+    );
+}
+
+#[test]
+fn test_trigger_must_not_be_hit_at_creation() {
+    let env = Env::default();
+    let (client, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    // Without a price the trigger side is unknown
+    assert_eq!(
+        create(&env, &client, &provider, 150_000, &breakout(150_000)),
+        Err(AdminError::InvalidCondition)
+    );
+
+    oracle.set_price(&151_000);
+    assert_eq!(
+        create(&env, &client, &provider, 150_000, &breakout(150_000)),
+        Err(AdminError::InvalidCondition)
+    );
+
+    let pullback = SignalCondition {
+        trigger_price: Some(140_000),
+        trigger_direction: TriggerDirection::Below,
+        valid_from: None,
+    };
+    let signal_id = create(&env, &client, &provider, 140_000, &pullback).unwrap();
+    assert_eq!(client.activate_conditional_signals(&0, &10), 0);
+
+    oracle.set_price(&139_000);
+    assert_eq!(client.activate_conditional_signals(&0, &10), 1);
+    assert_eq!(
+        client.get_signal(&signal_id).unwrap().status,
+        SignalStatus::Active
+    );
+}
+
+#[test]
+fn test_untriggered_signals_expire_separately() {
+    let env = Env::default();
+    let (client, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    oracle.set_price(&140_000);
+    let conditional = create(&env, &client, &provider, 150_000, &breakout(150_000)).unwrap();
+    let plain = client.create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Plain call"),
+        &(env.ledger().timestamp() + 3600),
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+
+    // Regular cleanup leaves untriggered signals alone
+    let (_, expired) = client.cleanup_expired_signals(&50);
+    assert_eq!(expired, 1);
+    assert_eq!(
+        client.get_signal(&plain).unwrap().status,
+        SignalStatus::Expired
+    );
+    assert_eq!(
+        client.get_signal(&conditional).unwrap().status,
+        SignalStatus::Pending
+    );
+
+    assert_eq!(client.expire_untriggered_signals(&50), 1);
+    assert_eq!(
+        client.get_signal(&conditional).unwrap().status,
+        SignalStatus::Expired
+    );
+
+    // Never activated, so never settled
+    oracle.set_price(&200_000);
+    assert_eq!(
+        client.try_resolve_signal(&conditional),
+        Err(Ok(ResolutionError::NotActivated))
+    );
+    assert!(client.get_provider_stats(&provider).is_some());
+    assert_eq!(
+        client.get_provider_stats(&provider).unwrap().total_signals,
+        0
+    );
+}
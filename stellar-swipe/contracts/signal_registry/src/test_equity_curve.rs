@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> SignalRegistryClient<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    client
+}
+
+/// Create a signal and settle it through one trade closing at `exit`
+fn settle(env: &Env, client: &SignalRegistryClient, provider: &Address, exit: i128) {
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    let id = client.create_signal(
+        provider,
+        &String::from_str(env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(env, "Setup"),
+        &(env.ledger().timestamp() + 3_600),
+        &SignalCategory::SwingTrade,
+        &Vec::new(env),
+        &RiskLevel::Medium,
+    );
+    client.record_trade_execution(&Address::generate(env), &id, &100_000, &exit, &1_000);
+}
+
+#[test]
+fn test_equity_curve_compounds_in_resolution_order() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+
+    assert_eq!(client.get_provider_equity_curve(&provider, &0).len(), 0);
+
+    // +10%, -10%, +5%
+    for exit in [110_000, 90_000, 105_000] {
+        settle(&env, &client, &provider, exit);
+    }
+
+    let curve = client.get_provider_equity_curve(&provider, &0);
+    assert_eq!(curve.len(), 3);
+    let expected = [(1_000, 11_000), (-1_000, 9_900), (500, 10_395)];
+    for (i, (roi, equity)) in expected.iter().enumerate() {
+        let point = curve.get(i as u32).unwrap();
+        assert_eq!(point.roi, *roi);
+        assert_eq!(point.equity, *equity);
+    }
+    assert!(curve.get(0).unwrap().resolved_at < curve.get(2).unwrap().resolved_at);
+
+    // A limit keeps the most recent points
+    let recent = client.get_provider_equity_curve(&provider, &2);
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent.get(0).unwrap().equity, 9_900);
+}
+
+#[test]
+fn test_equity_curve_keeps_latest_points() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+
+    for _ in 0..(leaderboard::MAX_EQUITY_POINTS + 2) {
+        settle(&env, &client, &provider, 105_000);
+    }
+
+    let curve = client.get_provider_equity_curve(&provider, &1_000);
+    assert_eq!(curve.len(), leaderboard::MAX_EQUITY_POINTS);
+    // The first two points were dropped, so the oldest kept one is the third
+    assert_eq!(curve.get(0).unwrap().equity, 11_576);
+}
@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> SignalRegistryClient<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    client
+}
+
+fn create(
+    env: &Env,
+    client: &SignalRegistryClient,
+    provider: &Address,
+    pair: &str,
+    category: SignalCategory,
+    risk_level: RiskLevel,
+) -> u64 {
+    let id = client.create_signal(
+        provider,
+        &String::from_str(env, pair),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(env, "Setup"),
+        &(env.ledger().timestamp() + 86_400),
+        &category,
+        &Vec::new(env),
+        &risk_level,
+    );
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    id
+}
+
+#[test]
+fn test_feed_ranks_by_user_affinity() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    let followed = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.follow_provider(&user, &followed);
+    let mut categories = Vec::new(&env);
+    categories.push_back(SignalCategory::Breakout);
+    client.set_user_preferences(
+        &user,
+        &UserPreferences {
+            categories,
+            max_risk_level: RiskLevel::High,
+            blocked_providers: Vec::new(&env),
+            watched_pairs: Vec::new(&env),
+            min_success_rate: 0,
+        },
+    );
+
+    let from_followed = create(
+        &env,
+        &client,
+        &followed,
+        "XLM/USDC",
+        SignalCategory::SwingTrade,
+        RiskLevel::High,
+    );
+    let preferred = create(
+        &env,
+        &client,
+        &other,
+        "BTC/USDC",
+        SignalCategory::Breakout,
+        RiskLevel::High,
+    );
+    let plain = create(
+        &env,
+        &client,
+        &other,
+        "ETH/USDC",
+        SignalCategory::SwingTrade,
+        RiskLevel::High,
+    );
+
+    let page = client.get_feed(&user, &FeedCursor::Start, &10);
+    assert_eq!(page.signals.len(), 3);
+    assert_eq!(page.signals.get(0).unwrap().id, from_followed);
+    assert_eq!(page.signals.get(1).unwrap().id, preferred);
+    assert_eq!(page.signals.get(2).unwrap().id, plain);
+    assert_eq!(page.next_cursor, FeedCursor::End);
+}
+
+#[test]
+fn test_feed_cursor_and_swiped_signals() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    let oldest = create(
+        &env,
+        &client,
+        &provider,
+        "XLM/USDC",
+        SignalCategory::SwingTrade,
+        RiskLevel::Low,
+    );
+    create(
+        &env,
+        &client,
+        &provider,
+        "BTC/USDC",
+        SignalCategory::SwingTrade,
+        RiskLevel::Low,
+    );
+    let newest = create(
+        &env,
+        &client,
+        &provider,
+        "ETH/USDC",
+        SignalCategory::SwingTrade,
+        RiskLevel::Low,
+    );
+
+    client.swipe(&user, &newest, &SwipeDirection::Left);
+
+    let first = client.get_feed(&user, &FeedCursor::Start, &1);
+    assert_eq!(first.signals.len(), 1);
+    assert_ne!(first.next_cursor, FeedCursor::End);
+
+    let second = client.get_feed(&user, &first.next_cursor, &1);
+    assert_eq!(second.signals.get(0).unwrap().id, oldest);
+    assert_eq!(second.next_cursor, FeedCursor::End);
+}
+
+#[test]
+fn test_feed_cursor_survives_swipes_and_time() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    let mut ids = Vec::new(&env);
+    for pair in ["XLM/USDC", "BTC/USDC", "ETH/USDC", "SOL/USDC"] {
+        ids.push_back(create(
+            &env,
+            &client,
+            &provider,
+            pair,
+            SignalCategory::SwingTrade,
+            RiskLevel::Low,
+        ));
+    }
+
+    // Newest first: ids 4 and 3 on the first page
+    let first = client.get_feed(&user, &FeedCursor::Start, &2);
+    assert_eq!(first.signals.get(0).unwrap().id, ids.get(3).unwrap());
+    assert_eq!(first.signals.get(1).unwrap().id, ids.get(2).unwrap());
+
+    // Swiping the first page and letting freshness decay does not shift the
+    // next page, and a signal posted since then is not mixed in
+    client.swipe(&user, &ids.get(3).unwrap(), &SwipeDirection::Right);
+    client.swipe(&user, &ids.get(2).unwrap(), &SwipeDirection::Left);
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    create(
+        &env,
+        &client,
+        &provider,
+        "ADA/USDC",
+        SignalCategory::SwingTrade,
+        RiskLevel::Low,
+    );
+
+    let second = client.get_feed(&user, &first.next_cursor, &2);
+    assert_eq!(second.signals.len(), 2);
+    assert_eq!(second.signals.get(0).unwrap().id, ids.get(1).unwrap());
+    assert_eq!(second.signals.get(1).unwrap().id, ids.get(0).unwrap());
+    assert_eq!(second.next_cursor, FeedCursor::End);
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+const DAY: u64 = 86_400;
+
+fn setup(env: &Env) -> SignalRegistryClient<'_> {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000 * DAY);
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    client
+}
+
+fn next_day(env: &Env) {
+    env.ledger().with_mut(|li| li.timestamp += DAY);
+}
+
+#[test]
+fn test_history_records_daily_counts() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+    let fans = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    // Day 1000: two follows. Day 1001: quiet. Day 1002: one follow, one unfollow
+    // and another follow
+    client.follow_provider(&fans[0], &provider);
+    client.follow_provider(&fans[1], &provider);
+    next_day(&env);
+    next_day(&env);
+    client.follow_provider(&fans[2], &provider);
+    client.unfollow_provider(&fans[0], &provider);
+    client.follow_provider(&fans[0], &provider);
+
+    let history = client.get_follower_history(&provider, &4);
+    assert_eq!(history.len(), 4);
+    let counts: [(u64, u32); 4] = [(999, 0), (1_000, 2), (1_001, 2), (1_002, 3)];
+    for (i, (day, followers)) in counts.iter().enumerate() {
+        assert_eq!(
+            history.get(i as u32).unwrap(),
+            FollowerSnapshot {
+                day: *day,
+                followers: *followers
+            }
+        );
+    }
+
+    // Unknown providers report zero followers every day
+    let empty = client.get_follower_history(&Address::generate(&env), &3);
+    assert_eq!(empty.len(), 3);
+    assert!(empty.iter().all(|s| s.followers == 0));
+    assert_eq!(client.get_follower_history(&provider, &0).len(), 0);
+    assert_eq!(client.get_follower_history(&provider, &1_000).len(), 365);
+}
+
+#[test]
+fn test_growth_rate_over_windows() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+
+    for _ in 0..2 {
+        client.follow_provider(&Address::generate(&env), &provider);
+    }
+    for _ in 0..10 {
+        next_day(&env);
+    }
+    for _ in 0..3 {
+        client.follow_provider(&Address::generate(&env), &provider);
+    }
+    next_day(&env);
+
+    // 2 -> 5 over the last week, 0 -> 5 over the last month
+    assert_eq!(client.get_follower_growth_rate(&provider, &7), 15_000);
+    assert_eq!(client.get_follower_growth_rate(&provider, &30), 50_000);
+
+    // A week later nothing changed inside the weekly window
+    for _ in 0..7 {
+        next_day(&env);
+    }
+    assert_eq!(client.get_follower_growth_rate(&provider, &7), 0);
+}
+
+#[test]
+fn test_count_before_retention_window_carries() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+
+    // Day 1000: one follower. Day 1400: a second one, after day 1000 left
+    // the retention window
+    client.follow_provider(&Address::generate(&env), &provider);
+    env.ledger().set_timestamp(1_400 * DAY);
+    client.follow_provider(&Address::generate(&env), &provider);
+
+    let history = client.get_follower_history(&provider, &3);
+    assert_eq!(history.get(0).unwrap().followers, 1);
+    assert_eq!(history.get(1).unwrap().followers, 1);
+    assert_eq!(history.get(2).unwrap().followers, 2);
+    assert_eq!(client.get_follower_growth_rate(&provider, &30), 10_000);
+}
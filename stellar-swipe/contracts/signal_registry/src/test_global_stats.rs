@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+const DAY: u64 = 86_400;
+
+fn setup(env: &Env) -> SignalRegistryClient<'_> {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000 * DAY);
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    client
+}
+
+fn create(env: &Env, client: &SignalRegistryClient, provider: &Address, price: i128) -> u64 {
+    client.create_signal(
+        provider,
+        &String::from_str(env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &price,
+        &String::from_str(env, "Setup"),
+        &(env.ledger().timestamp() + 3_600),
+        &SignalCategory::SwingTrade,
+        &Vec::new(env),
+        &RiskLevel::Medium,
+    )
+}
+
+#[test]
+fn test_daily_aggregates_follow_writes() {
+    let env = Env::default();
+    let client = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let executor = Address::generate(&env);
+
+    // Day 1000: alice posts twice, bob once; one winner and one loser settle
+    let win = create(&env, &client, &alice, 100_000);
+    let loss = create(&env, &client, &alice, 200_000);
+    create(&env, &client, &bob, 100_000);
+    client.record_trade_execution(&executor, &win, &100_000, &105_000, &1_000);
+    client.record_trade_execution(&executor, &loss, &100_000, &90_000, &2_500);
+
+    // Day 1002: bob posts again
+    env.ledger().with_mut(|li| li.timestamp += 2 * DAY);
+    create(&env, &client, &bob, 300_000);
+
+    let history = client.get_global_history(&999, &1_002);
+    assert_eq!(history.len(), 4);
+    assert_eq!(
+        history.get(0).unwrap(),
+        DailyGlobalStats {
+            day: 999,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        history.get(1).unwrap(),
+        DailyGlobalStats {
+            day: 1_000,
+            signals_created: 3,
+            executions: 2,
+            volume: 3_500,
+            resolved_signals: 2,
+            successful_signals: 1,
+            active_providers: 2,
+        }
+    );
+    assert_eq!(history.get(2).unwrap().signals_created, 0);
+    let last = history.get(3).unwrap();
+    assert_eq!(last.day, 1_002);
+    assert_eq!(last.signals_created, 1);
+    assert_eq!(last.active_providers, 1);
+}
+
+#[test]
+fn test_history_range_bounds() {
+    let env = Env::default();
+    let client = setup(&env);
+    create(&env, &client, &Address::generate(&env), 100_000);
+
+    assert_eq!(client.get_global_history(&1_001, &1_000).len(), 0);
+    assert_eq!(client.get_global_history(&1_000, &1_000).len(), 1);
+
+    // Oversized ranges keep the most recent days
+    let history = client.get_global_history(&0, &1_000);
+    assert_eq!(history.len(), 90);
+    assert_eq!(history.get(0).unwrap().day, 911);
+    assert_eq!(history.get(89).unwrap().signals_created, 1);
+}
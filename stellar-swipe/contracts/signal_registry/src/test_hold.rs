@@ -0,0 +1,165 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_resolution::{MockOracle, MockOracleClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> (SignalRegistryClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    (client, admin)
+}
+
+fn create_hold(env: &Env, client: &SignalRegistryClient, provider: &Address, price: i128) -> u64 {
+    client.create_signal(
+        provider,
+        &String::from_str(env, "XLM/USDC"),
+        &SignalAction::Hold,
+        &price,
+        &String::from_str(env, "Range bound"),
+        &(env.ledger().timestamp() + 3600),
+        &SignalCategory::SwingTrade,
+        &Vec::new(env),
+        &RiskLevel::Low,
+    )
+}
+
+#[test]
+fn test_hold_roi_scores_move_size() {
+    assert_eq!(
+        performance::calculate_roi(100_000, 101_000, &SignalAction::Hold),
+        -100
+    );
+    assert_eq!(
+        performance::calculate_roi(100_000, 99_000, &SignalAction::Hold),
+        -100
+    );
+    assert_eq!(
+        performance::evaluate_outcome_status(&SignalAction::Hold, -200),
+        SignalStatus::Successful
+    );
+    assert_eq!(
+        performance::evaluate_outcome_status(&SignalAction::Hold, -201),
+        SignalStatus::Failed
+    );
+}
+
+#[test]
+fn test_hold_resolved_against_band() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    client.set_price_oracle(&admin, &oracle_id);
+
+    let inside = create_hold(&env, &client, &provider, 100_000);
+    let outside = create_hold(&env, &client, &provider, 95_000);
+
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+    oracle.set_price(&101_500);
+
+    let outcome = client.resolve_signal(&inside);
+    assert_eq!(outcome.roi, -150);
+    assert_eq!(outcome.status, SignalStatus::Successful);
+
+    let outcome = client.resolve_signal(&outside);
+    assert_eq!(outcome.status, SignalStatus::Failed);
+
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.successful_signals, 1);
+    assert_eq!(stats.failed_signals, 1);
+}
+
+#[test]
+fn test_hold_signals_are_not_executable() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let provider = Address::generate(&env);
+    let executor = Address::generate(&env);
+
+    let signal_id = create_hold(&env, &client, &provider, 100_000);
+    assert_eq!(
+        client.try_record_trade_execution(&executor, &signal_id, &100_000, &101_000, &1_000),
+        Err(Ok(errors::PerformanceError::NeutralSignal))
+    );
+
+    // Neutral calls have no exit levels
+    let targets = SignalTargets {
+        take_profit: Some(110_000),
+        stop_loss: None,
+    };
+    assert_eq!(
+        client.try_create_signal_with_targets(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Hold,
+            &100_000,
+            &String::from_str(&env, "Range bound"),
+            &(env.ledger().timestamp() + 3600),
+            &SignalCategory::SwingTrade,
+            &Vec::new(&env),
+            &RiskLevel::Low,
+            &targets,
+        ),
+        Err(Ok(AdminError::InvalidTargets))
+    );
+}
+
+#[test]
+fn test_hold_from_templates_import_and_query() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let provider = Address::generate(&env);
+
+    assert_eq!(
+        templates::parse_action(&String::from_str(&env, "Neutral")),
+        Ok(SignalAction::Hold)
+    );
+    assert_eq!(
+        templates::parse_action(&String::from_str(&env, "hold")),
+        Ok(SignalAction::Hold)
+    );
+
+    let csv_data = Bytes::from_slice(
+        &env,
+        b"asset_pair,action,price,rationale,expiry_hours\nXLM/USDC,HOLD,120000,Consolidating,24\nBTC/USDC,neutral,45000000,No edge,24",
+    );
+    let result = client.import_signals_csv(&provider, &csv_data, &false);
+    assert_eq!(result.success_count, 2);
+    assert_eq!(result.error_count, 0);
+
+    create_hold(&env, &client, &provider, 100_000);
+    let feed = client.get_active_signals(&0, &10, &SortOption::RecencyDesc, &None);
+    assert_eq!(feed.len(), 1);
+    assert_eq!(feed.get(0).unwrap().action, SignalAction::Hold);
+}
+
+#[test]
+fn test_hold_signals_report_neutral() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let hold = create_hold(&env, &client, &provider, 100_000);
+    let buy = client.create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Breakout"),
+        &(env.ledger().timestamp() + 3600),
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Low,
+    );
+    assert!(client.is_neutral_signal(&hold));
+    assert!(!client.is_neutral_signal(&buy));
+    assert!(!client.is_neutral_signal(&999));
+}
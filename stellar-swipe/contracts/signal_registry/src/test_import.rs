@@ -135,7 +135,7 @@ fn test_import_invalid_action() {
 
     let csv_data = Bytes::from_slice(
         &env,
-        b"asset_pair,action,price,rationale,expiry_hours\nXLM/USDC,HOLD,120000,Invalid action,24",
+        b"asset_pair,action,price,rationale,expiry_hours\nXLM/USDC,SHORT,120000,Invalid action,24",
     );
 
     let result = client.import_signals_csv(&provider, &csv_data, &false);
@@ -0,0 +1,366 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+
+fn setup(env: &Env) -> SignalRegistryClient<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    client
+}
+
+/// Create `count` signals for `provider` and settle each through a trade
+fn settle_signals(
+    env: &Env,
+    client: &SignalRegistryClient,
+    provider: &Address,
+    count: u32,
+    success: bool,
+) {
+    let executor = Address::generate(env);
+    for _ in 0..count {
+        env.ledger().with_mut(|li| li.timestamp += 3_600);
+        let id = client.create_signal(
+            provider,
+            &String::from_str(env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(env, "Setup"),
+            &(env.ledger().timestamp() + 3_600),
+            &SignalCategory::SwingTrade,
+            &Vec::new(env),
+            &RiskLevel::Medium,
+        );
+        let exit = if success { 105_000 } else { 90_000 };
+        client.record_trade_execution(&executor, &id, &100_000, &exit, &1_000);
+    }
+}
+
+#[test]
+fn test_followers_leaderboard() {
+    let env = Env::default();
+    let client = setup(&env);
+    let popular = Address::generate(&env);
+    let niche = Address::generate(&env);
+    let unfollowed = Address::generate(&env);
+
+    settle_signals(&env, &client, &popular, 1, false);
+    settle_signals(&env, &client, &niche, 5, true);
+    settle_signals(&env, &client, &unfollowed, 5, true);
+
+    for _ in 0..3 {
+        client.follow_provider(&Address::generate(&env), &popular);
+    }
+    client.follow_provider(&Address::generate(&env), &niche);
+
+    let board = client.get_leaderboard(&LeaderboardMetric::Followers, &10);
+    assert_eq!(board.len(), 2);
+    let first = board.get(0).unwrap();
+    assert_eq!(first.provider, popular);
+    assert_eq!(first.followers, 3);
+    assert_eq!(first.rank, 1);
+    let second = board.get(1).unwrap();
+    assert_eq!(second.provider, niche);
+    assert_eq!(second.followers, 1);
+    assert_eq!(second.rank, 2);
+}
+
+#[test]
+fn test_windows_only_count_recent_resolutions() {
+    let env = Env::default();
+    let client = setup(&env);
+    let veteran = Address::generate(&env);
+    let newcomer = Address::generate(&env);
+
+    // The veteran's perfect record is older than a week, and the last week
+    // was poor
+    settle_signals(&env, &client, &veteran, 5, true);
+    env.ledger().with_mut(|li| li.timestamp += 10 * 86_400);
+    settle_signals(&env, &client, &veteran, 4, false);
+    settle_signals(&env, &client, &veteran, 1, true);
+    settle_signals(&env, &client, &newcomer, 5, true);
+
+    let all_time = client.get_leaderboard(&LeaderboardMetric::SuccessRate, &10);
+    assert_eq!(all_time.len(), 2);
+    assert_eq!(all_time.get(1).unwrap().total_signals, 10);
+    assert_eq!(all_time.get(1).unwrap().success_rate, 6_000);
+
+    let weekly = client.get_leaderboard_windowed(
+        &LeaderboardMetric::SuccessRate,
+        &LeaderboardWindow::SevenDays,
+        &10,
+    );
+    assert_eq!(weekly.len(), 2);
+    assert_eq!(weekly.get(0).unwrap().provider, newcomer);
+    let veteran_week = weekly.get(1).unwrap();
+    assert_eq!(veteran_week.provider, veteran);
+    assert_eq!(veteran_week.total_signals, 5);
+    assert_eq!(veteran_week.success_rate, 2_000);
+    assert_eq!(veteran_week.total_volume, 5_000);
+
+    // A month later nobody has resolved anything inside either window
+    env.ledger().with_mut(|li| li.timestamp += 31 * 86_400);
+    for window in [LeaderboardWindow::SevenDays, LeaderboardWindow::ThirtyDays] {
+        assert_eq!(
+            client
+                .get_leaderboard_windowed(&LeaderboardMetric::Volume, &window, &10)
+                .len(),
+            0
+        );
+    }
+    assert_eq!(
+        client
+            .get_leaderboard(&LeaderboardMetric::Volume, &10)
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_risk_adjusted_rankings() {
+    let env = Env::default();
+    let client = setup(&env);
+    let steady = Address::generate(&env);
+    let volatile = Address::generate(&env);
+
+    // Steady: +5% every time. Volatile: three +5% wins, then -10% twice
+    settle_signals(&env, &client, &steady, 5, true);
+    settle_signals(&env, &client, &volatile, 3, true);
+    settle_signals(&env, &client, &volatile, 2, false);
+
+    let risk = client.get_provider_risk_stats(&volatile).unwrap();
+    assert_eq!(risk.resolved_signals, 5);
+    assert_eq!(risk.mean_roi, -100);
+    assert_eq!(risk.max_drawdown, 2_000);
+    assert_eq!(risk.avg_holding_time, 0);
+    assert!(risk.std_dev > 0);
+    let steady_risk = client.get_provider_risk_stats(&steady).unwrap();
+    assert_eq!(steady_risk.std_dev, 0);
+    assert_eq!(steady_risk.max_drawdown, 0);
+    assert_eq!(
+        client.get_provider_risk_stats(&Address::generate(&env)),
+        None
+    );
+
+    for metric in [
+        LeaderboardMetric::RiskAdjustedReturn,
+        LeaderboardMetric::DrawdownAdjustedReturn,
+    ] {
+        let board = client.get_leaderboard(&metric, &10);
+        assert_eq!(board.len(), 2);
+        assert_eq!(board.get(0).unwrap().provider, steady);
+        assert_eq!(board.get(1).unwrap().provider, volatile);
+        assert_eq!(board.get(1).unwrap().max_drawdown, 2_000);
+    }
+}
+
+#[test]
+fn test_reputation_combines_results_stake_and_slashes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let staked = Address::generate(&env);
+    let unstaked = Address::generate(&env);
+    settle_signals(&env, &client, &staked, 5, true);
+    settle_signals(&env, &client, &unstaked, 5, true);
+
+    // Staking needs a stake token to pull the provider's funds from
+    assert_eq!(
+        client.try_add_provider_stake(&staked, &500_000_000),
+        Err(Ok(AdminError::NotInitialized))
+    );
+    let token_id = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token = TokenClient::new(&env, &token_id);
+    let treasury = Address::generate(&env);
+    client.set_stake_token(&admin, &token_id);
+    client.set_platform_treasury(&admin, &treasury);
+    StellarAssetClient::new(&env, &token_id).mint(&staked, &500_000_000);
+
+    client.add_provider_stake(&staked, &500_000_000);
+    assert_eq!(client.get_provider_stake(&staked), 500_000_000);
+    assert_eq!(token.balance(&staked), 0);
+    assert_eq!(token.balance(&contract_id), 500_000_000);
+
+    let staked_score = client
+        .get_provider_performance(&staked)
+        .unwrap()
+        .reputation_score;
+    let unstaked_score = client
+        .get_provider_performance(&unstaked)
+        .unwrap()
+        .reputation_score;
+    assert!(staked_score > unstaked_score);
+
+    let board = client.get_leaderboard(&LeaderboardMetric::Reputation, &10);
+    assert_eq!(board.get(0).unwrap().provider, staked);
+    assert_eq!(board.get(0).unwrap().reputation_score, staked_score);
+
+    // A slash costs more than the stake bonus is worth
+    assert_eq!(
+        client.slash_provider(&admin, &staked, &100_000_000),
+        100_000_000
+    );
+    assert_eq!(client.get_provider_stake(&staked), 400_000_000);
+    assert_eq!(token.balance(&contract_id), 400_000_000);
+    assert_eq!(token.balance(&treasury), 100_000_000);
+    assert_eq!(client.get_slash_history(&staked).len(), 1);
+    let board = client.get_leaderboard(&LeaderboardMetric::Reputation, &10);
+    assert_eq!(board.get(0).unwrap().provider, unstaked);
+    assert!(board.get(1).unwrap().reputation_score < unstaked_score);
+
+    // The penalty fades as the slash ages, and the stake bonus wins again
+    env.ledger().with_mut(|li| li.timestamp += 90 * 86_400);
+    let staked_later = client.get_provider_performance(&staked).unwrap();
+    let unstaked_later = client.get_provider_performance(&unstaked).unwrap();
+    assert_eq!(staked_later.stats.total_signals, 5);
+    assert!(staked_later.reputation_score > unstaked_later.reputation_score);
+}
+
+#[test]
+fn test_slash_requires_multisig_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.set_stake_token(&admin, &token_id);
+    let treasury = Address::generate(&env);
+    client.set_platform_treasury(&admin, &treasury);
+    StellarAssetClient::new(&env, &token_id).mint(&provider, &200_000_000);
+    client.add_provider_stake(&provider, &200_000_000);
+
+    let second = Address::generate(&env);
+    let mut signers = Vec::new(&env);
+    signers.push_back(admin.clone());
+    signers.push_back(second.clone());
+    client.enable_multisig(&admin, &signers, &2);
+
+    assert_eq!(
+        client.try_slash_provider(&admin, &provider, &100_000_000),
+        Err(Ok(AdminError::InsufficientSignatures))
+    );
+    assert_eq!(client.get_provider_stake(&provider), 200_000_000);
+
+    let proposal_id = client.propose_admin_action(
+        &admin,
+        &AdminAction::SlashProvider(provider.clone(), 100_000_000),
+    );
+    assert_eq!(
+        client.approve_proposal(&second, &proposal_id),
+        ProposalStatus::Executed
+    );
+    assert_eq!(client.get_provider_stake(&provider), 100_000_000);
+    assert_eq!(
+        TokenClient::new(&env, &token_id).balance(&treasury),
+        100_000_000
+    );
+}
+
+#[test]
+fn test_stake_withdrawal_waits_for_lock_period() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = client.get_admin();
+    let provider = Address::generate(&env);
+
+    assert_eq!(
+        client.try_withdraw_provider_stake(&provider),
+        Err(Ok(AdminError::NoStakeFound))
+    );
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token = TokenClient::new(&env, &token_id);
+    client.set_stake_token(&admin, &token_id);
+    StellarAssetClient::new(&env, &token_id).mint(&provider, &200_000_000);
+    client.add_provider_stake(&provider, &200_000_000);
+
+    // Posting a signal locks the stake
+    settle_signals(&env, &client, &provider, 1, true);
+    assert_eq!(
+        client.try_withdraw_provider_stake(&provider),
+        Err(Ok(AdminError::StakeLocked))
+    );
+    env.ledger()
+        .with_mut(|li| li.timestamp += stake::UNSTAKE_LOCK_PERIOD - 1);
+    assert_eq!(
+        client.try_withdraw_provider_stake(&provider),
+        Err(Ok(AdminError::StakeLocked))
+    );
+    assert_eq!(client.get_provider_stake(&provider), 200_000_000);
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    assert_eq!(client.withdraw_provider_stake(&provider), 200_000_000);
+    assert_eq!(client.get_provider_stake(&provider), 0);
+    assert_eq!(token.balance(&provider), 200_000_000);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.try_withdraw_provider_stake(&provider),
+        Err(Ok(AdminError::NoStakeFound))
+    );
+}
+
+#[test]
+fn test_stake_stays_in_token_it_was_posted_in() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = client.get_admin();
+    let provider = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.set_platform_treasury(&admin, &treasury);
+
+    let first_id = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let first = TokenClient::new(&env, &first_id);
+    client.set_stake_token(&admin, &first_id);
+    StellarAssetClient::new(&env, &first_id).mint(&provider, &300_000_000);
+    client.add_provider_stake(&provider, &200_000_000);
+
+    // Switching the stake token does not move existing stakes
+    let second_id = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let second = TokenClient::new(&env, &second_id);
+    StellarAssetClient::new(&env, &second_id).mint(&client.address, &500_000_000);
+    client.set_stake_token(&admin, &second_id);
+
+    client.add_provider_stake(&provider, &100_000_000);
+    assert_eq!(first.balance(&provider), 0);
+    assert_eq!(first.balance(&client.address), 300_000_000);
+
+    assert_eq!(
+        client.slash_provider(&admin, &provider, &100_000_000),
+        100_000_000
+    );
+    assert_eq!(first.balance(&treasury), 100_000_000);
+    assert_eq!(second.balance(&treasury), 0);
+    assert_eq!(second.balance(&client.address), 500_000_000);
+
+    assert_eq!(client.withdraw_provider_stake(&provider), 200_000_000);
+    assert_eq!(first.balance(&provider), 200_000_000);
+    assert_eq!(first.balance(&client.address), 0);
+    assert_eq!(second.balance(&provider), 0);
+
+    // Once withdrawn, the next stake is posted in the current token
+    StellarAssetClient::new(&env, &second_id).mint(&provider, &100_000_000);
+    client.add_provider_stake(&provider, &100_000_000);
+    assert_eq!(second.balance(&client.address), 600_000_000);
+}
@@ -0,0 +1,213 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_resolution::{MockOracle, MockOracleClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> (SignalRegistryClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    (client, admin)
+}
+
+fn create(env: &Env, client: &SignalRegistryClient, provider: &Address) -> u64 {
+    client.create_signal(
+        provider,
+        &String::from_str(env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(env, "Original call"),
+        &(env.ledger().timestamp() + 3600),
+        &SignalCategory::SwingTrade,
+        &Vec::new(env),
+        &RiskLevel::Medium,
+    )
+}
+
+fn amendment(env: &Env, rationale: Option<&str>, price: Option<i128>) -> SignalAmendment {
+    SignalAmendment {
+        rationale: rationale.map(|r| String::from_str(env, r)),
+        price,
+        expiry: None,
+    }
+}
+
+#[test]
+fn test_cancel_signal() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let provider = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let signal_id = create(&env, &client, &provider);
+    assert_eq!(
+        client.try_cancel_signal(&other, &signal_id),
+        Err(Ok(AdminError::Unauthorized))
+    );
+
+    client.cancel_signal(&provider, &signal_id);
+    assert_eq!(
+        client.get_signal(&signal_id).unwrap().status,
+        SignalStatus::Cancelled
+    );
+    assert_eq!(
+        client
+            .get_active_signals(&0, &10, &SortOption::RecencyDesc, &None)
+            .len(),
+        0
+    );
+    assert_eq!(
+        client.try_cancel_signal(&provider, &signal_id),
+        Err(Ok(AdminError::SignalNotOpen))
+    );
+
+    // Cancelled signals cannot be copied
+    let executor = Address::generate(&env);
+    assert_eq!(
+        client.try_record_trade_execution(&executor, &signal_id, &100_000, &101_000, &1_000),
+        Err(Ok(errors::PerformanceError::SignalCancelled))
+    );
+}
+
+#[test]
+fn test_cancel_and_amend_rejected_after_execution() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let provider = Address::generate(&env);
+    let executor = Address::generate(&env);
+
+    let signal_id = create(&env, &client, &provider);
+    client.record_trade_execution(&executor, &signal_id, &100_000, &100_500, &1_000);
+
+    assert_eq!(
+        client.try_cancel_signal(&provider, &signal_id),
+        Err(Ok(AdminError::SignalHasExecutions))
+    );
+    assert_eq!(
+        client.try_amend_signal(
+            &provider,
+            &signal_id,
+            &amendment(&env, Some("Too late"), None)
+        ),
+        Err(Ok(AdminError::SignalHasExecutions))
+    );
+}
+
+#[test]
+fn test_amend_signal_keeps_revisions() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let signal_id = create(&env, &client, &provider);
+    let original_expiry = client.get_signal(&signal_id).unwrap().expiry;
+
+    let revision = client.amend_signal(
+        &provider,
+        &signal_id,
+        &amendment(&env, Some("Corrected rationale"), None),
+    );
+    assert_eq!(revision, 1);
+
+    let revision = client.amend_signal(&provider, &signal_id, &amendment(&env, None, Some(98_000)));
+    assert_eq!(revision, 2);
+
+    let signal = client.get_signal(&signal_id).unwrap();
+    assert_eq!(
+        signal.rationale,
+        String::from_str(&env, "Corrected rationale")
+    );
+    assert_eq!(signal.price, 98_000);
+
+    let revisions = client.get_signal_revisions(&signal_id);
+    assert_eq!(revisions.len(), 2);
+    let first = revisions.get(0).unwrap();
+    assert_eq!(first.revision, 1);
+    assert_eq!(first.rationale, String::from_str(&env, "Original call"));
+    assert_eq!(first.price, 100_000);
+    assert_eq!(first.expiry, original_expiry);
+    let second = revisions.get(1).unwrap();
+    assert_eq!(
+        second.rationale,
+        String::from_str(&env, "Corrected rationale")
+    );
+    assert_eq!(second.price, 100_000);
+
+    // Invalid amendments are rejected and leave no revision
+    assert_eq!(
+        client.try_amend_signal(&provider, &signal_id, &amendment(&env, None, None)),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+    assert_eq!(
+        client.try_amend_signal(&provider, &signal_id, &amendment(&env, Some(""), None)),
+        Err(Ok(AdminError::InvalidRationale))
+    );
+    assert_eq!(client.get_signal_revisions(&signal_id).len(), 2);
+}
+
+#[test]
+fn test_close_signal_counts_in_stats() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let winner = create(&env, &client, &provider);
+    let outcome = client.close_signal(&provider, &winner, &105_000);
+    assert_eq!(outcome.reason, OutcomeReason::Closed);
+    assert_eq!(outcome.roi, 500);
+    assert_eq!(outcome.status, SignalStatus::Successful);
+    assert_eq!(client.get_signal_outcome(&winner), Some(outcome));
+
+    // Closed signals drop out of the active feed and cannot be closed again
+    assert_eq!(
+        client
+            .get_active_signals(&0, &10, &SortOption::RecencyDesc, &None)
+            .len(),
+        0
+    );
+    assert_eq!(
+        client.try_close_signal(&provider, &winner, &105_000),
+        Err(Ok(AdminError::SignalNotOpen))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+    let loser = create(&env, &client, &provider);
+    let outcome = client.close_signal(&provider, &loser, &90_000);
+    assert_eq!(outcome.status, SignalStatus::Failed);
+
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.successful_signals, 1);
+    assert_eq!(stats.failed_signals, 1);
+}
+
+#[test]
+fn test_close_signal_uses_oracle_price() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    client.set_price_oracle(&admin, &oracle_id);
+
+    let signal_id = create(&env, &client, &provider);
+    assert_eq!(
+        client.try_close_signal(&provider, &signal_id, &150_000),
+        Err(Ok(AdminError::OraclePriceUnavailable))
+    );
+
+    // The provider's exit price is ignored in oracle mode
+    oracle.set_price(&97_000);
+    let outcome = client.close_signal(&provider, &signal_id, &150_000);
+    assert_eq!(outcome.close_price, 97_000);
+    assert_eq!(outcome.status, SignalStatus::Failed);
+    assert_eq!(
+        client.try_resolve_signal(&signal_id),
+        Err(Ok(ResolutionError::AlreadyResolved))
+    );
+}
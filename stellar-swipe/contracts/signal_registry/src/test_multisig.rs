@@ -0,0 +1,241 @@
+#![cfg(test)]
+
+use super::*;
+use crate::multisig::PROPOSAL_DURATION;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> (SignalRegistryClient<'_>, Address, Vec<Address>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let mut signers = Vec::new(env);
+    for _ in 0..3 {
+        signers.push_back(Address::generate(env));
+    }
+    client.enable_multisig(&admin, &signers, &2);
+
+    (client, admin, signers)
+}
+
+#[test]
+fn test_proposal_executes_at_threshold() {
+    let env = Env::default();
+    let (client, _admin, signers) = setup(&env);
+    let signer1 = signers.get(0).unwrap();
+    let signer2 = signers.get(1).unwrap();
+
+    let id = client.propose_admin_action(&signer1, &AdminAction::SetTradeFee(25));
+    assert_eq!(client.get_config().trade_fee_bps, 10);
+
+    let proposal = client.get_proposal(&id).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert_eq!(proposal.approvals.len(), 1);
+
+    assert_eq!(
+        client.approve_proposal(&signer2, &id),
+        ProposalStatus::Executed
+    );
+    assert_eq!(client.get_config().trade_fee_bps, 25);
+    assert_eq!(
+        client.get_proposal(&id).unwrap().status,
+        ProposalStatus::Executed
+    );
+
+    // Executed proposals take no further approvals
+    assert_eq!(
+        client.try_approve_proposal(&signers.get(2).unwrap(), &id),
+        Err(Ok(AdminError::ProposalNotPending))
+    );
+}
+
+#[test]
+fn test_direct_setters_require_proposal() {
+    let env = Env::default();
+    let (client, admin, signers) = setup(&env);
+    let signer1 = signers.get(0).unwrap();
+
+    assert_eq!(
+        client.try_set_trade_fee(&signer1, &25),
+        Err(Ok(AdminError::InsufficientSignatures))
+    );
+    assert_eq!(
+        client.try_set_platform_treasury(&signer1, &admin),
+        Err(Ok(AdminError::InsufficientSignatures))
+    );
+    assert_eq!(
+        client.try_transfer_admin(&signer1, &signer1),
+        Err(Ok(AdminError::InsufficientSignatures))
+    );
+}
+
+#[test]
+fn test_single_signer_cannot_reconfigure_multisig() {
+    let env = Env::default();
+    let (client, admin, signers) = setup(&env);
+    let signer1 = signers.get(0).unwrap();
+    let rogue = Address::generate(&env);
+    let mut rogue_signers = Vec::new(&env);
+    rogue_signers.push_back(rogue.clone());
+
+    let denied = Err(Ok(AdminError::InsufficientSignatures));
+    assert_eq!(
+        client.try_enable_multisig(&signer1, &rogue_signers, &1),
+        denied
+    );
+    assert_eq!(client.try_disable_multisig(&signer1), denied);
+    assert_eq!(client.try_add_multisig_signer(&signer1, &rogue), denied);
+    assert_eq!(
+        client.try_remove_multisig_signer(&signer1, &signers.get(1).unwrap()),
+        denied
+    );
+    assert_eq!(client.try_pause_trading(&signer1), denied);
+    assert_eq!(client.try_unpause_trading(&signer1), denied);
+    // The admin is held to the same rule
+    assert_eq!(
+        client.try_disable_multisig(&admin),
+        Err(Ok(AdminError::Unauthorized))
+    );
+
+    assert_eq!(client.get_multisig_signers(), signers);
+    assert!(client.try_set_trade_fee(&rogue, &50).is_err());
+    assert_eq!(client.get_config().trade_fee_bps, 10);
+}
+
+#[test]
+fn test_disable_multisig_proposal() {
+    let env = Env::default();
+    let (client, admin, signers) = setup(&env);
+
+    let id = client.propose_admin_action(&signers.get(0).unwrap(), &AdminAction::DisableMultisig);
+    client.approve_proposal(&signers.get(1).unwrap(), &id);
+    assert!(!client.is_multisig_enabled());
+
+    // Back to single-admin control
+    client.set_trade_fee(&admin, &20);
+    assert_eq!(client.get_config().trade_fee_bps, 20);
+}
+
+#[test]
+fn test_treasury_and_admin_transfer_proposals() {
+    let env = Env::default();
+    let (client, _admin, signers) = setup(&env);
+    let signer1 = signers.get(0).unwrap();
+    let signer3 = signers.get(2).unwrap();
+    let treasury = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    let id = client.propose_admin_action(
+        &signer1,
+        &AdminAction::SetPlatformTreasury(treasury.clone()),
+    );
+    client.approve_proposal(&signer3, &id);
+    assert_eq!(client.get_platform_treasury(), Some(treasury));
+
+    let id = client.propose_admin_action(&signer3, &AdminAction::TransferAdmin(new_admin.clone()));
+    client.approve_proposal(&signer1, &id);
+    assert_eq!(client.get_admin(), new_admin);
+}
+
+#[test]
+fn test_proposal_validation_and_double_approval() {
+    let env = Env::default();
+    let (client, admin, signers) = setup(&env);
+    let signer1 = signers.get(0).unwrap();
+
+    // Non-signers cannot propose
+    assert_eq!(
+        client.try_propose_admin_action(&admin, &AdminAction::PauseTrading),
+        Err(Ok(AdminError::Unauthorized))
+    );
+    // Invalid actions are rejected up front
+    assert_eq!(
+        client.try_propose_admin_action(&signer1, &AdminAction::SetTradeFee(500)),
+        Err(Ok(AdminError::InvalidFeeRate))
+    );
+
+    let id = client.propose_admin_action(&signer1, &AdminAction::PauseTrading);
+    assert_eq!(
+        client.try_approve_proposal(&signer1, &id),
+        Err(Ok(AdminError::AlreadyApproved))
+    );
+    assert_eq!(
+        client.try_approve_proposal(&signer1, &99),
+        Err(Ok(AdminError::ProposalNotFound))
+    );
+}
+
+#[test]
+fn test_proposal_expiry_and_cancel() {
+    let env = Env::default();
+    let (client, admin, signers) = setup(&env);
+    let signer1 = signers.get(0).unwrap();
+    let signer2 = signers.get(1).unwrap();
+
+    let expiring = client.propose_admin_action(&signer1, &AdminAction::SetMinStake(5));
+    let cancelled = client.propose_admin_action(&signer1, &AdminAction::SetMinStake(6));
+
+    // Only the proposer or the admin can cancel
+    assert_eq!(
+        client.try_cancel_proposal(&signer2, &cancelled),
+        Err(Ok(AdminError::Unauthorized))
+    );
+    client.cancel_proposal(&admin, &cancelled);
+    assert_eq!(
+        client.get_proposal(&cancelled).unwrap().status,
+        ProposalStatus::Cancelled
+    );
+    assert_eq!(
+        client.try_approve_proposal(&signer2, &cancelled),
+        Err(Ok(AdminError::ProposalNotPending))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += PROPOSAL_DURATION);
+    assert_eq!(
+        client.get_proposal(&expiring).unwrap().status,
+        ProposalStatus::Expired
+    );
+    assert_eq!(
+        client.try_approve_proposal(&signer2, &expiring),
+        Err(Ok(AdminError::ProposalExpired))
+    );
+    assert_eq!(client.get_config().min_stake, admin::DEFAULT_MIN_STAKE);
+}
+
+#[test]
+fn test_removed_signer_approval_does_not_count() {
+    let env = Env::default();
+    let (client, _admin, signers) = setup(&env);
+    let signer1 = signers.get(0).unwrap();
+    let signer2 = signers.get(1).unwrap();
+    let signer3 = signers.get(2).unwrap();
+
+    let id = client.propose_admin_action(&signer1, &AdminAction::SetTradeFee(30));
+    let remove = client.propose_admin_action(
+        &signer2,
+        &AdminAction::RemoveMultisigSigner(signer1.clone()),
+    );
+    client.approve_proposal(&signer3, &remove);
+    assert!(!client.get_multisig_signers().contains(&signer1));
+
+    // One valid approval is not enough
+    assert_eq!(
+        client.approve_proposal(&signer2, &id),
+        ProposalStatus::Pending
+    );
+    assert_eq!(
+        client.try_execute_proposal(&signer2, &id),
+        Err(Ok(AdminError::InsufficientSignatures))
+    );
+
+    assert_eq!(
+        client.approve_proposal(&signer3, &id),
+        ProposalStatus::Executed
+    );
+    assert_eq!(client.get_config().trade_fee_bps, 30);
+}
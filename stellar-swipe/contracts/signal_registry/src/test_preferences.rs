@@ -0,0 +1,191 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> SignalRegistryClient<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    client
+}
+
+fn create(
+    env: &Env,
+    client: &SignalRegistryClient,
+    provider: &Address,
+    pair: &str,
+    risk_level: RiskLevel,
+) -> u64 {
+    let id = client.create_signal(
+        provider,
+        &String::from_str(env, pair),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(env, "Setup"),
+        &(env.ledger().timestamp() + 86_400),
+        &SignalCategory::SwingTrade,
+        &Vec::new(env),
+        &risk_level,
+    );
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    id
+}
+
+fn open_preferences(env: &Env) -> UserPreferences {
+    UserPreferences {
+        categories: Vec::new(env),
+        max_risk_level: RiskLevel::High,
+        blocked_providers: Vec::new(env),
+        watched_pairs: Vec::new(env),
+        min_success_rate: 0,
+    }
+}
+
+#[test]
+fn test_preferences_validated() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+
+    let mut prefs = open_preferences(&env);
+    prefs.min_success_rate = 10_001;
+    assert_eq!(
+        client.try_set_user_preferences(&user, &prefs),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+
+    let mut prefs = open_preferences(&env);
+    prefs
+        .watched_pairs
+        .push_back(String::from_str(&env, "XLMUSDC"));
+    assert_eq!(
+        client.try_set_user_preferences(&user, &prefs),
+        Err(Ok(AdminError::InvalidAssetPair))
+    );
+
+    assert_eq!(client.get_user_preferences(&user), None);
+    client.set_user_preferences(&user, &open_preferences(&env));
+    assert_eq!(
+        client.get_user_preferences(&user),
+        Some(open_preferences(&env))
+    );
+}
+
+#[test]
+fn test_filters_apply_to_lists_and_feeds() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    let trusted = Address::generate(&env);
+    let blocked = Address::generate(&env);
+
+    client.follow_provider(&user, &trusted);
+    client.follow_provider(&user, &blocked);
+
+    let safe = create(&env, &client, &trusted, "XLM/USDC", RiskLevel::Low);
+    create(&env, &client, &trusted, "BTC/USDC", RiskLevel::High);
+    create(&env, &client, &blocked, "ETH/USDC", RiskLevel::Low);
+
+    let mut prefs = open_preferences(&env);
+    prefs.max_risk_level = RiskLevel::Medium;
+    prefs.blocked_providers.push_back(blocked.clone());
+    client.set_user_preferences(&user, &prefs);
+
+    let filtered = client.get_signals_filtered(&None, &None, &None, &0, &10, &Some(user.clone()));
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(filtered.get(0).unwrap().id, safe);
+    // Without a user the list is unchanged
+    assert_eq!(
+        client
+            .get_signals_filtered(&None, &None, &None, &0, &10, &None)
+            .len(),
+        3
+    );
+
+    let follow_feed = client.get_active_signals_archived(&user, &true);
+    assert_eq!(follow_feed.len(), 1);
+
+    let feed = client.get_feed(&user, &FeedCursor::Start, &10);
+    assert_eq!(feed.signals.len(), 1);
+    assert_eq!(feed.signals.get(0).unwrap().id, safe);
+
+    // Unproven providers are hidden once a minimum success rate is set
+    prefs.min_success_rate = 5_000;
+    client.set_user_preferences(&user, &prefs);
+    assert_eq!(
+        client
+            .get_feed(&user, &FeedCursor::Start, &10)
+            .signals
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_auto_copy_matching() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    let xlm = create(&env, &client, &provider, "XLM/USDC", RiskLevel::Low);
+    let btc = create(&env, &client, &provider, "BTC/USDC", RiskLevel::Low);
+    assert!(client.signal_matches_preferences(&user, &btc));
+
+    let mut prefs = open_preferences(&env);
+    prefs
+        .watched_pairs
+        .push_back(String::from_str(&env, "XLM/USDC"));
+    client.set_user_preferences(&user, &prefs);
+
+    assert!(client.signal_matches_preferences(&user, &xlm));
+    assert!(!client.signal_matches_preferences(&user, &btc));
+    assert!(!client.signal_matches_preferences(&user, &999));
+}
+
+#[test]
+fn test_strategy_auto_copy_matching() {
+    let env = Env::default();
+    let client = setup(&env);
+    let user = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    let mut legs = Vec::new(&env);
+    for pair in ["XLM/USDC", "BTC/USDC"] {
+        legs.push_back(StrategyLeg {
+            asset_pair: String::from_str(&env, pair),
+            action: SignalAction::Buy,
+            price: 100_000,
+            weight_bps: 5_000,
+        });
+    }
+    let strategy_id = client.create_strategy_signal(
+        &provider,
+        &String::from_str(&env, "Pair basket"),
+        &legs,
+        &(env.ledger().timestamp() + 3_600),
+    );
+    assert!(client.strategy_matches_preferences(&user, &strategy_id));
+
+    // Every leg has to trade a watched pair
+    let mut prefs = open_preferences(&env);
+    prefs
+        .watched_pairs
+        .push_back(String::from_str(&env, "XLM/USDC"));
+    client.set_user_preferences(&user, &prefs);
+    assert!(!client.strategy_matches_preferences(&user, &strategy_id));
+
+    prefs
+        .watched_pairs
+        .push_back(String::from_str(&env, "BTC/USDC"));
+    client.set_user_preferences(&user, &prefs);
+    assert!(client.strategy_matches_preferences(&user, &strategy_id));
+
+    prefs.blocked_providers.push_back(provider.clone());
+    client.set_user_preferences(&user, &prefs);
+    assert!(!client.strategy_matches_preferences(&user, &strategy_id));
+    assert!(!client.strategy_matches_preferences(&user, &999));
+}
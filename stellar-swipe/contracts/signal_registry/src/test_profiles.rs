@@ -0,0 +1,162 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+
+fn setup(env: &Env) -> (SignalRegistryClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn set_profile(
+    env: &Env,
+    client: &SignalRegistryClient,
+    provider: &Address,
+    name: &str,
+) -> Result<(), ProfileError> {
+    let mut links = Vec::new(env);
+    links.push_back(String::from_str(env, "https://example.com/desk"));
+    let mut categories = Vec::new(env);
+    categories.push_back(SignalCategory::SwingTrade);
+
+    client
+        .try_set_provider_profile(
+            provider,
+            &String::from_str(env, name),
+            &String::from_str(env, "Macro swing trader"),
+            &Some(BytesN::from_array(env, &[7; 32])),
+            &links,
+            &categories,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_provider_manages_profile() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let provider = Address::generate(&env);
+
+    assert_eq!(client.get_provider_profile(&provider), None);
+    assert_eq!(
+        set_profile(&env, &client, &provider, ""),
+        Err(ProfileError::InvalidDisplayName)
+    );
+
+    set_profile(&env, &client, &provider, "Desk One").unwrap();
+    let profile = client.get_provider_profile(&provider).unwrap();
+    assert_eq!(profile.display_name, String::from_str(&env, "Desk One"));
+    assert_eq!(profile.links.len(), 1);
+    assert_eq!(profile.categories.len(), 1);
+    assert!(profile.badges.is_empty());
+}
+
+#[test]
+fn test_badges_need_admin_or_moderator() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let provider = Address::generate(&env);
+    let moderator = Address::generate(&env);
+
+    assert_eq!(
+        client.try_grant_badge(&moderator, &provider, &ProviderBadge::Verified),
+        Err(Ok(ProfileError::NotModerator))
+    );
+    assert_eq!(
+        client.try_set_moderator(&moderator, &moderator, &true),
+        Err(Ok(AdminError::Unauthorized))
+    );
+
+    client.set_moderator(&admin, &moderator, &true);
+    client.grant_badge(&moderator, &provider, &ProviderBadge::Verified);
+    client.grant_badge(&admin, &provider, &ProviderBadge::Institutional);
+    assert_eq!(
+        client.try_grant_badge(&moderator, &provider, &ProviderBadge::Verified),
+        Err(Ok(ProfileError::BadgeAlreadyGranted))
+    );
+
+    // Badges show even before the provider sets a profile, and survive edits
+    assert_eq!(
+        client.get_provider_profile(&provider).unwrap().badges.len(),
+        2
+    );
+    set_profile(&env, &client, &provider, "Desk One").unwrap();
+    client.revoke_badge(&moderator, &provider, &ProviderBadge::Institutional);
+    let badges = client.get_provider_profile(&provider).unwrap().badges;
+    assert_eq!(badges.len(), 1);
+    assert_eq!(badges.get(0).unwrap(), ProviderBadge::Verified);
+
+    client.set_moderator(&admin, &moderator, &false);
+    assert_eq!(
+        client.try_revoke_badge(&moderator, &provider, &ProviderBadge::Verified),
+        Err(Ok(ProfileError::NotModerator))
+    );
+}
+
+#[test]
+fn test_set_moderator_requires_proposal_under_multisig() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let moderator = Address::generate(&env);
+    let second = Address::generate(&env);
+    let mut signers = Vec::new(&env);
+    signers.push_back(admin.clone());
+    signers.push_back(second.clone());
+    client.enable_multisig(&admin, &signers, &2);
+
+    assert_eq!(
+        client.try_set_moderator(&admin, &moderator, &true),
+        Err(Ok(AdminError::InsufficientSignatures))
+    );
+    assert!(!client.is_moderator(&moderator));
+
+    let proposal_id =
+        client.propose_admin_action(&admin, &AdminAction::SetModerator(moderator.clone(), true));
+    client.approve_proposal(&second, &proposal_id);
+    assert!(client.is_moderator(&moderator));
+}
+
+#[test]
+fn test_leaderboard_entries_carry_profiles() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let named = Address::generate(&env);
+    let anonymous = Address::generate(&env);
+    set_profile(&env, &client, &named, "Desk One").unwrap();
+
+    let stats = |success_rate: u32| ProviderPerformance {
+        total_signals: 10,
+        successful_signals: 5,
+        failed_signals: 5,
+        total_copies: 0,
+        success_rate,
+        avg_return: 0,
+        total_volume: 0,
+    };
+    let mut stats_map = Map::new(&env);
+    stats_map.set(named.clone(), stats(8_000));
+    stats_map.set(anonymous.clone(), stats(6_000));
+
+    let board = env.as_contract(&client.address, || {
+        leaderboard::get_leaderboard(
+            &env,
+            &stats_map,
+            LeaderboardMetric::SuccessRate,
+            LeaderboardWindow::AllTime,
+            10,
+        )
+    });
+    assert_eq!(
+        board.get(0).unwrap().profile.display_name,
+        String::from_str(&env, "Desk One")
+    );
+    let default_profile = board.get(1).unwrap().profile;
+    assert_eq!(default_profile.provider, anonymous);
+    assert_eq!(default_profile.updated_at, 0);
+}
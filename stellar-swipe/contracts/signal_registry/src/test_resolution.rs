@@ -0,0 +1,204 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, symbol_short};
+use stellar_swipe_common::AssetPair;
+
+/// Oracle stub returning a configurable price for every pair, optionally
+/// overridden per base asset code
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_price(env: Env, price: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("price"), &price);
+    }
+
+    pub fn set_base_price(env: Env, base_code: String, price: i128) {
+        env.storage().instance().set(&base_code, &price);
+    }
+
+    pub fn get_price(env: Env, pair: AssetPair) -> i128 {
+        env.storage()
+            .instance()
+            .get(&pair.base.code)
+            .or_else(|| env.storage().instance().get(&symbol_short!("price")))
+            .expect("no price")
+    }
+}
+
+fn setup(env: &Env) -> (SignalRegistryClient<'_>, Address, MockOracleClient<'_>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(env, &oracle_id);
+    client.set_price_oracle(&admin, &oracle_id);
+
+    (client, admin, oracle)
+}
+
+fn create(
+    env: &Env,
+    client: &SignalRegistryClient,
+    provider: &Address,
+    action: SignalAction,
+) -> u64 {
+    client.create_signal(
+        provider,
+        &String::from_str(env, "XLM/USDC"),
+        &action,
+        &100_000,
+        &String::from_str(env, "Oracle settled"),
+        &(env.ledger().timestamp() + 3600),
+        &SignalCategory::SwingTrade,
+        &Vec::new(env),
+        &RiskLevel::Medium,
+    )
+}
+
+fn advance_past_expiry(env: &Env) {
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+}
+
+#[test]
+fn test_resolve_signal_against_oracle_close() {
+    let env = Env::default();
+    let (client, _admin, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let winner = create(&env, &client, &provider, SignalAction::Buy);
+    let loser = create(&env, &client, &provider, SignalAction::Sell);
+
+    advance_past_expiry(&env);
+    oracle.set_price(&110_000);
+
+    let outcome = client.resolve_signal(&winner);
+    assert_eq!(client.get_signal_outcome(&winner), Some(outcome.clone()));
+    assert_eq!(outcome.close_price, 110_000);
+    assert_eq!(outcome.roi, 1000);
+    assert_eq!(outcome.status, SignalStatus::Successful);
+    assert_eq!(
+        client.get_signal(&winner).unwrap().status,
+        SignalStatus::Successful
+    );
+
+    // A sell call is wrong when the price rises
+    let outcome = client.resolve_signal(&loser);
+    assert_eq!(outcome.roi, -1000);
+    assert_eq!(outcome.status, SignalStatus::Failed);
+
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.total_signals, 2);
+    assert_eq!(stats.successful_signals, 1);
+    assert_eq!(stats.failed_signals, 1);
+    assert_eq!(stats.avg_return, 0);
+}
+
+#[test]
+fn test_cleanup_keeps_resolved_outcome() {
+    let env = Env::default();
+    let (client, _admin, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let signal_id = create(&env, &client, &provider, SignalAction::Buy);
+    advance_past_expiry(&env);
+    oracle.set_price(&110_000);
+    client.resolve_signal(&signal_id);
+
+    let (_, expired) = client.cleanup_expired_signals(&10);
+    assert_eq!(expired, 0);
+    assert_eq!(
+        client.get_signal(&signal_id).unwrap().status,
+        SignalStatus::Successful
+    );
+}
+
+#[test]
+fn test_self_reported_trades_do_not_settle_in_oracle_mode() {
+    let env = Env::default();
+    let (client, _admin, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+    let executor = Address::generate(&env);
+
+    let signal_id = create(&env, &client, &provider, SignalAction::Buy);
+
+    // A huge reported win only counts as copy volume
+    client.record_trade_execution(&executor, &signal_id, &100_000, &200_000, &1_000_000);
+    let signal = client.get_signal(&signal_id).unwrap();
+    assert_eq!(signal.status, SignalStatus::Active);
+    assert_eq!(signal.executions, 1);
+    assert_eq!(
+        client.get_provider_stats(&provider).unwrap().total_signals,
+        0
+    );
+
+    advance_past_expiry(&env);
+    oracle.set_price(&99_000);
+    let outcome = client.resolve_signal(&signal_id);
+    assert_eq!(outcome.status, SignalStatus::Failed);
+    assert_eq!(
+        client.get_provider_stats(&provider).unwrap().failed_signals,
+        1
+    );
+}
+
+#[test]
+fn test_resolve_signal_errors() {
+    let env = Env::default();
+    let (client, _admin, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let signal_id = create(&env, &client, &provider, SignalAction::Buy);
+    assert_eq!(
+        client.try_resolve_signal(&signal_id),
+        Err(Ok(ResolutionError::NotExpired))
+    );
+    assert_eq!(
+        client.try_resolve_signal(&99),
+        Err(Ok(ResolutionError::SignalNotFound))
+    );
+
+    advance_past_expiry(&env);
+    // Oracle has no price yet
+    assert_eq!(
+        client.try_resolve_signal(&signal_id),
+        Err(Ok(ResolutionError::PriceUnavailable))
+    );
+
+    oracle.set_price(&105_000);
+    client.resolve_signal(&signal_id);
+    assert_eq!(
+        client.try_resolve_signal(&signal_id),
+        Err(Ok(ResolutionError::AlreadyResolved))
+    );
+}
+
+#[test]
+fn test_resolve_requires_oracle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let provider = Address::generate(&env);
+
+    let signal_id = create(&env, &client, &provider, SignalAction::Buy);
+    advance_past_expiry(&env);
+
+    assert_eq!(client.get_price_oracle(), None);
+    assert_eq!(
+        client.try_resolve_signal(&signal_id),
+        Err(Ok(ResolutionError::OracleNotConfigured))
+    );
+}
@@ -0,0 +1,147 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_resolution::{MockOracle, MockOracleClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> (SignalRegistryClient<'_>, MockOracleClient<'_>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let oracle_id = env.register(MockOracle, ());
+    client.set_price_oracle(&admin, &oracle_id);
+
+    (client, MockOracleClient::new(env, &oracle_id))
+}
+
+fn leg(env: &Env, pair: &str, action: SignalAction, price: i128, weight_bps: u32) -> StrategyLeg {
+    StrategyLeg {
+        asset_pair: String::from_str(env, pair),
+        action,
+        price,
+        weight_bps,
+    }
+}
+
+fn pair_trade(env: &Env) -> Vec<StrategyLeg> {
+    let mut legs = Vec::new(env);
+    legs.push_back(leg(env, "XLM/USDC", SignalAction::Buy, 100_000, 5000));
+    legs.push_back(leg(env, "BTC/USDC", SignalAction::Sell, 200_000, 5000));
+    legs
+}
+
+fn create(
+    env: &Env,
+    client: &SignalRegistryClient,
+    provider: &Address,
+    legs: &Vec<StrategyLeg>,
+) -> Result<u64, AdminError> {
+    client
+        .try_create_strategy_signal(
+            provider,
+            &String::from_str(env, "Long XLM / short BTC"),
+            legs,
+            &(env.ledger().timestamp() + 3600),
+        )
+        .map(|id| id.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_combined_strategy_roi() {
+    let env = Env::default();
+    let legs = pair_trade(&env);
+    let mut exit_prices = Vec::new(&env);
+    exit_prices.push_back(110_000); // long leg +10%
+    exit_prices.push_back(210_000); // short leg -5%
+
+    assert_eq!(
+        performance::calculate_strategy_roi(&legs, &exit_prices),
+        Ok(250)
+    );
+}
+
+#[test]
+fn test_strategy_roi_overflow_is_an_error() {
+    let env = Env::default();
+    let mut legs = Vec::new(&env);
+    legs.push_back(leg(&env, "XLM/USDC", SignalAction::Buy, 1, 5000));
+    legs.push_back(leg(&env, "BTC/USDC", SignalAction::Buy, 1, 5000));
+    let mut exit_prices = Vec::new(&env);
+    exit_prices.push_back(i128::MAX / 10_000);
+    exit_prices.push_back(i128::MAX / 10_000);
+
+    assert_eq!(
+        performance::calculate_strategy_roi(&legs, &exit_prices),
+        Err(ResolutionError::RoiOverflow)
+    );
+}
+
+#[test]
+fn test_strategy_legs_validated() {
+    let env = Env::default();
+    let (client, _oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    // Weights must sum to 100%
+    let mut legs = pair_trade(&env);
+    legs.set(1, leg(&env, "BTC/USDC", SignalAction::Sell, 200_000, 4000));
+    assert_eq!(
+        create(&env, &client, &provider, &legs),
+        Err(AdminError::InvalidStrategy)
+    );
+
+    // A single leg is just a signal
+    let mut single = Vec::new(&env);
+    single.push_back(leg(&env, "XLM/USDC", SignalAction::Buy, 100_000, 10_000));
+    assert_eq!(
+        create(&env, &client, &provider, &single),
+        Err(AdminError::InvalidStrategy)
+    );
+
+    let mut neutral = pair_trade(&env);
+    neutral.set(0, leg(&env, "XLM/USDC", SignalAction::Hold, 100_000, 5000));
+    assert_eq!(
+        create(&env, &client, &provider, &neutral),
+        Err(AdminError::InvalidStrategy)
+    );
+
+    let id = create(&env, &client, &provider, &pair_trade(&env)).unwrap();
+    let strategy = client.get_strategy_signal(&id).unwrap();
+    assert_eq!(strategy.legs.len(), 2);
+    assert_eq!(strategy.status, SignalStatus::Active);
+}
+
+#[test]
+fn test_resolve_strategy_counts_in_stats() {
+    let env = Env::default();
+    let (client, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let id = create(&env, &client, &provider, &pair_trade(&env)).unwrap();
+    assert_eq!(
+        client.try_resolve_strategy(&id),
+        Err(Ok(ResolutionError::NotExpired))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+    oracle.set_base_price(&String::from_str(&env, "XLM"), &110_000);
+    oracle.set_base_price(&String::from_str(&env, "BTC"), &190_000);
+
+    let strategy = client.resolve_strategy(&id);
+    // +10% long, +5% short, equally weighted
+    assert_eq!(strategy.roi, Some(750));
+    assert_eq!(strategy.status, SignalStatus::Successful);
+    assert_eq!(
+        client.try_resolve_strategy(&id),
+        Err(Ok(ResolutionError::AlreadyResolved))
+    );
+
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.successful_signals, 1);
+    assert_eq!(stats.avg_return, 750);
+}
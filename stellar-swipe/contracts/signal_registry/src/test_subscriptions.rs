@@ -0,0 +1,236 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::symbol_short;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+
+const DAY: u64 = 86_400;
+
+struct Setup<'a> {
+    client: SignalRegistryClient<'a>,
+    contract_id: Address,
+    asset: Asset,
+    token: TokenClient<'a>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let asset = Asset {
+        symbol: symbol_short!("USDC"),
+        contract: token_id.clone(),
+    };
+
+    Setup {
+        client,
+        contract_id,
+        asset,
+        token: TokenClient::new(env, &token_id),
+    }
+}
+
+fn funded_subscriber(env: &Env, s: &Setup, amount: i128) -> Address {
+    let subscriber = Address::generate(env);
+    StellarAssetClient::new(env, &s.asset.contract).mint(&subscriber, &amount);
+    subscriber
+}
+
+fn create_private(env: &Env, client: &SignalRegistryClient, provider: &Address) -> u64 {
+    client.create_subscriber_signal(
+        provider,
+        &String::from_str(env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &120_000,
+        &String::from_str(env, "Members only entry"),
+        &(env.ledger().timestamp() + 3 * DAY),
+        &SignalCategory::SwingTrade,
+        &Vec::new(env),
+        &RiskLevel::Medium,
+    )
+}
+
+#[test]
+fn test_subscription_payment_split_and_renewal() {
+    let env = Env::default();
+    let s = setup(&env);
+    let provider = Address::generate(&env);
+    let subscriber = funded_subscriber(&env, &s, 1_000);
+
+    let plan_id = s
+        .client
+        .create_subscription_plan(&provider, &s.asset, &400, &(30 * DAY));
+    assert_eq!(s.client.get_subscription_plans(&provider).len(), 1);
+
+    let start = env.ledger().timestamp();
+    let sub = s.client.subscribe(&subscriber, &provider, &plan_id);
+    assert_eq!(sub.expires_at, start + 30 * DAY);
+
+    // 10% platform fee is held by the contract and tracked in the treasury
+    assert_eq!(s.token.balance(&provider), 360);
+    assert_eq!(s.token.balance(&s.contract_id), 40);
+    assert_eq!(s.client.get_treasury_balance(&s.asset), 40);
+
+    // Renewing early extends from the current expiry
+    env.ledger().with_mut(|li| li.timestamp += 10 * DAY);
+    let renewed = s.client.subscribe(&subscriber, &provider, &plan_id);
+    assert_eq!(renewed.started_at, start);
+    assert_eq!(renewed.expires_at, start + 60 * DAY);
+    assert_eq!(s.token.balance(&subscriber), 200);
+}
+
+#[test]
+fn test_plan_validation() {
+    let env = Env::default();
+    let s = setup(&env);
+    let provider = Address::generate(&env);
+    let subscriber = funded_subscriber(&env, &s, 1_000);
+
+    assert_eq!(
+        s.client
+            .try_create_subscription_plan(&provider, &s.asset, &400, &3600),
+        Err(Ok(SubscriptionError::InvalidPlan))
+    );
+    assert_eq!(
+        s.client.try_subscribe(&subscriber, &provider, &1),
+        Err(Ok(SubscriptionError::PlanNotFound))
+    );
+
+    let plan_id = s
+        .client
+        .create_subscription_plan(&provider, &s.asset, &400, &(7 * DAY));
+    assert_eq!(
+        s.client.try_subscribe(&provider, &provider, &plan_id),
+        Err(Ok(SubscriptionError::CannotSubscribeSelf))
+    );
+
+    s.client
+        .set_subscription_plan_active(&provider, &plan_id, &false);
+    assert_eq!(
+        s.client.try_subscribe(&subscriber, &provider, &plan_id),
+        Err(Ok(SubscriptionError::PlanInactive))
+    );
+    assert_eq!(s.token.balance(&subscriber), 1_000);
+}
+
+#[test]
+fn test_subscriber_only_signal_gating() {
+    let env = Env::default();
+    let s = setup(&env);
+    let provider = Address::generate(&env);
+    let subscriber = funded_subscriber(&env, &s, 1_000);
+    let outsider = Address::generate(&env);
+
+    let plan_id = s
+        .client
+        .create_subscription_plan(&provider, &s.asset, &100, &DAY);
+    let signal_id = create_private(&env, &s.client, &provider);
+
+    // Public views
+    assert!(s.client.is_subscriber_only(&signal_id));
+    assert!(s.client.get_signal(&signal_id).is_none());
+    let feed = s
+        .client
+        .get_active_signals(&0, &10, &SortOption::RecencyDesc, &None);
+    let summary = feed.get(0).unwrap();
+    assert!(summary.subscriber_only);
+    assert_eq!(summary.price, 0);
+    assert_eq!(
+        s.client
+            .get_signals_filtered(&None, &None, &None, &0, &10, &None)
+            .len(),
+        0
+    );
+
+    // Amending does not leak the earlier price through the revision history
+    s.client.amend_signal(
+        &provider,
+        &signal_id,
+        &SignalAmendment {
+            rationale: None,
+            price: Some(118_000),
+            expiry: None,
+        },
+    );
+    assert_eq!(s.client.get_signal_revisions(&signal_id).len(), 0);
+
+    // Gated view
+    assert_eq!(
+        s.client
+            .try_get_signal_for_subscriber(&outsider, &signal_id)
+            .unwrap_err(),
+        Ok(SubscriptionError::SubscriptionRequired)
+    );
+    assert_eq!(
+        s.client
+            .get_signal_for_subscriber(&provider, &signal_id)
+            .price,
+        118_000
+    );
+
+    s.client.subscribe(&subscriber, &provider, &plan_id);
+    assert_eq!(
+        s.client
+            .get_signal_for_subscriber(&subscriber, &signal_id)
+            .price,
+        118_000
+    );
+
+    // Access ends with the subscription
+    env.ledger().with_mut(|li| li.timestamp += DAY);
+    assert_eq!(
+        s.client
+            .try_get_signal_for_subscriber(&subscriber, &signal_id)
+            .unwrap_err(),
+        Ok(SubscriptionError::SubscriptionRequired)
+    );
+}
+
+#[test]
+fn test_subscriber_only_prices_stay_private() {
+    let env = Env::default();
+    let s = setup(&env);
+    let provider = Address::generate(&env);
+
+    let signal_id = create_private(&env, &s.client, &provider);
+    env.as_contract(&s.contract_id, || {
+        conditions::set_condition(
+            &env,
+            signal_id,
+            &SignalCondition {
+                trigger_price: Some(125_000),
+                trigger_direction: TriggerDirection::Above,
+                valid_from: None,
+            },
+        )
+    });
+    assert!(s.client.get_signal_condition(&signal_id).is_none());
+
+    // The close still resolves the signal, but its outcome is not public
+    let outcome = s.client.close_signal(&provider, &signal_id, &126_000);
+    assert_eq!(outcome.entry_price, 120_000);
+    assert!(s.client.get_signal_outcome(&signal_id).is_none());
+
+    // Public signals keep their outcome visible
+    let public_id = s.client.create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &121_000,
+        &String::from_str(&env, "Open entry"),
+        &(env.ledger().timestamp() + 3 * DAY),
+        &SignalCategory::SwingTrade,
+        &Vec::new(&env),
+        &RiskLevel::Medium,
+    );
+    let outcome = s.client.close_signal(&provider, &public_id, &126_000);
+    assert_eq!(s.client.get_signal_outcome(&public_id), Some(outcome));
+}
@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> SignalRegistryClient<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    client
+}
+
+fn create(env: &Env, client: &SignalRegistryClient, provider: &Address, pair: &str) -> u64 {
+    let id = client.create_signal(
+        provider,
+        &String::from_str(env, pair),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(env, "Breakout"),
+        &(env.ledger().timestamp() + 3600),
+        &SignalCategory::SwingTrade,
+        &Vec::new(env),
+        &RiskLevel::Medium,
+    );
+    env.ledger().with_mut(|li| li.timestamp += 10);
+    id
+}
+
+#[test]
+fn test_swipes_counted_once_per_user() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let signal_id = create(&env, &client, &provider, "XLM/USDC");
+
+    client.swipe(&alice, &signal_id, &SwipeDirection::Right);
+    let counts = client.swipe(&bob, &signal_id, &SwipeDirection::Left);
+    assert_eq!(counts, SwipeCounts { right: 1, left: 1 });
+    assert_eq!(
+        client.get_swipe(&alice, &signal_id),
+        Some(SwipeDirection::Right)
+    );
+
+    assert_eq!(
+        client.try_swipe(&alice, &signal_id, &SwipeDirection::Left),
+        Err(Ok(SwipeError::AlreadySwiped))
+    );
+    assert_eq!(
+        client.try_swipe(&provider, &signal_id, &SwipeDirection::Right),
+        Err(Ok(SwipeError::CannotSwipeOwnSignal))
+    );
+    assert_eq!(
+        client.try_swipe(&alice, &99, &SwipeDirection::Right),
+        Err(Ok(SwipeError::SignalNotFound))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    assert_eq!(
+        client.try_swipe(&Address::generate(&env), &signal_id, &SwipeDirection::Right),
+        Err(Ok(SwipeError::SignalNotOpen))
+    );
+}
+
+#[test]
+fn test_queue_skips_swiped_and_feed_ranks_by_interest() {
+    let env = Env::default();
+    let client = setup(&env);
+    let provider = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let older = create(&env, &client, &provider, "XLM/USDC");
+    let newer = create(&env, &client, &provider, "BTC/USDC");
+
+    client.swipe(&alice, &older, &SwipeDirection::Right);
+    client.swipe(&bob, &older, &SwipeDirection::Right);
+    client.swipe(&bob, &newer, &SwipeDirection::Left);
+
+    let queue = client.get_swipe_queue(&alice, &0, &10);
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.get(0).unwrap().id, newer);
+    assert_eq!(client.get_swipe_queue(&bob, &0, &10).len(), 0);
+
+    let feed = client.get_active_signals(&0, &10, &SortOption::SwipesDesc, &None);
+    let top = feed.get(0).unwrap();
+    assert_eq!(top.id, older);
+    assert_eq!(top.right_swipes, 2);
+    assert_eq!(feed.get(1).unwrap().left_swipes, 1);
+}
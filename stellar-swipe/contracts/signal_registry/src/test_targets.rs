@@ -0,0 +1,203 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_resolution::{MockOracle, MockOracleClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+fn setup(env: &Env) -> (SignalRegistryClient<'_>, MockOracleClient<'_>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let oracle_id = env.register(MockOracle, ());
+    client.set_price_oracle(&admin, &oracle_id);
+
+    (client, MockOracleClient::new(env, &oracle_id))
+}
+
+fn targets(take_profit: Option<i128>, stop_loss: Option<i128>) -> SignalTargets {
+    SignalTargets {
+        take_profit,
+        stop_loss,
+    }
+}
+
+fn create(
+    env: &Env,
+    client: &SignalRegistryClient,
+    provider: &Address,
+    action: SignalAction,
+    targets: &SignalTargets,
+) -> Result<u64, AdminError> {
+    client
+        .try_create_signal_with_targets(
+            provider,
+            &String::from_str(env, "XLM/USDC"),
+            &action,
+            &100_000,
+            &String::from_str(env, "With exits"),
+            &(env.ledger().timestamp() + 3600),
+            &SignalCategory::SwingTrade,
+            &Vec::new(env),
+            &RiskLevel::Medium,
+            targets,
+        )
+        .map(|id| id.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_targets_validated_against_direction() {
+    let env = Env::default();
+    let (client, _oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    // Buy: stop below entry, profit above
+    assert_eq!(
+        create(
+            &env,
+            &client,
+            &provider,
+            SignalAction::Buy,
+            &targets(Some(90_000), None)
+        ),
+        Err(AdminError::InvalidTargets)
+    );
+    assert_eq!(
+        create(
+            &env,
+            &client,
+            &provider,
+            SignalAction::Buy,
+            &targets(None, Some(110_000))
+        ),
+        Err(AdminError::InvalidTargets)
+    );
+    // Sell: the reverse
+    assert_eq!(
+        create(
+            &env,
+            &client,
+            &provider,
+            SignalAction::Sell,
+            &targets(Some(110_000), None)
+        ),
+        Err(AdminError::InvalidTargets)
+    );
+    assert_eq!(
+        create(
+            &env,
+            &client,
+            &provider,
+            SignalAction::Sell,
+            &targets(Some(-1), None)
+        ),
+        Err(AdminError::InvalidTargets)
+    );
+
+    let id = create(
+        &env,
+        &client,
+        &provider,
+        SignalAction::Sell,
+        &targets(Some(90_000), Some(105_000)),
+    )
+    .unwrap();
+    assert_eq!(
+        client.get_signal_targets(&id),
+        Some(targets(Some(90_000), Some(105_000)))
+    );
+}
+
+#[test]
+fn test_targets_in_signal_summary() {
+    let env = Env::default();
+    let (client, _oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    create(
+        &env,
+        &client,
+        &provider,
+        SignalAction::Buy,
+        &targets(Some(120_000), Some(95_000)),
+    )
+    .unwrap();
+
+    let feed = client.get_active_signals(&0, &10, &SortOption::RecencyDesc, &None);
+    assert_eq!(feed.len(), 1);
+    let summary = feed.get(0).unwrap();
+    assert_eq!(summary.take_profit, Some(120_000));
+    assert_eq!(summary.stop_loss, Some(95_000));
+}
+
+#[test]
+fn test_target_hit_resolves_before_expiry() {
+    let env = Env::default();
+    let (client, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let winner = create(
+        &env,
+        &client,
+        &provider,
+        SignalAction::Buy,
+        &targets(Some(110_000), Some(95_000)),
+    )
+    .unwrap();
+    let loser = create(
+        &env,
+        &client,
+        &provider,
+        SignalAction::Sell,
+        &targets(Some(90_000), Some(104_000)),
+    )
+    .unwrap();
+
+    // Price between the targets: nothing to settle yet
+    oracle.set_price(&103_000);
+    assert_eq!(
+        client.try_resolve_signal(&winner),
+        Err(Ok(ResolutionError::NotExpired))
+    );
+
+    // Rally crosses the Buy take-profit and the Sell stop-loss
+    oracle.set_price(&111_000);
+    let outcome = client.resolve_signal(&winner);
+    assert_eq!(outcome.status, SignalStatus::Successful);
+    assert_eq!(outcome.reason, OutcomeReason::TakeProfit);
+
+    let outcome = client.resolve_signal(&loser);
+    assert_eq!(outcome.status, SignalStatus::Failed);
+    assert_eq!(outcome.reason, OutcomeReason::StopLoss);
+
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.successful_signals, 1);
+    assert_eq!(stats.failed_signals, 1);
+}
+
+#[test]
+fn test_untouched_targets_resolve_at_expiry() {
+    let env = Env::default();
+    let (client, oracle) = setup(&env);
+    let provider = Address::generate(&env);
+
+    let id = create(
+        &env,
+        &client,
+        &provider,
+        SignalAction::Buy,
+        &targets(Some(150_000), Some(50_000)),
+    )
+    .unwrap();
+
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+    oracle.set_price(&104_000);
+
+    let outcome = client.resolve_signal(&id);
+    assert_eq!(outcome.reason, OutcomeReason::Expiry);
+    assert_eq!(outcome.status, SignalStatus::Successful);
+}
@@ -0,0 +1,204 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+const DAY: u64 = 86_400;
+
+fn setup(env: &Env) -> SignalRegistryClient<'_> {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000 * DAY);
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    client
+}
+
+/// Post a signal from a fresh provider and return its id
+fn post(
+    env: &Env,
+    client: &SignalRegistryClient,
+    asset_pair: &str,
+    category: SignalCategory,
+    tags: &[&str],
+) -> u64 {
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    let mut tag_vec = Vec::new(env);
+    for tag in tags {
+        tag_vec.push_back(String::from_str(env, tag));
+    }
+    client.create_signal(
+        &Address::generate(env),
+        &String::from_str(env, asset_pair),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(env, "Setup"),
+        &(env.ledger().timestamp() + DAY),
+        &category,
+        &tag_vec,
+        &RiskLevel::Medium,
+    )
+}
+
+/// Two days of activity: XLM cools down, BTC picks up through swipes and
+/// ETH appears with a copied trade
+fn seed(env: &Env, client: &SignalRegistryClient) {
+    for _ in 0..3 {
+        post(
+            env,
+            client,
+            "XLM/USDC",
+            SignalCategory::SwingTrade,
+            &["macro"],
+        );
+    }
+    post(
+        env,
+        client,
+        "BTC/USDC",
+        SignalCategory::SwingTrade,
+        &["alt"],
+    );
+    env.ledger().with_mut(|li| li.timestamp += DAY);
+
+    for _ in 0..2 {
+        post(
+            env,
+            client,
+            "XLM/USDC",
+            SignalCategory::SwingTrade,
+            &["macro"],
+        );
+    }
+    let btc = post(
+        env,
+        client,
+        "BTC/USDC",
+        SignalCategory::SwingTrade,
+        &["alt"],
+    );
+    client.swipe(&Address::generate(env), &btc, &SwipeDirection::Right);
+    client.swipe(&Address::generate(env), &btc, &SwipeDirection::Right);
+    client.swipe(&Address::generate(env), &btc, &SwipeDirection::Left);
+    let eth = post(env, client, "ETH/USDC", SignalCategory::Momentum, &["alt"]);
+    client.record_trade_execution(
+        &Address::generate(env),
+        &eth,
+        &100_000,
+        &100_500,
+        &20_000_000,
+    );
+}
+
+fn entry(env: &Env, name: &str, signals: u32, activity: i128, previous: i128) -> TrendEntry {
+    TrendEntry {
+        name: String::from_str(env, name),
+        signals,
+        activity,
+        previous_activity: previous,
+        velocity: activity - previous,
+    }
+}
+
+#[test]
+fn test_trending_ranks_by_velocity() {
+    let env = Env::default();
+    let client = setup(&env);
+    seed(&env, &client);
+
+    // Signal 10 points, copy 5, one point per 10^7 volume, right swipe 2,
+    // left swipe 1
+    let trending = client.get_trending_assets(&24, &None);
+    assert_eq!(trending.len(), 3);
+    assert_eq!(trending.get(0).unwrap(), entry(&env, "ETH/USDC", 1, 17, 0));
+    assert_eq!(trending.get(1).unwrap(), entry(&env, "BTC/USDC", 1, 15, 10));
+    assert_eq!(trending.get(2).unwrap(), entry(&env, "XLM/USDC", 2, 20, 30));
+
+    let swing = client.get_trending_assets(&24, &Some(SignalCategory::SwingTrade));
+    assert_eq!(swing.len(), 2);
+    assert_eq!(
+        swing.get(0).unwrap().name,
+        String::from_str(&env, "BTC/USDC")
+    );
+
+    // Nothing was posted in the last hour
+    env.ledger().with_mut(|li| li.timestamp += 2 * 3_600);
+    assert_eq!(client.get_trending_assets(&1, &None).len(), 0);
+}
+
+#[test]
+fn test_popular_tags_are_windowed() {
+    let env = Env::default();
+    let client = setup(&env);
+    seed(&env, &client);
+
+    let popular = client.get_popular_tags(&24, &None, &10);
+    assert_eq!(popular.len(), 2);
+    assert_eq!(popular.get(0).unwrap(), entry(&env, "alt", 2, 32, 10));
+    assert_eq!(popular.get(1).unwrap(), entry(&env, "macro", 2, 20, 30));
+
+    let momentum = client.get_popular_tags(&24, &Some(SignalCategory::Momentum), &10);
+    assert_eq!(momentum.len(), 1);
+    assert_eq!(momentum.get(0).unwrap(), entry(&env, "alt", 1, 17, 0));
+    assert_eq!(client.get_popular_tags(&24, &None, &1).len(), 1);
+
+    // Two days later the old activity has left both windows
+    env.ledger().with_mut(|li| li.timestamp += 2 * DAY);
+    assert_eq!(client.get_popular_tags(&24, &None, &10).len(), 0);
+}
+
+#[test]
+fn test_activated_signals_count_from_activation() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    // Posted before the signals that follow it, but waits two days for
+    // its start time
+    let delayed = client.create_conditional_signal(
+        &Address::generate(&env),
+        &String::from_str(&env, "BTC/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Setup"),
+        &(env.ledger().timestamp() + 5 * DAY),
+        &SignalCategory::SwingTrade,
+        &Vec::from_array(&env, [String::from_str(&env, "alt")]),
+        &RiskLevel::Medium,
+        &SignalCondition {
+            trigger_price: None,
+            trigger_direction: TriggerDirection::Above,
+            valid_from: Some(env.ledger().timestamp() + 2 * DAY),
+        },
+    );
+    post(
+        &env,
+        &client,
+        "XLM/USDC",
+        SignalCategory::SwingTrade,
+        &["macro"],
+    );
+    let analytics = client.get_global_analytics();
+    assert_eq!(analytics.total_signals_24h, 1);
+    assert_eq!(client.get_popular_tags(&24, &None, &10).len(), 1);
+
+    env.ledger().with_mut(|li| li.timestamp += 2 * DAY);
+    assert_eq!(client.activate_conditional_signals(&0, &10), 1);
+    assert_eq!(
+        client.get_signal(&delayed).unwrap().timestamp,
+        env.ledger().timestamp()
+    );
+
+    let analytics = client.get_global_analytics();
+    assert_eq!(analytics.total_signals_24h, 1);
+    assert_eq!(
+        analytics.most_traded_pairs,
+        Vec::from_array(&env, [(String::from_str(&env, "BTC/USDC"), 1)])
+    );
+    let trending = client.get_trending_assets(&24, &None);
+    assert_eq!(trending.len(), 1);
+    assert_eq!(trending.get(0).unwrap(), entry(&env, "BTC/USDC", 1, 10, 0));
+    let popular = client.get_popular_tags(&24, &None, &10);
+    assert_eq!(popular.len(), 1);
+    assert_eq!(popular.get(0).unwrap(), entry(&env, "alt", 1, 10, 0));
+}
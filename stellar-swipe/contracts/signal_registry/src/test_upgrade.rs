@@ -0,0 +1,229 @@
+#![cfg(test)]
+
+use super::*;
+use crate::upgrade::{RetiredStorageKey, SignalV1, SCHEMA_VERSION};
+use soroban_sdk::{testutils::Address as _, Env, IntoVal, String, Val};
+
+fn setup(env: &Env) -> (Address, SignalRegistryClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(SignalRegistry, ());
+    let client = SignalRegistryClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    (contract_id, client, admin)
+}
+
+fn v1_signal(env: &Env, id: u64, provider: &Address) -> SignalV1 {
+    SignalV1 {
+        id,
+        provider: provider.clone(),
+        asset_pair: String::from_str(env, "XLM/USDC"),
+        action: SignalAction::Buy,
+        price: 100_000,
+        rationale: String::from_str(env, "Legacy signal"),
+        timestamp: env.ledger().timestamp(),
+        expiry: env.ledger().timestamp() + 3600,
+        status: SignalStatus::Active,
+        executions: 2,
+        successful_executions: 1,
+        total_volume: 5_000,
+        total_roi: 300,
+    }
+}
+
+/// Rewind storage to the v1 layout: every signal in one instance map and
+/// no stored schema version.
+fn write_v1_layout(env: &Env, contract_id: &Address, provider: &Address) {
+    env.as_contract(contract_id, || {
+        let mut legacy: Map<u64, Val> = Map::new(env);
+        legacy.set(1, v1_signal(env, 1, provider).into_val(env));
+        legacy.set(2, v1_signal(env, 2, provider).into_val(env));
+
+        // Written by the intermediate release that already had categories
+        let mut tagged = v1_signal(env, 3, provider);
+        tagged.price = 200_000;
+        let mut tags = Vec::new(env);
+        tags.push_back(String::from_str(env, "breakout"));
+        let signal = Signal {
+            id: tagged.id,
+            provider: tagged.provider,
+            asset_pair: tagged.asset_pair,
+            action: tagged.action,
+            price: tagged.price,
+            rationale: tagged.rationale,
+            timestamp: tagged.timestamp,
+            expiry: tagged.expiry,
+            status: tagged.status,
+            executions: tagged.executions,
+            successful_executions: tagged.successful_executions,
+            total_volume: tagged.total_volume,
+            total_roi: tagged.total_roi,
+            category: SignalCategory::Breakout,
+            tags,
+            risk_level: RiskLevel::High,
+            is_collaborative: false,
+        };
+        legacy.set(3, signal.into_val(env));
+
+        env.storage().instance().set(&StorageKey::Signals, &legacy);
+        env.storage()
+            .instance()
+            .set(&StorageKey::SignalCounter, &3u64);
+        write_tag_popularity(env);
+        env.storage()
+            .instance()
+            .remove(&stellar_swipe_common::UpgradeKey::SchemaVersion);
+    });
+}
+
+/// The all-time tag counters kept in instance storage before v3
+fn write_tag_popularity(env: &Env) {
+    let mut popularity: Map<String, u32> = Map::new(env);
+    popularity.set(String::from_str(env, "breakout"), 4);
+    env.storage()
+        .instance()
+        .set(&RetiredStorageKey::TagPopularity, &popularity);
+}
+
+fn has_tag_popularity(env: &Env, contract_id: &Address) -> bool {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .instance()
+            .has(&RetiredStorageKey::TagPopularity)
+    })
+}
+
+#[test]
+fn test_fresh_deploy_is_current_schema() {
+    let env = Env::default();
+    let (_, client, _) = setup(&env);
+
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    let status = client.migrate(&10);
+    assert_eq!(status.migrated, 0);
+    assert_eq!(status.remaining, 0);
+    assert_eq!(status.schema_version, SCHEMA_VERSION);
+}
+
+#[test]
+fn test_migrate_from_v1_layout_in_batches() {
+    let env = Env::default();
+    let (contract_id, client, _) = setup(&env);
+    let provider = Address::generate(&env);
+    write_v1_layout(&env, &contract_id, &provider);
+
+    assert_eq!(client.get_schema_version(), 1);
+    assert!(client.get_signal(&1).is_none());
+
+    // First batch leaves work behind and keeps the old version
+    let status = client.migrate(&2);
+    assert_eq!(status.migrated, 2);
+    assert_eq!(status.remaining, 1);
+    assert_eq!(status.schema_version, 1);
+    assert_eq!(status.target_version, SCHEMA_VERSION);
+
+    assert!(has_tag_popularity(&env, &contract_id));
+
+    // Resume until done
+    let status = client.migrate(&2);
+    assert_eq!(status.migrated, 1);
+    assert_eq!(status.remaining, 0);
+    assert_eq!(status.schema_version, SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert!(!has_tag_popularity(&env, &contract_id));
+
+    // v1 records get defaults for the new fields
+    let migrated = client.get_signal(&1).unwrap();
+    assert_eq!(migrated.provider, provider);
+    assert_eq!(migrated.executions, 2);
+    assert_eq!(migrated.total_roi, 300);
+    assert_eq!(migrated.category, SignalCategory::SwingTrade);
+    assert_eq!(migrated.risk_level, RiskLevel::Medium);
+    assert_eq!(migrated.tags.len(), 0);
+    assert!(!migrated.is_collaborative);
+
+    // Records already in the newer shape keep their fields
+    let tagged = client.get_signal(&3).unwrap();
+    assert_eq!(tagged.category, SignalCategory::Breakout);
+    assert_eq!(tagged.risk_level, RiskLevel::High);
+    assert_eq!(tagged.tags.len(), 1);
+
+    // Migrated signals are indexed and visible to queries
+    let feed = client.get_active_signals(&0, &10, &SortOption::RecencyDesc, &Some(provider));
+    assert_eq!(feed.len(), 3);
+
+    // Rewriting old records does not count as creating signals
+    let today = env.ledger().timestamp() / 86_400;
+    let stats = client.get_global_history(&today, &today).get(0).unwrap();
+    assert_eq!(stats.signals_created, 0);
+    assert_eq!(stats.active_providers, 0);
+
+    // New signals continue from the old counter
+    let new_provider = Address::generate(&env);
+    let id = client.create_signal(
+        &new_provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Sell,
+        &150_000,
+        &String::from_str(&env, "Post-migration signal"),
+        &(env.ledger().timestamp() + 3600),
+        &SignalCategory::DayTrade,
+        &Vec::new(&env),
+        &RiskLevel::Low,
+    );
+    assert_eq!(id, 4);
+
+    // Further calls are no-ops
+    let status = client.migrate(&10);
+    assert_eq!(status.migrated, 0);
+    assert_eq!(status.remaining, 0);
+}
+
+#[test]
+fn test_migrate_from_v2_drops_tag_counters() {
+    let env = Env::default();
+    let (contract_id, client, _) = setup(&env);
+    env.as_contract(&contract_id, || {
+        stellar_swipe_common::set_schema_version(&env, 2);
+        write_tag_popularity(&env);
+    });
+    assert_eq!(client.get_schema_version(), 2);
+
+    let status = client.migrate(&10);
+    assert_eq!(status.migrated, 0);
+    assert_eq!(status.remaining, 0);
+    assert_eq!(status.schema_version, SCHEMA_VERSION);
+    assert!(!has_tag_popularity(&env, &contract_id));
+}
+
+#[test]
+fn test_upgrade_requires_admin() {
+    let env = Env::default();
+    let (_, client, _) = setup(&env);
+
+    let stranger = Address::generate(&env);
+    let wasm_hash = BytesN::from_array(&env, &[0u8; 32]);
+    let result = client.try_upgrade(&stranger, &wasm_hash);
+    assert_eq!(result, Err(Ok(AdminError::Unauthorized)));
+}
+
+#[test]
+fn test_upgrade_needs_proposal_under_multisig() {
+    let env = Env::default();
+    let (_, client, admin) = setup(&env);
+
+    let mut signers = Vec::new(&env);
+    signers.push_back(Address::generate(&env));
+    signers.push_back(Address::generate(&env));
+    client.enable_multisig(&admin, &signers, &2);
+
+    let wasm_hash = BytesN::from_array(&env, &[0u8; 32]);
+    assert_eq!(
+        client.try_upgrade(&signers.get(0).unwrap(), &wasm_hash),
+        Err(Ok(AdminError::InsufficientSignatures))
+    );
+}
@@ -1,5 +1,5 @@
-use soroban_sdk::{contracttype, Address, String, Symbol, Vec};
 use crate::categories::{RiskLevel, SignalCategory};
+use soroban_sdk::{contracttype, Address, String, Symbol, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -7,6 +7,8 @@ pub enum SortOption {
     PerformanceDesc,
     RecencyDesc,
     VolumeDesc,
+    /// Most right swipes first
+    SwipesDesc,
 }
 
 #[contracttype]
@@ -20,6 +22,14 @@ pub struct SignalSummary {
     pub success_rate: u32,
     pub total_copies: u32,
     pub timestamp: u64,
+    pub take_profit: Option<i128>,
+    pub stop_loss: Option<i128>,
+    /// Subscriber-only signal: price and targets are withheld
+    pub subscriber_only: bool,
+    /// Users who swiped right (interested in copying)
+    pub right_swipes: u32,
+    /// Users who swiped left (passed)
+    pub left_swipes: u32,
 }
 
 #[contracttype]
@@ -31,6 +41,7 @@ pub enum SignalStatus {
     Expired,
     Successful, // Signal met success criteria (avg ROI > 2%)
     Failed,     // Signal met failure criteria (avg ROI < -5% or expired with no executions)
+    Cancelled,  // Withdrawn by the provider before any execution
 }
 
 #[contracttype]
@@ -38,6 +49,7 @@ pub enum SignalStatus {
 pub enum SignalAction {
     Buy,
     Sell,
+    Hold, // Neutral call: price expected to stay within the hold band
 }
 
 #[contracttype]
@@ -55,17 +67,12 @@ pub struct Signal {
     // Performance tracking fields
     pub executions: u32,            // Number of trade executions for this signal
     pub successful_executions: u32, // Number of successful trade executions
- feature/signal-categorization-tagging
-    pub total_volume: i128, // Cumulative volume across all executions
-    pub total_roi: i128,    // Cumulative ROI in basis points (10000 = 100%)
+    pub total_volume: i128,         // Cumulative volume across all executions
+    pub total_roi: i128,            // Cumulative ROI in basis points (10000 = 100%)
     // Categorization fields
     pub category: SignalCategory,
-    pub tags: Vec<String>,  // Max 10 tags
+    pub tags: Vec<String>, // Max 10 tags
     pub risk_level: RiskLevel,
-=======
-    pub total_volume: i128,         // Cumulative volume across all executions
-    pub total_roi: i128,            // Cumulative ROI in basis points (10000 = 100%)
- main
     // Collaboration fields
     pub is_collaborative: bool,
 }
@@ -130,12 +137,23 @@ pub struct SignalPerformanceView {
     pub status: SignalStatus,
 }
 
+/// View struct for provider performance queries
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProviderPerformanceView {
+    pub stats: ProviderPerformance,
+    /// Time-decayed reputation in basis points (0-10000), see
+    /// `performance::calculate_reputation_score`
+    pub reputation_score: u32,
+}
+
 // Type alias for backward compatibility
 #[allow(dead_code)]
 pub type SignalStats = ProviderPerformance;
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum ImportFormat {
     CSV,
     JSON,
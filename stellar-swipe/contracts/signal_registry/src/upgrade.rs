@@ -0,0 +1,176 @@
+//! Contract upgrades and storage schema migration.
+//!
+//! Schema versions:
+//! - 1: every signal in one `Map<u64, Signal>` under `StorageKey::Signals` in
+//!   instance storage. Entries written before categorization and
+//!   collaboration existed are in the `SignalV1` shape.
+//! - 2: one persistent entry per signal plus secondary indexes (see `storage`).
+//! - 3: the all-time tag counter map (`TagPopularity`) is gone from instance
+//!   storage; popular tags are computed from recent signals.
+//!
+//! `migrate` drains the v1 map in id order, a batch at a time, so it can be
+//! resumed across transactions until the stored version reaches `SCHEMA_VERSION`.
+//! The finishing call also drops instance entries earlier schemas left behind.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, TryFromVal, Val, Vec};
+use stellar_swipe_common::{
+    get_stored_schema_version, set_schema_version, upgrade_contract, MigrationStatus,
+};
+
+use crate::admin::require_direct_admin;
+use crate::categories::{RiskLevel, SignalCategory};
+use crate::errors::AdminError;
+use crate::events::{emit_contract_upgraded, emit_schema_migrated};
+use crate::storage;
+use crate::types::{Signal, SignalAction, SignalStatus};
+use crate::StorageKey;
+
+/// Schema version written by this code
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Max signals moved per `migrate` call (each one writes its own entries)
+pub const MAX_MIGRATION_BATCH: u32 = 10;
+
+/// Instance keys of data no longer kept
+#[contracttype]
+#[derive(Clone)]
+pub enum RetiredStorageKey {
+    /// Map<String, u32> of all-time tag counts (until v3)
+    TagPopularity,
+}
+
+/// Signal layout before categorization and collaboration fields were added
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignalV1 {
+    pub id: u64,
+    pub provider: Address,
+    pub asset_pair: String,
+    pub action: SignalAction,
+    pub price: i128,
+    pub rationale: String,
+    pub timestamp: u64,
+    pub expiry: u64,
+    pub status: SignalStatus,
+    pub executions: u32,
+    pub successful_executions: u32,
+    pub total_volume: i128,
+    pub total_roi: i128,
+}
+
+impl SignalV1 {
+    fn into_signal(self, env: &Env) -> Signal {
+        Signal {
+            id: self.id,
+            provider: self.provider,
+            asset_pair: self.asset_pair,
+            action: self.action,
+            price: self.price,
+            rationale: self.rationale,
+            timestamp: self.timestamp,
+            expiry: self.expiry,
+            status: self.status,
+            executions: self.executions,
+            successful_executions: self.successful_executions,
+            total_volume: self.total_volume,
+            total_roi: self.total_roi,
+            category: SignalCategory::SwingTrade,
+            tags: Vec::new(env),
+            risk_level: RiskLevel::Medium,
+            is_collaborative: false,
+        }
+    }
+}
+
+/// Replace the contract Wasm. Admin only; with a multi-sig threshold above
+/// one this needs an `AdminAction::Upgrade` proposal.
+pub fn upgrade(env: &Env, caller: &Address, wasm_hash: BytesN<32>) -> Result<(), AdminError> {
+    require_direct_admin(env, caller)?;
+    caller.require_auth();
+
+    apply_upgrade(env, caller, wasm_hash);
+    Ok(())
+}
+
+pub(crate) fn apply_upgrade(env: &Env, upgraded_by: &Address, wasm_hash: BytesN<32>) {
+    upgrade_contract(env, wasm_hash.clone());
+    emit_contract_upgraded(env, upgraded_by.clone(), wasm_hash);
+}
+
+/// Schema version of the data in storage.
+/// Deployments that never stored a version are v1 if the legacy map exists.
+pub fn get_schema_version(env: &Env) -> u32 {
+    match get_stored_schema_version(env) {
+        Some(version) => version,
+        None if env.storage().instance().has(&StorageKey::Signals) => 1,
+        None => SCHEMA_VERSION,
+    }
+}
+
+fn get_legacy_signals(env: &Env) -> Map<u64, Val> {
+    env.storage()
+        .instance()
+        .get(&StorageKey::Signals)
+        .unwrap_or(Map::new(env))
+}
+
+/// Decode a v1 map entry in either the current or the pre-categorization shape.
+/// The shape is picked from the stored field names, since decoding into a
+/// struct with different fields traps in the host instead of returning an error.
+fn decode_legacy_signal(env: &Env, value: &Val) -> Result<Signal, AdminError> {
+    let fields =
+        Map::<Symbol, Val>::try_from_val(env, value).map_err(|_| AdminError::MigrationFailed)?;
+
+    if fields.contains_key(Symbol::new(env, "category")) {
+        Signal::try_from_val(env, value).map_err(|_| AdminError::MigrationFailed)
+    } else {
+        SignalV1::try_from_val(env, value)
+            .map(|signal| signal.into_signal(env))
+            .map_err(|_| AdminError::MigrationFailed)
+    }
+}
+
+/// Move up to `batch` signals from the v1 map into per-signal storage.
+/// Safe to call repeatedly; once the map is empty the schema version is bumped.
+pub fn migrate(env: &Env, batch: u32) -> Result<MigrationStatus, AdminError> {
+    let batch = if batch == 0 || batch > MAX_MIGRATION_BATCH {
+        MAX_MIGRATION_BATCH
+    } else {
+        batch
+    };
+
+    let mut legacy = get_legacy_signals(env);
+    let mut migrated = 0u32;
+
+    while migrated < batch {
+        let (signal_id, value) = match legacy.iter().next() {
+            Some(entry) => entry,
+            None => break,
+        };
+
+        let signal = decode_legacy_signal(env, &value)?;
+        storage::save_signal(env, &signal);
+        legacy.remove(signal_id);
+        migrated += 1;
+    }
+
+    if legacy.is_empty() {
+        env.storage().instance().remove(&StorageKey::Signals);
+        env.storage()
+            .instance()
+            .remove(&RetiredStorageKey::TagPopularity);
+        if get_stored_schema_version(env) != Some(SCHEMA_VERSION) {
+            set_schema_version(env, SCHEMA_VERSION);
+            emit_schema_migrated(env, SCHEMA_VERSION);
+        }
+    } else {
+        env.storage().instance().set(&StorageKey::Signals, &legacy);
+    }
+
+    Ok(MigrationStatus {
+        schema_version: get_schema_version(env),
+        target_version: SCHEMA_VERSION,
+        migrated,
+        remaining: legacy.len(),
+    })
+}